@@ -0,0 +1,130 @@
+//! Local SQLite cache for series observations
+//! 
+//! Used by [FredClient::series_observation_cached](../client/struct.FredClient.html#method.series_observation_cached).  Each cached response is stored as JSON together with the last_updated time of the series when it was fetched.
+
+use rusqlite::{params, Connection, OptionalExtension};
+
+use crate::series::observation::Response;
+
+const CREATE_TABLE: &str = "CREATE TABLE IF NOT EXISTS observations (
+    series_id TEXT NOT NULL,
+    params_hash TEXT NOT NULL,
+    last_updated TEXT NOT NULL,
+    response TEXT NOT NULL,
+    PRIMARY KEY (series_id, params_hash)
+)";
+
+/// Opens the cache database at `path`, creating the table if needed
+pub(crate) fn open(path: &str) -> Result<Connection, String> {
+    let conn = match Connection::open(path) {
+        Ok(conn) => conn,
+        Err(msg) => return Err(msg.to_string()),
+    };
+    match conn.execute(CREATE_TABLE, params![]) {
+        Ok(_) => Ok(conn),
+        Err(msg) => Err(msg.to_string()),
+    }
+}
+
+/// Returns a stable hash of the request arguments as hex
+/// 
+/// FNV-1a is used rather than the standard library hasher, whose output may change between Rust releases and would invalidate existing caches.
+pub(crate) fn params_hash(params: &str) -> String {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in params.bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    format!("{:016x}", hash)
+}
+
+/// Returns the cached response if it was stored for the given last_updated time of the series
+pub(crate) fn load(
+    conn: &Connection,
+    series_id: &str,
+    params_hash: &str,
+    last_updated: &str,
+) -> Result<Option<Response>, String> {
+    let row: Option<(String, String)> = match conn.query_row(
+        "SELECT last_updated, response FROM observations WHERE series_id = ?1 AND params_hash = ?2",
+        params![series_id, params_hash],
+        |row| Ok((row.get(0)?, row.get(1)?)),
+    ).optional() {
+        Ok(row) => row,
+        Err(msg) => return Err(msg.to_string()),
+    };
+
+    match row {
+        Some((cached_updated, json)) if cached_updated == last_updated => {
+            match serde_json::from_str(&json) {
+                Ok(resp) => Ok(Some(resp)),
+                Err(msg) => Err(msg.to_string()),
+            }
+        },
+        _ => Ok(None),
+    }
+}
+
+/// Stores a response, replacing any earlier entry for the same series and arguments
+pub(crate) fn store(
+    conn: &Connection,
+    series_id: &str,
+    params_hash: &str,
+    last_updated: &str,
+    resp: &Response,
+) -> Result<(), String> {
+    let json = match serde_json::to_string(resp) {
+        Ok(json) => json,
+        Err(msg) => return Err(msg.to_string()),
+    };
+    match conn.execute(
+        "INSERT OR REPLACE INTO observations (series_id, params_hash, last_updated, response) VALUES (?1, ?2, ?3, ?4)",
+        params![series_id, params_hash, last_updated, json],
+    ) {
+        Ok(_) => Ok(()),
+        Err(msg) => Err(msg.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::series::observation::DataPoint;
+
+    #[test]
+    fn cache_params_hash() {
+        assert_eq!(params_hash(""), "cbf29ce484222325");
+        assert_eq!(params_hash("&units=pc1"), params_hash("&units=pc1"));
+        assert_ne!(params_hash("&units=pc1"), params_hash("&units=pch"));
+    }
+
+    #[test]
+    fn cache_store_and_load() {
+        let conn = match open(":memory:") {
+            Ok(conn) => conn,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+
+        let mut resp = Response::default();
+        resp.observations.push(DataPoint {
+            date: String::from("2020-01-01"),
+            value: String::from("1.5"),
+            ..DataPoint::default()
+        });
+
+        let key = params_hash("&units=lin");
+        assert_eq!(load(&conn, "GNPCA", &key, "2020-03-26 07:51:02-05").map(|r| r.is_none()), Ok(true));
+        assert_eq!(store(&conn, "GNPCA", &key, "2020-03-26 07:51:02-05", &resp), Ok(()));
+
+        match load(&conn, "GNPCA", &key, "2020-03-26 07:51:02-05") {
+            Ok(Some(cached)) => assert_eq!(cached.observations[0].value, "1.5"),
+            _ => assert_eq!(2, 1),
+        }
+        // the series was revised since it was cached
+        assert_eq!(load(&conn, "GNPCA", &key, "2020-04-29 07:51:02-05").map(|r| r.is_none()), Ok(true));
+    }
+}
@@ -2,6 +2,8 @@
 //! 
 //! [https://research.stlouisfed.org/docs/api/fred/category.html](https://research.stlouisfed.org/docs/api/fred/category.html)
 //! 
+//! The fred/category endpoint only takes a category id so, unlike the other category endpoints, it has no Builder.  Realtime arguments are available on the sub-endpoints such as [children](children/index.html), [related](related/index.html) and [series](series/index.html).
+//! 
 //! ```
 //! use fred_rs::client::FredClient;
 //! use fred_rs::category::Response;
@@ -37,16 +39,25 @@ pub mod related_tags;
 
 // -----------------------------------------------------------------------------
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::fmt::{self, Display, Formatter};
 
-#[derive(Deserialize, Clone, Debug, Default)]
+use crate::series::Series;
+
+#[derive(Deserialize, Serialize, Clone, Debug, Default)]
 /// Response data structure for a collection of categories
 /// 
 /// [https://research.stlouisfed.org/docs/api/fred/category.html] (https://research.stlouisfed.org/docs/api/fred/category.html)
 pub struct Response {
     /// List of categories returned by the query
+    #[serde(default, alias = "category")]
     pub categories: Vec<Category>,
+    /// Fields in the response that this crate does not parse yet
+    /// 
+    /// Only available with the `extra_fields` feature.
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 impl Display for Response {
@@ -65,7 +76,7 @@ impl Display for Response {
     }
 }
 
-#[derive(Deserialize, Clone, Debug, Default)]
+#[derive(Deserialize, Serialize, Clone, Debug, Default)]
 /// Data structure containing infomation about a particular category
 /// 
 /// [https://research.stlouisfed.org/docs/api/fred/category.html](https://research.stlouisfed.org/docs/api/fred/category.html)
@@ -86,6 +97,19 @@ impl Display for Category {
     }
 }
 
+#[derive(Serialize, Clone, Debug, Default)]
+/// A category together with its child categories and series
+/// 
+/// Returned by [FredClient::category_overview](../client/struct.FredClient.html#method.category_overview) and [AsyncFredClient::category_overview](../client/struct.AsyncFredClient.html#method.category_overview).
+pub struct CategoryOverview {
+    /// The requested category
+    pub category: Category,
+    /// The immediate child categories
+    pub children: Vec<Category>,
+    /// The first page of series in the category
+    pub series: Vec<Series>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -114,5 +138,58 @@ mod tests {
         for s in resp.categories {
             println!("ID: {}  Name: {}  ParentID: {}", s.id, s.name, s.parent_id);
         }
-    } 
-}
\ No newline at end of file
+    }
+
+    #[test]
+    fn category_overview() {
+        let mut c = match FredClient::new() {
+            Ok(c) => c,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+
+        let overview: CategoryOverview = match c.category_overview(125) {
+            Ok(overview) => overview,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+
+        assert_eq!(overview.category.id, 125);
+        for child in overview.children.iter() {
+            assert_eq!(child.parent_id, 125);
+        }
+        for s in overview.series {
+            println!("{}: {}", s.id, s.title);
+        }
+    }
+
+    #[test]
+    fn category_empty() {
+        // FRED leaves the list out of some responses with no results
+        let resp: Response = match serde_json::from_str(r#"{}"#) {
+            Ok(resp) => resp,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+        assert!(resp.categories.is_empty());
+
+        let resp: Response = match serde_json::from_str(r#"{"categories":[]}"#) {
+            Ok(resp) => resp,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+        assert!(resp.categories.is_empty());
+    }
+}
@@ -42,7 +42,9 @@
 //! }
 //! ```
 
-use crate::error::TAG_NAME_REQUIRED_ERROR_TEXT;
+pub use crate::common::SortOrder;
+use crate::encode::encode_query_value;
+use crate::error::{FredError, LIMIT_EXCEEDED_ERROR_TEXT, TAG_NAME_REQUIRED_ERROR_TEXT};
 
 /// Determines the order of search results
 /// 
@@ -56,16 +58,6 @@ pub enum OrderBy {
     GroupId,
 }
 
-/// Sort order options for the fred/category/related_tags endpoint
-/// 
-/// [https://research.stlouisfed.org/docs/api/fred/category_related_tags.html#sort_order](https://research.stlouisfed.org/docs/api/fred/category_related_tags.html#sort_order)
-pub enum SortOrder {
-    /// Results returned in ascending order (default)
-    Ascending,    
-    /// Results returned in descending order
-    Descending,   
-}
-
 /// A tag group id to filter tags by type
 /// 
 /// https://research.stlouisfed.org/docs/api/fred/category_related_tags.html#tag_group_id](https://research.stlouisfed.org/docs/api/fred/category_related_tags.html#tag_group_id)
@@ -79,6 +71,7 @@ pub enum TagGroupId {
     Source,
 }
 
+#[derive(Clone)]
 pub struct Builder {
     option_string: String,
     tag_names: String,
@@ -108,19 +101,38 @@ impl Builder {
         }
     }
 
-    /// Returns the current arguments as a URL formatted string
+    /// Removes all arguments from the builder so it can be reused
+    pub fn clear(&mut self) -> &mut Builder {
+        *self = Builder::new();
+        self
+    }
+
+    /// Returns the current arguments as a URL formatted string without consuming the builder
     /// 
     /// Returns Err if there are no tag names specified using tag_name().
-    pub(crate) fn build(mut self) -> Result<String, String> {
+    pub fn peek(&self) -> Result<String, String> {
+        let mut options = self.option_string.clone();
         if self.tag_names.len() > 0 {
-            self.option_string += format!("&tag_names={}", self.tag_names).as_str()
+            options += format!("&tag_names={}", self.tag_names).as_str()
         } else {
             return Err(String::from(TAG_NAME_REQUIRED_ERROR_TEXT));
         }
         if self.exclude_tags.len() > 0 {
-            self.option_string += format!("&exclude_tag_names={}", self.exclude_tags).as_str()
+            options += format!("&exclude_tag_names={}", self.exclude_tags).as_str()
         }
-        Ok(self.option_string)
+        Ok(options)
+    }
+
+    /// Returns the current arguments as a URL formatted string
+    pub(crate) fn build(self) -> Result<String, String> {
+        self.peek()
+    }
+
+    /// Consumes the builder and returns its arguments as a URL formatted string
+    /// 
+    /// This is the query string the client appends to the request URL, which can be logged or checked in tests without sending a request.  Returns Err if the arguments are invalid, as the client would.
+    pub fn into_query(self) -> Result<String, String> {
+        self.build()
     }
 
     /// Adds a realtime_start argument to the builder
@@ -130,7 +142,7 @@ impl Builder {
     /// 
     /// [https://research.stlouisfed.org/docs/api/fred/category_related_tags.html#realtime_start](https://research.stlouisfed.org/docs/api/fred/category_related_tags.html#realtime_start)
     pub fn realtime_start(&mut self, start_date: &str) -> &mut Builder {
-        self.option_string += format!("&realtime_start={}", start_date).as_str();
+        self.option_string += format!("&realtime_start={}", crate::common::date_arg(start_date)).as_str();
         self
     }
 
@@ -141,10 +153,12 @@ impl Builder {
     /// 
     /// [https://research.stlouisfed.org/docs/api/fred/category_related_tags.html#realtime_end](https://research.stlouisfed.org/docs/api/fred/category_related_tags.html#realtime_end)
     pub fn realtime_end(&mut self, end_date: &str) -> &mut Builder {
-        self.option_string += format!("&realtime_end={}", end_date).as_str();
+        self.option_string += format!("&realtime_end={}", crate::common::date_arg(end_date)).as_str();
         self
     }
 
+    realtime_period!();
+
     /// Adds a tag name to include in the search
     /// 
     /// Results must match all included tag names.
@@ -157,7 +171,7 @@ impl Builder {
         if self.tag_names.len() != 0 {
             self.tag_names.push(';');
         } 
-        self.tag_names += tag;
+        self.tag_names += encode_query_value(tag).as_str();
         self
     }
 
@@ -173,7 +187,7 @@ impl Builder {
         if self.exclude_tags.len() != 0 {
             self.exclude_tags.push(';');
         } 
-        self.exclude_tags += tag;
+        self.exclude_tags += encode_query_value(tag).as_str();
         self
     }
 
@@ -217,7 +231,7 @@ impl Builder {
     /// 
     /// [https://research.stlouisfed.org/docs/api/fred/category_related_tags.html#search_text](https://research.stlouisfed.org/docs/api/fred/category_related_tags.html#search_text)
     pub fn search_text(&mut self, search_string: &str) -> &mut Builder {
-        let search_string = search_string.replace(" ", "%20"); // encode for URL
+        let search_string = encode_query_value(search_string);
         self.option_string += format!("&tag_search_text={}", search_string).as_str();
         self
     }
@@ -240,6 +254,19 @@ impl Builder {
         self
     }
 
+    /// Adds a limit argument to the builder, returning Err rather than clamping
+    /// 
+    /// limit() silently clamps values above the endpoint maximum of 1,000, which returns fewer results than requested.  This function returns Err in that case so the condition can be detected.
+    /// 
+    /// # Arguments
+    /// * `num_results` - Maximum number of results to return
+    pub fn try_limit(&mut self, num_results: usize) -> Result<&mut Builder, FredError> {
+        if num_results > 1000 {
+            return Err(FredError::InvalidArgument(String::from(LIMIT_EXCEEDED_ERROR_TEXT)));
+        }
+        Ok(self.limit(num_results))
+    }
+
     /// Adds an offset argument to the builder
     /// 
     /// Adding an offset shifts the starting result number.  For example, if limit is 5 and offset is 0 then results 1-5 will be returned, but if offset was 5 then results 6-10 would be returned.
@@ -287,12 +314,7 @@ impl Builder {
     /// 
     /// [https://research.stlouisfed.org/docs/api/fred/category_related_tags.html#sort_order](https://research.stlouisfed.org/docs/api/fred/category_related_tags.html#sort_order)
     pub fn sort_order(&mut self, order: SortOrder) -> &mut Builder {
-        match order {
-            SortOrder::Descending => {
-                self.option_string += format!("&sort_order=desc").as_str()
-            },
-            _ => () // ASC is the default so do nothing
-        }
+        self.option_string += format!("&sort_order={}", order.as_param()).as_str();
         self
     }
 
@@ -303,6 +325,7 @@ mod tests {
     use super::*;
     use crate::tags::Response;
     use crate::client::FredClient;
+    use crate::error::FredError;
 
     #[test]
     fn category_related_tags_with_options_passing() {
@@ -360,10 +383,15 @@ mod tests {
 
         let _resp: Response = match c.category_related_tags(125, builder) {
             Ok(resp) => resp,
-            Err(msg) => {
+            Err(FredError::InvalidArgument(msg)) => {
                 assert_eq!(msg.as_str(), TAG_NAME_REQUIRED_ERROR_TEXT);
                 return
             },
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
         };
 
         assert_eq!(1, 2); // if the request succeeded then the test failed
@@ -41,6 +41,11 @@
 //! }
 //! ```
 
+pub use crate::common::{SeasonalAdjustment, SortOrder};
+pub use crate::series::observation::Frequency;
+use crate::encode::encode_query_value;
+use crate::error::{FredError, LIMIT_EXCEEDED_ERROR_TEXT};
+
 /// Determines the order of search results
 /// 
 /// [https://research.stlouisfed.org/docs/api/fred/category_series.html#order_by](https://research.stlouisfed.org/docs/api/fred/category_series.html#order_by)
@@ -60,16 +65,6 @@ pub enum OrderBy {
     GroupPopularity,
 }
 
-/// Sort order options for the fred/category/series endpoint
-/// 
-/// [https://research.stlouisfed.org/docs/api/fred/category_series.html#sort_order](https://research.stlouisfed.org/docs/api/fred/category_series.html#sort_order)
-pub enum SortOrder {
-    /// Dates returned in ascending order (default)
-    Ascending,    
-    /// Dates returned in descending order
-    Descending,   
-}
-
 /// Apply result filter
 /// 
 /// This should be used in conjunction with the filter_value argument to filter results based on one (maybe more than one?) of the fields.
@@ -81,6 +76,7 @@ pub enum FilterVariable {
     SeasonalAdjustment,
 }
 
+#[derive(Clone)]
 pub struct Builder {
     option_string: String,
     include_tags: String,
@@ -110,15 +106,34 @@ impl Builder {
         }
     }
 
-    /// Returns the current arguments as a URL formatted string
-    pub(crate) fn build(mut self) -> String {
+    /// Removes all arguments from the builder so it can be reused
+    pub fn clear(&mut self) -> &mut Builder {
+        *self = Builder::new();
+        self
+    }
+
+    /// Returns the current arguments as a URL formatted string without consuming the builder
+    pub fn peek(&self) -> String {
+        let mut options = self.option_string.clone();
         if self.include_tags.len() > 0 {
-            self.option_string += format!("&tag_names={}", self.include_tags).as_str()
+            options += format!("&tag_names={}", self.include_tags).as_str()
         }
         if self.exclude_tags.len() > 0 {
-            self.option_string += format!("&exclude_tag_names={}", self.exclude_tags).as_str()
+            options += format!("&exclude_tag_names={}", self.exclude_tags).as_str()
         }
-        self.option_string
+        options
+    }
+
+    /// Returns the current arguments as a URL formatted string
+    pub(crate) fn build(self) -> String {
+        self.peek()
+    }
+
+    /// Consumes the builder and returns its arguments as a URL formatted string
+    /// 
+    /// This is the query string the client appends to the request URL, which can be logged or checked in tests without sending a request.
+    pub fn into_query(self) -> String {
+        self.build()
     }
 
     /// Adds a realtime_start argument to the builder
@@ -128,7 +143,7 @@ impl Builder {
     /// 
     /// [https://research.stlouisfed.org/docs/api/fred/category_series.html#realtime_start](https://research.stlouisfed.org/docs/api/fred/category_series.html#realtime_start)
     pub fn realtime_start(&mut self, start_date: &str) -> &mut Builder {
-        self.option_string += format!("&realtime_start={}", start_date).as_str();
+        self.option_string += format!("&realtime_start={}", crate::common::date_arg(start_date)).as_str();
         self
     }
 
@@ -139,10 +154,12 @@ impl Builder {
     /// 
     /// [https://research.stlouisfed.org/docs/api/fred/category_series.html#realtime_end](https://research.stlouisfed.org/docs/api/fred/category_series.html#realtime_end)
     pub fn realtime_end(&mut self, end_date: &str) -> &mut Builder {
-        self.option_string += format!("&realtime_end={}", end_date).as_str();
+        self.option_string += format!("&realtime_end={}", crate::common::date_arg(end_date)).as_str();
         self
     }
 
+    realtime_period!();
+
     /// Adds a limit argument to the builder
     /// 
     /// The limit argument specifies a maximum number of observations to return.
@@ -161,6 +178,19 @@ impl Builder {
         self
     }
 
+    /// Adds a limit argument to the builder, returning Err rather than clamping
+    /// 
+    /// limit() silently clamps values above the endpoint maximum of 1,000, which returns fewer results than requested.  This function returns Err in that case so the condition can be detected.
+    /// 
+    /// # Arguments
+    /// * `num_results` - Maximum number of results to return
+    pub fn try_limit(&mut self, num_results: usize) -> Result<&mut Builder, FredError> {
+        if num_results > 1000 {
+            return Err(FredError::InvalidArgument(String::from(LIMIT_EXCEEDED_ERROR_TEXT)));
+        }
+        Ok(self.limit(num_results))
+    }
+
     /// Adds an offset argument to the builder
     /// 
     /// Adding an offset shifts the starting result number.  For example, if limit is 5 and offset is 0 then results 1-5 will be returned, but if offset was 5 then results 6-10 would be returned.
@@ -229,12 +259,7 @@ impl Builder {
     /// 
     /// [https://research.stlouisfed.org/docs/api/fred/category_series.html#sort_order](https://research.stlouisfed.org/docs/api/fred/category_series.html#sort_order)
     pub fn sort_order(&mut self, order: SortOrder) -> &mut Builder {
-        match order {
-            SortOrder::Descending => {
-                self.option_string += format!("&sort_order=desc").as_str()
-            },
-            _ => () // ASC is the default so do nothing
-        }
+        self.option_string += format!("&sort_order={}", order.as_param()).as_str();
         self
     }
 
@@ -268,10 +293,49 @@ impl Builder {
     /// 
     /// [https://research.stlouisfed.org/docs/api/fred/category_series.html#filter_value](https://research.stlouisfed.org/docs/api/fred/category_series.html#filter_value)
     pub fn filter_value(&mut self, val: &str) -> &mut Builder {
-        self.option_string += format!("&filter_value={}", val).as_str();
+        self.option_string += format!("&filter_value={}", encode_query_value(val)).as_str();
         self
     }
 
+    /// Filters the results to series with a frequency
+    /// 
+    /// Sets filter_variable to frequency and filter_value to the name FRED uses for the frequency (e.g. Frequency::M is "Monthly").
+    /// 
+    /// # Arguments
+    /// * `freq` - the frequency of the series to return
+    /// 
+    /// [https://research.stlouisfed.org/docs/api/fred/category_series.html#filter_value](https://research.stlouisfed.org/docs/api/fred/category_series.html#filter_value)
+    pub fn frequency_filter(&mut self, freq: Frequency) -> &mut Builder {
+        self.filter_variable(FilterVariable::Frequency);
+        self.filter_value(freq.name())
+    }
+
+    /// Filters the results to series with a units description
+    /// 
+    /// Sets filter_variable to units and filter_value to `units`, which is encoded for the URL.
+    /// 
+    /// # Arguments
+    /// * `units` - the units of the series to return as they appear in series metadata (e.g. "Percent")
+    /// 
+    /// [https://research.stlouisfed.org/docs/api/fred/category_series.html#filter_value](https://research.stlouisfed.org/docs/api/fred/category_series.html#filter_value)
+    pub fn units_filter(&mut self, units: &str) -> &mut Builder {
+        self.filter_variable(FilterVariable::Units);
+        self.filter_value(units)
+    }
+
+    /// Filters the results to series with a seasonal adjustment
+    /// 
+    /// Sets filter_variable to seasonal_adjustment and filter_value to the name FRED uses for the adjustment (e.g. SeasonalAdjustment::NSA is "Not Seasonally Adjusted").
+    /// 
+    /// # Arguments
+    /// * `adj` - the seasonal adjustment of the series to return
+    /// 
+    /// [https://research.stlouisfed.org/docs/api/fred/category_series.html#filter_value](https://research.stlouisfed.org/docs/api/fred/category_series.html#filter_value)
+    pub fn seasonal_adjustment_filter(&mut self, adj: SeasonalAdjustment) -> &mut Builder {
+        self.filter_variable(FilterVariable::SeasonalAdjustment);
+        self.filter_value(adj.name())
+    }
+
     /// Adds a tag name to include in the search
     /// 
     /// Results must match all included tag names.
@@ -284,7 +348,7 @@ impl Builder {
         if self.include_tags.len() != 0 {
             self.include_tags.push(';');
         } 
-        self.include_tags += tag;
+        self.include_tags += encode_query_value(tag).as_str();
         self
     }
 
@@ -300,7 +364,7 @@ impl Builder {
         if self.exclude_tags.len() != 0 {
             self.exclude_tags.push(';');
         } 
-        self.exclude_tags += tag;
+        self.exclude_tags += encode_query_value(tag).as_str();
         self
     }
 
@@ -346,5 +410,64 @@ mod tests {
                 item.popularity,
             );
         }
-    } 
-}
\ No newline at end of file
+    }
+
+    #[test]
+    fn category_series_exclude_tag_names() {
+        let mut builder = Builder::new();
+        builder
+            .limit(5)
+            .tag_name("usa")
+            .exclude_tag("quarterly")
+            .exclude_tag("annual");
+
+        assert_eq!(
+            builder.build(),
+            "&limit=5&tag_names=usa&exclude_tag_names=quarterly;annual"
+        );
+    }
+
+    #[test]
+    fn category_series_exclude_tag_names_request() {
+        let mut c = match FredClient::new() {
+            Ok(c) => c,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+
+        let mut builder = Builder::new();
+        builder.exclude_tag("quarterly");
+
+        let resp: Response = match c.category_series(125, Some(builder)) {
+            Ok(resp) => resp,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+
+        assert!(!resp.seriess.is_empty());
+        for item in resp.seriess {
+            assert_ne!(item.frequency_short, "Q");
+        }
+    }
+
+    #[test]
+    fn category_series_typed_filters() {
+        let mut builder = Builder::new();
+        builder.frequency_filter(Frequency::WEF);
+        assert_eq!(builder.peek(), "&filter_variable=frequency&filter_value=Weekly%2C%20Ending%20Friday");
+
+        let mut builder = Builder::new();
+        builder.units_filter("Percent");
+        assert_eq!(builder.peek(), "&filter_variable=units&filter_value=Percent");
+
+        let mut builder = Builder::new();
+        builder.seasonal_adjustment_filter(SeasonalAdjustment::NSA);
+        assert_eq!(builder.peek(), "&filter_variable=seasonal_adjustment&filter_value=Not%20Seasonally%20Adjusted");
+    }
+}
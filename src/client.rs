@@ -33,23 +33,87 @@
 //! ```
 
 use reqwest::blocking::{Client, Response};
+use reqwest::{header, StatusCode};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 
-use std::time::Duration;
-use std::env;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::io::Write;
+use std::time::{Duration, Instant};
+use std::thread;
 
 use crate::*;
+use crate::common::{check_date_args, timestamp_seconds, Paged};
+use crate::error::FredError;
+use crate::format::{FileType, Json, ResponseFormat};
+
+mod builder;
+mod endpoint;
+mod paging;
+#[cfg(feature = "async")]
+mod async_client;
+
+pub use builder::FredClientBuilder;
+#[cfg(feature = "async")]
+pub use async_client::AsyncFredClient;
 
 const FRED_BASE_URL: &str = "https://api.stlouisfed.org/fred/";
 const FRED_API_KEY: &str = "FRED_API_KEY";
+const PAGE_LIMIT: usize = 1000;
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(250);
 
 #[derive(Clone, Debug)]
 /// Persistent client object used to access the FRED API
 /// 
 /// Each method for the client represents a data endpoint provided by the API and will return a data object representing the response contents.
-pub struct FredClient {
+/// 
+/// Responses are parsed with the client's [ResponseFormat](../format/trait.ResponseFormat.html), which is [Json](../format/struct.Json.html) unless changed with [with_format](#method.with_format).
+pub struct FredClient<R: ResponseFormat = Json> {
     client: Client,
-    url_base: &'static str,
+    url_base: String,
     api_key: String,
+    timeout: Duration,
+    retries: u32,
+    limiter: Option<RateLimiter>,
+    format: R,
+}
+
+#[derive(Serialize, Clone, Debug, PartialEq)]
+/// Settings of a client, excluding its API key
+/// 
+/// Returned by [FredClient::config](struct.FredClient.html#method.config) so the configuration of a run can be logged or saved alongside its results.
+pub struct FredClientConfig {
+    /// The URL every request is made relative to
+    pub url_base: String,
+    /// The request timeout in seconds
+    pub timeout_secs: u64,
+    /// The number of times a request is retried after a transient failure
+    pub retries: u32,
+    /// The maximum number of requests made per minute, if limited
+    pub rate_limit: Option<u32>,
+    /// The file_type argument sent with every request
+    pub file_type: String,
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+/// Results gathered from every page of a paged endpoint
+/// 
+/// Returned by the client helpers which make repeated requests to collect all of the results for a query (e.g. [series_search_all](struct.FredClient.html#method.series_search_all)).
+pub struct Collected<T> {
+    /// The results from every page that was requested
+    pub items: Vec<T>,
+    /// True if paging stopped at the result budget before all results were collected
+    pub truncated: bool,
+}
+
+#[derive(Debug, Default)]
+/// The titles found by [series_titles](struct.FredClient.html#method.series_titles) and the ids it could not resolve
+pub struct SeriesTitles {
+    /// Map from series id to title
+    pub titles: HashMap<String, String>,
+    /// The ids which could not be resolved, each with the error it failed with
+    pub failures: Vec<(String, FredError)>,
 }
 
 impl FredClient {
@@ -71,34 +135,124 @@ impl FredClient {
     ///     },
     /// };
     /// ```
-    pub fn new() -> Result<FredClient, String> {
-
-        let client = match Client::builder().timeout(Duration::from_secs(30)).build() {
-            Ok(c) => c,
-            Err(msg) => return Err(msg.to_string()),
-        };
-
-        let api_key = match env::var(FRED_API_KEY) {
-            Ok(val) => val,
-            Err(_) => String::from(""),
-        };
+    pub fn new() -> Result<FredClient, FredError> {
 
-        let fred = FredClient {
-            client,
-            url_base: FRED_BASE_URL,
-            api_key,
+        let fred = match FredClient::new_offline() {
+            Ok(fred) => fred,
+            Err(e) => return Err(e),
         };
 
-        let url = format!("{}category?category_id=125&api_key={}&file_type=json", fred.url_base, fred.api_key);
+        let url = format!("{}category?category_id=125&api_key={}&file_type={}", fred.url_base, fred.api_key, fred.format.file_type());
         match fred.client.get(url.as_str()).send() {
             Ok(_) => (),
-            Err(msg) => return Err(msg.to_string()),
+            Err(e) => return Err(FredError::Network(e)),
         }
 
         return Ok(fred)
 
     }
 
+    /// Creates a new client object without checking the connection to the FRED API
+    /// 
+    /// Behaves like [new](#method.new) but makes no request, so the first request the client makes is the first contact with FRED.  This is useful offline and in tests.  Use [FredClientBuilder](struct.FredClientBuilder.html) to change other settings.
+    /// 
+    /// Returns Err if the underlying HTTP client cannot be created.
+    /// 
+    /// ```
+    /// use fred_rs::client::FredClient;
+    /// 
+    /// let mut client = match FredClient::new_offline() {
+    ///     Ok(c) => c,
+    ///     Err(msg) => {
+    ///         println!("{}", msg);
+    ///         return
+    ///     },
+    /// };
+    /// ```
+    pub fn new_offline() -> Result<FredClient, FredError> {
+        FredClientBuilder::new().build()
+    }
+
+}
+
+impl<R: ResponseFormat> FredClient<R> {
+
+    /// Changes the format the client requests and parses responses with
+    /// 
+    /// # Arguments
+    /// * `format` - The [ResponseFormat](../format/trait.ResponseFormat.html) to use for subsequent requests
+    /// 
+    /// ```
+    /// use fred_rs::client::FredClient;
+    /// use fred_rs::format::Json;
+    /// 
+    /// let client = match FredClient::new() {
+    ///     Ok(c) => c.with_format(Json),
+    ///     Err(msg) => {
+    ///         println!("{}", msg);
+    ///         return
+    ///     },
+    /// };
+    /// ```
+    pub fn with_format<G: ResponseFormat>(self, format: G) -> FredClient<G> {
+        FredClient {
+            client: self.client,
+            url_base: self.url_base,
+            api_key: self.api_key,
+            timeout: self.timeout,
+            retries: self.retries,
+            limiter: self.limiter,
+            format,
+        }
+    }
+
+    /// Changes the file type the client requests, see [FileType](../format/enum.FileType.html)
+    /// 
+    /// # Arguments
+    /// * `file_type` - The file type to use for subsequent requests
+    /// 
+    /// ```
+    /// use fred_rs::client::FredClient;
+    /// use fred_rs::format::FileType;
+    /// 
+    /// let client = match FredClient::new() {
+    ///     Ok(c) => c.with_file_type(FileType::Json),
+    ///     Err(msg) => {
+    ///         println!("{}", msg);
+    ///         return
+    ///     },
+    /// };
+    /// ```
+    pub fn with_file_type(self, file_type: FileType) -> FredClient<FileType> {
+        self.with_format(file_type)
+    }
+
+    /// Returns the settings of the client, without the API key
+    /// 
+    /// ```
+    /// use fred_rs::client::FredClient;
+    /// 
+    /// let client = match FredClient::new() {
+    ///     Ok(c) => c,
+    ///     Err(msg) => {
+    ///         println!("{}", msg);
+    ///         return
+    ///     },
+    /// };
+    /// 
+    /// let config = client.config();
+    /// println!("{}", serde_json::to_string(&config).unwrap());
+    /// ```
+    pub fn config(&self) -> FredClientConfig {
+        FredClientConfig {
+            url_base: self.url_base.clone(),
+            timeout_secs: self.timeout.as_secs(),
+            retries: self.retries,
+            rate_limit: self.limiter.as_ref().map(|l| l.per_minute),
+            file_type: String::from(self.format.file_type()),
+        }
+    }
+
     /// Sets the FRED API key for the client
     /// 
     /// # Arguments
@@ -121,11 +275,160 @@ impl FredClient {
         self.api_key = String::from(key);
     }
 
-    fn get_request(&mut self, url: &str) -> Result<Response, String> {
-        match self.client.get(url).send() {
-            Ok(r) => Ok(r),
-            Err(msg) => Err(msg.to_string()),
+    /// Sets the URL every request is made relative to
+    /// 
+    /// The default is https://api.stlouisfed.org/fred/.  Pointing the client at a local mock server lets tests run against recorded responses without network access or an API key.  A trailing slash is added if `url` does not end with one.
+    /// 
+    /// # Arguments
+    /// * `url` - The base URL, e.g. http://127.0.0.1:8080/fred/
+    /// 
+    /// ```
+    /// use fred_rs::client::FredClient;
+    /// 
+    /// let mut client = match FredClient::new_offline() {
+    ///     Ok(c) => c,
+    ///     Err(msg) => {
+    ///         println!("{}", msg);
+    ///         return
+    ///     },
+    /// };
+    /// 
+    /// client.with_base_url("http://127.0.0.1:8080/fred");
+    /// ```
+    pub fn with_base_url(&mut self, url: &str) {
+        self.url_base = base_url(url);
+    }
+
+    /// Sets how many times a request is retried after a transient failure
+    /// 
+    /// Requests answered with a 5xx status, and requests that fail to connect or time out, are repeated after waiting 250ms, 500ms, 1s and so on.  Requests answered with 429 (too many requests) are repeated after the delay given by the Retry-After header, or the same backoff if there is none.  Other failures, including other 4xx responses, are returned immediately.  When every retry fails the last error is returned.  The default is 0 retries.
+    /// 
+    /// # Arguments
+    /// * `max` - The maximum number of retries for each request
+    /// 
+    /// ```
+    /// use fred_rs::client::FredClient;
+    /// 
+    /// let mut client = match FredClient::new() {
+    ///     Ok(c) => c,
+    ///     Err(msg) => {
+    ///         println!("{}", msg);
+    ///         return
+    ///     },
+    /// };
+    /// 
+    /// client.with_retries(3);
+    /// ```
+    pub fn with_retries(&mut self, max: u32) {
+        self.retries = max;
+    }
+
+    /// Limits how many requests the client makes per minute
+    /// 
+    /// FRED allows roughly 120 requests per minute.  Once `per_minute` requests have been made within the last minute, the next request sleeps until the oldest of them is a minute old.  A limit of 0 turns rate limiting off, which is the default.
+    /// 
+    /// If FRED still answers with 429 (too many requests) the request is retried after the delay given by the Retry-After header.  A rate limited client retries a 429 at least once even if [with_retries](#method.with_retries) has not been called.
+    /// 
+    /// # Arguments
+    /// * `per_minute` - The maximum number of requests to make in any minute
+    /// 
+    /// ```
+    /// use fred_rs::client::FredClient;
+    /// 
+    /// let mut client = match FredClient::new() {
+    ///     Ok(c) => c,
+    ///     Err(msg) => {
+    ///         println!("{}", msg);
+    ///         return
+    ///     },
+    /// };
+    /// 
+    /// client.with_rate_limit(120);
+    /// ```
+    pub fn with_rate_limit(&mut self, per_minute: u32) {
+        self.limiter = match per_minute {
+            0 => None,
+            n => Some(RateLimiter::new(n)),
+        };
+    }
+
+    /// Returns how many times a request answered with 429 (too many requests) is retried
+    fn throttled_retries(&self) -> u32 {
+        match self.limiter {
+            Some(_) => self.retries.max(1),
+            None => self.retries,
+        }
+    }
+
+    fn get_request(&mut self, url: &str) -> Result<Response, FredError> {
+        match check_date_args(url) {
+            Ok(()) => (),
+            Err(e) => return Err(e),
+        }
+        let url = request_url(url, self.format.file_type());
+        let mut attempt = 0;
+        loop {
+            match &mut self.limiter {
+                Some(limiter) => limiter.acquire(),
+                None => (),
+            }
+
+            let retry = attempt < self.retries;
+            let retry_throttled = attempt < self.throttled_retries();
+            let mut delay = retry_delay(attempt);
+            match self.client.get(url.as_str()).send() {
+                Ok(resp) if retry_throttled && resp.status() == StatusCode::TOO_MANY_REQUESTS => {
+                    match retry_after(resp.headers()) {
+                        Some(wait) => delay = wait,
+                        None => (),
+                    }
+                },
+                Ok(resp) if retry && resp.status().is_server_error() => (),
+                Ok(resp) => {
+                    return match check_content_type(resp.headers(), resp.status(), self.format.file_type()) {
+                        Ok(()) => Ok(resp),
+                        Err(msg) => Err(msg),
+                    };
+                },
+                Err(e) if retry && (e.is_connect() || e.is_timeout()) => (),
+                Err(e) => return Err(FredError::Network(e)),
+            }
+            thread::sleep(delay);
+            attempt += 1;
+        }
+    }
+
+    fn fetch<T: DeserializeOwned>(&mut self, url: &str) -> Result<T, FredError> {
+        if self.api_key.is_empty() {
+            return Err(FredError::MissingApiKey);
+        }
+
+        let resp = match self.get_request(url) {
+            Ok(resp) => resp,
+            Err(e) => return Err(e),
+        };
+        let bytes = match resp.bytes() {
+            Ok(bytes) => bytes,
+            Err(e) => return Err(FredError::Network(e)),
+        };
+        parse_body(&self.format, &bytes)
+    }
+
+    /// Requests pages of `url` until every result has been collected or `max_results` is reached
+    fn collect_pages<P: paging::Page>(
+        &mut self,
+        url: &str,
+        max_results: Option<usize>
+    ) -> Result<Collected<P::Item>, FredError> {
+        let mut pager = paging::Pager::new(max_results);
+        while let Some(page_url) = pager.next_url(url) {
+            let page: P = match self.fetch(page_url.as_str()) {
+                Ok(page) => page,
+                Err(msg) => return Err(msg),
+            };
+            pager.add(page);
         }
+        Ok(pager.finish())
     }
 
     // ----------------------------------------------------------------------
@@ -133,47 +436,18 @@ impl FredClient {
 
     /// [See fred_rs::series](../series/index.html)
     /// 
+    /// Returns Err without making a request if `series_id` is empty.
+    /// 
     /// # Arguments
     /// `series_id` - The id for a series [[Link]](https://research.stlouisfed.org/docs/api/fred/series.html#series_id)
     pub fn series(
         &mut self,
         series_id: &str,
         builder: Option<series::Builder>
-    ) -> Result<series::Response, String> {
-        let mut url: String = format!(
-            "{}series?series_id={}&api_key={}&file_type=json",
-            self.url_base,
-            series_id,
-            self.api_key
-        );
-
-        match builder {
-            Some(b) => url.push_str(b.build().as_str()),
-            None => (),
-        }
-
-        match self.get_request(url.as_str()) {
-            Ok(resp) => {
-                let text = resp.text().unwrap();
-                match serde_json::from_str(&text) {
-                    Ok(val) => Ok(val),
-                    Err(_e) => {
-                        match serde_json::from_str(&text) {
-                            Ok(e) => {
-                                let err: error::FredError = e;
-                                let err_msg = format!(
-                                    "ERROR {}: {}",
-                                    err.error_code,
-                                    err.error_message
-                                );
-                                return Err(err_msg);
-                            },
-                            Err(msg) => return Err(String::from(msg.to_string())),
-                        }
-                    },
-                }
-            },
-            Err(e) => return Err(e.to_string()),
+    ) -> Result<series::Response, FredError> {
+        match endpoint::series(&self.url_base, &self.api_key, series_id, builder) {
+            Ok(url) => self.fetch(url.as_str()),
+            Err(msg) => Err(msg),
         }
     }
 
@@ -185,41 +459,10 @@ impl FredClient {
         &mut self,
         series_id: &str,
         builder: Option<series::categories::Builder>
-    ) -> Result<category::Response, String> {
-        let mut url: String = format!(
-            "{}series/categories?series_id={}&api_key={}&file_type=json",
-            self.url_base,
-            series_id,
-            self.api_key
-        );
-
-        match builder {
-            Some(b) => url.push_str(b.build().as_str()),
-            None => (),
-        }
-
-        match self.get_request(url.as_str()) {
-            Ok(resp) => {
-                let text = resp.text().unwrap();
-                match serde_json::from_str(&text) {
-                    Ok(val) => Ok(val),
-                    Err(_e) => {
-                        match serde_json::from_str(&text) {
-                            Ok(e) => {
-                                let err: error::FredError = e;
-                                let err_msg = format!(
-                                    "ERROR {}: {}",
-                                    err.error_code,
-                                    err.error_message
-                                );
-                                return Err(err_msg);
-                            },
-                            Err(msg) => return Err(String::from(msg.to_string())),
-                        }
-                    },
-                }
-            },
-            Err(e) => return Err(e.to_string()),
+    ) -> Result<category::Response, FredError> {
+        match endpoint::series_categories(&self.url_base, &self.api_key, series_id, builder) {
+            Ok(url) => self.fetch(url.as_str()),
+            Err(msg) => Err(msg),
         }
     }
 
@@ -231,1278 +474,1445 @@ impl FredClient {
         &mut self,
         series_id: &str,
         builder: Option<series::observation::Builder>
-    ) -> Result<series::observation::Response, String> {
-        let mut url: String = format!(
-            "{}series/observations?series_id={}&api_key={}&file_type=json",
-            self.url_base,
-            series_id,
-            self.api_key
-        );
+    ) -> Result<series::observation::Response, FredError> {
+        let all = match &builder {
+            Some(b) => b.is_all(),
+            None => false,
+        };
+        let url = match endpoint::series_observation(&self.url_base, &self.api_key, series_id, builder) {
+            Ok(url) => url,
+            Err(msg) => return Err(msg),
+        };
 
-        match builder {
-            Some(b) => url.push_str(b.build().as_str()),
-            None => (),
+        if all {
+            return self.series_observation_pages(url.as_str());
         }
 
-        match self.get_request(url.as_str()) {
-            Ok(resp) => {
-                let text = resp.text().unwrap();
-                match serde_json::from_str(&text) {
-                    Ok(val) => Ok(val),
-                    Err(_e) => {
-                        match serde_json::from_str(&text) {
-                            Ok(e) => {
-                                let err: error::FredError = e;
-                                let err_msg = format!(
-                                    "ERROR {}: {}",
-                                    err.error_code,
-                                    err.error_message
-                                );
-                                return Err(err_msg);
-                            },
-                            Err(msg) => return Err(String::from(msg.to_string())),
-                        }
-                    },
-                }
-            },
-            Err(e) => return Err(e.to_string()),
-        }
+        self.fetch(url.as_str())
     }
 
-    /// [See fred_rs::series::release](../series/release/index.html)
+    /// Requests every page of observations for `url` and merges them into a single response
     /// 
-    /// # Arguments
-    /// `series_id` - The id for a series [[Link]](https://research.stlouisfed.org/docs/api/fred/series_release.html#series_id)
-    pub fn series_release(
+    /// The metadata of the first page is kept with the limit and offset updated to describe the merged observations.
+    fn series_observation_pages(
         &mut self,
-        series_id: &str,
-        builder: Option<series::release::Builder>
-    ) -> Result<release::Response, String> {
-        let mut url: String = format!(
-            "{}series/release?series_id={}&api_key={}&file_type=json",
-            self.url_base,
-            series_id,
-            self.api_key
-        );
+        url: &str
+    ) -> Result<series::observation::Response, FredError> {
+        let first_url = endpoint::observation_page(url, 0);
+        let mut resp: series::observation::Response = match self.fetch(first_url.as_str()) {
+            Ok(resp) => resp,
+            Err(msg) => return Err(msg),
+        };
 
-        match builder {
-            Some(b) => url.push_str(b.build().as_str()),
-            None => (),
+        while resp.observations.len() < resp.count {
+            let page_url = endpoint::observation_page(url, resp.observations.len());
+            let page: series::observation::Response = match self.fetch(page_url.as_str()) {
+                Ok(page) => page,
+                Err(msg) => return Err(msg),
+            };
+            if page.observations.is_empty() {
+                break;
+            }
+            resp.observations.extend(page.observations);
         }
 
-        match self.get_request(url.as_str()) {
-            Ok(resp) => {
-                let text = resp.text().unwrap();
-                match serde_json::from_str(&text) {
-                    Ok(val) => Ok(val),
-                    Err(_e) => {
-                        match serde_json::from_str(&text) {
-                            Ok(e) => {
-                                let err: error::FredError = e;
-                                let err_msg = format!(
-                                    "ERROR {}: {}",
-                                    err.error_code,
-                                    err.error_message
-                                );
-                                return Err(err_msg);
-                            },
-                            Err(msg) => return Err(String::from(msg.to_string())),
-                        }
-                    },
-                }
-            },
-            Err(e) => return Err(e.to_string()),
-        }
+        resp.offset = 0;
+        resp.limit = resp.observations.len();
+        Ok(resp)
     }
-    
-    /// [See fred_rs::series::tags](../series/tags/index.html)
+
+    /// Fetches observations, falling back to Units::LIN if the requested units return only missing values
+    /// 
+    /// Transforms such as PC1 need more history than some series have and FRED answers them with "." for every observation.  In that case the request is repeated without the units transform.  The returned [FallbackResponse](../series/observation/struct.FallbackResponse.html) records the units that were ultimately used and whether a fallback occurred.
     /// 
     /// # Arguments
-    /// `series_id` - The id for a series [[Link]](https://research.stlouisfed.org/docs/api/fred/series_tags.html#series_id)
-    pub fn series_tags(
+    /// `series_id` - The id for a series [[Link]](https://research.stlouisfed.org/docs/api/fred/series_observation.html#series_id)
+    pub fn series_observation_with_fallback(
         &mut self,
         series_id: &str,
-        builder: Option<series::tags::Builder>
-    ) -> Result<tags::Response, String> {
+        builder: Option<series::observation::Builder>
+    ) -> Result<series::observation::FallbackResponse, FredError> {
+        let fallback = match &builder {
+            Some(b) => b.without_units(),
+            None => None,
+        };
 
-        let mut url: String = format!(
-            "{}series/tags?series_id={}&api_key={}&file_type=json",
-            self.url_base,
-            series_id,
-            self.api_key
-        );
+        let resp = match self.series_observation(series_id, builder) {
+            Ok(resp) => resp,
+            Err(msg) => return Err(msg),
+        };
 
-        match builder {
-            Some(b) => url.push_str(b.build().as_str()),
-            None => (),
-        }
-        
-        match self.get_request(url.as_str()) {
-            Ok(resp) => {
-                let text = resp.text().unwrap();
-                match serde_json::from_str(&text) {
-                    Ok(val) => Ok(val),
-                    Err(_e) => {
-                        match serde_json::from_str(&text) {
-                            Ok(e) => {
-                                let err: error::FredError = e;
-                                let err_msg = format!(
-                                    "ERROR {}: {}",
-                                    err.error_code,
-                                    err.error_message
-                                );
-                                return Err(err_msg);
-                            },
-                            Err(msg) => return Err(String::from(msg.to_string())),
-                        }
-                    },
+        match fallback {
+            Some(b) if !resp.observations.is_empty() && resp.missing_ratio() == 1.0 => {
+                match self.series_observation(series_id, Some(b)) {
+                    Ok(resp) => Ok(series::observation::FallbackResponse {
+                        units: resp.units.clone(),
+                        response: resp,
+                        fell_back: true,
+                    }),
+                    Err(msg) => Err(msg),
                 }
             },
-            Err(e) => return Err(e.to_string()),
+            _ => Ok(series::observation::FallbackResponse {
+                units: resp.units.clone(),
+                response: resp,
+                fell_back: false,
+            }),
         }
     }
 
-    /// [See fred_rs::series::updates](../series/updates/index.html)
-    pub fn series_updates(
+    /// Fetches observations through a local SQLite cache
+    /// 
+    /// Cached responses are keyed by the series id and the builder arguments.  The series metadata is requested each time and a cached response is returned only if the series' last_updated time has not changed since it was stored.  Otherwise the observations are requested and the cache is updated.  The database and its table are created if they do not exist.
+    /// 
+    /// Only available with the `sqlite` feature.
+    /// 
+    /// # Arguments
+    /// `series_id` - The id for a series [[Link]](https://research.stlouisfed.org/docs/api/fred/series_observation.html#series_id)
+    /// 
+    /// `db_path` - Path to the SQLite database file
+    #[cfg(feature = "sqlite")]
+    pub fn series_observation_cached(
         &mut self,
-        builder: Option<series::updates::Builder>
-    ) -> Result<series::updates::Response, String> {
+        series_id: &str,
+        builder: Option<series::observation::Builder>,
+        db_path: &str,
+    ) -> Result<series::observation::Response, FredError> {
+        let key = match cache_key(builder.as_ref()) {
+            Ok(key) => key,
+            Err(e) => return Err(e),
+        };
 
-        let mut url: String = format!(
-            "{}series/updates?api_key={}&file_type=json",
-            self.url_base,
-            self.api_key
-        );
+        let last_updated = match self.series(series_id, None) {
+            Ok(resp) => match resp.seriess.into_iter().next() {
+                Some(meta) => meta.last_updated,
+                None => String::new(),
+            },
+            Err(msg) => return Err(msg),
+        };
 
-        match builder {
-            Some(b) => url.push_str(b.build().as_str()),
-            None => (),
+        let conn = match cache::open(db_path) {
+            Ok(conn) => conn,
+            Err(msg) => return Err(FredError::Cache(msg)),
+        };
+        match cache::load(&conn, series_id, &key, &last_updated) {
+            Ok(Some(resp)) => return Ok(resp),
+            Ok(None) => (),
+            Err(msg) => return Err(FredError::Cache(msg)),
         }
-        
-        match self.get_request(url.as_str()) {
-            Ok(resp) => {
-                let text = resp.text().unwrap();
-                match serde_json::from_str(&text) {
-                    Ok(val) => Ok(val),
-                    Err(_e) => {
-                        match serde_json::from_str(&text) {
-                            Ok(e) => {
-                                let err: error::FredError = e;
-                                let err_msg = format!(
-                                    "ERROR {}: {}",
-                                    err.error_code,
-                                    err.error_message
-                                );
-                                return Err(err_msg);
-                            },
-                            Err(msg) => return Err(String::from(msg.to_string())),
-                        }
-                    },
-                }
-            },
-            Err(e) => return Err(e.to_string()),
+
+        let resp = match self.series_observation(series_id, builder) {
+            Ok(resp) => resp,
+            Err(msg) => return Err(msg),
+        };
+        match cache::store(&conn, series_id, &key, &last_updated, &resp) {
+            Ok(()) => Ok(resp),
+            Err(msg) => Err(FredError::Cache(msg)),
         }
     }
 
-    /// [See fred_rs::series::vintagedates](../series/vintagedates/index.html)
+    /// Fetches observations both untransformed and with a units transform
+    /// 
+    /// Makes two requests with the same arguments, the first with Units::LIN and the second with `units`, so a level and its growth rate (for example) can be shown side by side.  Any units set on the builder are replaced.  Both responses are trimmed to the observation dates present in both so their observations line up one to one.
     /// 
     /// # Arguments
-    /// `series_id` - The id for a series [[Link]](https://research.stlouisfed.org/docs/api/fred/series_vintagedates.html#series_id)
-    pub fn series_vintagedates(
+    /// `series_id` - The id for a series [[Link]](https://research.stlouisfed.org/docs/api/fred/series_observation.html#series_id)
+    /// 
+    /// `units` - The units transform of the second response [[Link]](https://research.stlouisfed.org/docs/api/fred/series_observation.html#units)
+    pub fn series_observation_dual(
         &mut self,
         series_id: &str,
-        builder: Option<series::vintagedates::Builder>
-    ) -> Result<series::vintagedates::Response, String> {
+        units: series::observation::Units,
+        builder: Option<series::observation::Builder>
+    ) -> Result<(series::observation::Response, series::observation::Response), FredError> {
+        let builder = match builder {
+            Some(b) => b,
+            None => series::observation::Builder::new(),
+        };
 
-        let mut url: String = format!(
-            "{}series/vintagedates?series_id={}&api_key={}&file_type=json",
-            self.url_base,
-            series_id,
-            self.api_key
-        );
+        let mut levels = match self.series_observation(series_id, Some(builder.with_units(series::observation::Units::LIN))) {
+            Ok(resp) => resp,
+            Err(msg) => return Err(msg),
+        };
+        let mut transformed = match self.series_observation(series_id, Some(builder.with_units(units))) {
+            Ok(resp) => resp,
+            Err(msg) => return Err(msg),
+        };
 
-        match builder {
-            Some(b) => url.push_str(b.build().as_str()),
-            None => (),
-        }
-        
-        match self.get_request(url.as_str()) {
-            Ok(resp) => {
-                let text = resp.text().unwrap();
-                match serde_json::from_str(&text) {
-                    Ok(val) => Ok(val),
-                    Err(_e) => {
-                        match serde_json::from_str(&text) {
-                            Ok(e) => {
-                                let err: error::FredError = e;
-                                let err_msg = format!(
-                                    "ERROR {}: {}",
-                                    err.error_code,
-                                    err.error_message
-                                );
-                                return Err(err_msg);
-                            },
-                            Err(msg) => return Err(String::from(msg.to_string())),
-                        }
-                    },
-                }
-            },
-            Err(e) => return Err(e.to_string()),
-        }
+        let level_dates: HashSet<String> = levels.observations.iter().map(|item| item.date.clone()).collect();
+        transformed.observations.retain(|item| level_dates.contains(&item.date));
+        let transformed_dates: HashSet<String> = transformed.observations.iter().map(|item| item.date.clone()).collect();
+        levels.observations.retain(|item| transformed_dates.contains(&item.date));
+
+        Ok((levels, transformed))
     }
 
-    // ----------------------------------------------------------------------
-    // Series/Search
-
-    /// [See fred_rs::series::search](../series/search/index.html)
+    /// Fetches a series' metadata and observations together
+    /// 
+    /// Combines [series](#method.series) and [series_observation](#method.series_observation) into a single [TimeSeries](../series/struct.TimeSeries.html).
     /// 
     /// # Arguments
-    /// `search_text` - The words to match against economic data series [[Link]](https://research.stlouisfed.org/docs/api/fred/series_search.html#search_text)
-    pub fn series_search(
+    /// `series_id` - The id for a series [[Link]](https://research.stlouisfed.org/docs/api/fred/series.html#series_id)
+    pub fn timeseries(
         &mut self,
-        search_text: &str,
-        builder: Option<series::search::Builder>
-    ) -> Result<series::Response, String> {
-        let search_text = search_text.replace(" ", "%20"); // encode strings in url
-
-        let mut url: String = format!(
-            "{}series/search?search_text={}&api_key={}&file_type=json",
-            self.url_base,
-            search_text,
-            self.api_key
-        );
-
-        match builder {
-            Some(b) => url.push_str(b.build().as_str()),
-            None => (),
-        }
-
-        match self.get_request(url.as_str()) {
-            Ok(resp) => {
-                let text = resp.text().unwrap();
-                match serde_json::from_str(&text) {
-                    Ok(val) => Ok(val),
-                    Err(_e) => {
-                        match serde_json::from_str(&text) {
-                            Ok(e) => {
-                                let err: error::FredError = e;
-                                let err_msg = format!(
-                                    "ERROR {}: {}",
-                                    err.error_code,
-                                    err.error_message
-                                );
-                                return Err(err_msg);
-                            },
-                            Err(msg) => return Err(String::from(msg.to_string())),
-                        }
-                    },
-                }
+        series_id: &str,
+        builder: Option<series::observation::Builder>
+    ) -> Result<series::TimeSeries, FredError> {
+        let meta = match self.series(series_id, None) {
+            Ok(resp) => match resp.seriess.into_iter().next() {
+                Some(s) => s,
+                None => return Err(FredError::NotFound(format!("No series found for id {}", series_id))),
             },
-            Err(e) => return Err(e.to_string()),
+            Err(msg) => return Err(msg),
+        };
+
+        match self.series_observation(series_id, builder) {
+            Ok(observations) => Ok(series::TimeSeries { meta, observations }),
+            Err(msg) => Err(msg),
         }
     }
 
-    /// [See fred_rs::series::search::tags](../series/search/tags/index.html)
+    /// Returns only the observation values of a series, in order
+    /// 
+    /// Wraps [series_observation](#method.series_observation) for callers that do not need the dates or response metadata.  Missing values (".") are returned as None.
     /// 
     /// # Arguments
-    /// `series_search_text` - The words to match against economic data series [[Link]](https://research.stlouisfed.org/docs/api/fred/series_search_tags.html#search_text)
-    pub fn series_search_tags(
+    /// `series_id` - The id for a series [[Link]](https://research.stlouisfed.org/docs/api/fred/series_observation.html#series_id)
+    pub fn series_values(
         &mut self,
-        series_search_text: &str,
-        builder: Option<series::search::tags::Builder>
-    ) -> Result<tags::Response, String> {
-        let search_text = series_search_text.replace(" ", "%20"); // encode spaces in url
-
-        let mut url: String = format!(
-            "{}series/search/tags?series_search_text={}&api_key={}&file_type=json",
-            self.url_base,
-            search_text,
-            self.api_key
-        );
-
-        match builder {
-            Some(b) => url.push_str(b.build().as_str()),
-            None => (),
-        }
-        
-        match self.get_request(url.as_str()) {
-            Ok(resp) => {
-                let text = resp.text().unwrap();
-                match serde_json::from_str(&text) {
-                    Ok(val) => Ok(val),
-                    Err(_e) => {
-                        match serde_json::from_str(&text) {
-                            Ok(e) => {
-                                let err: error::FredError = e;
-                                let err_msg = format!(
-                                    "ERROR {}: {}",
-                                    err.error_code,
-                                    err.error_message
-                                );
-                                return Err(err_msg);
-                            },
-                            Err(msg) => return Err(String::from(msg.to_string())),
-                        }
-                    },
-                }
-            },
-            Err(e) => return Err(e.to_string()),
+        series_id: &str,
+        builder: Option<series::observation::Builder>
+    ) -> Result<Vec<Option<f64>>, FredError> {
+        match self.series_observation(series_id, builder) {
+            Ok(resp) => Ok(resp.values()),
+            Err(msg) => Err(msg),
         }
     }
 
-    /// [See fred_rs::series::search::related_tags](../series/search/related_tags/index.html)
+    /// Returns the most recent observation of a series, or None if the series has no observations
+    /// 
+    /// Requests a single observation sorted in descending order.  The observation is returned even if its value is missing (".").
     /// 
     /// # Arguments
-    /// `series_search_text` - The words to match against economic data series [[Link]](https://research.stlouisfed.org/docs/api/fred/series_search_related_tags.html#search_text)
-    pub fn series_search_related_tags(
+    /// `series_id` - The id for a series [[Link]](https://research.stlouisfed.org/docs/api/fred/series_observation.html#series_id)
+    pub fn series_latest(
         &mut self,
-        series_search_text: &str,
-        builder: series::search::related_tags::Builder
-    ) -> Result<tags::Response, String> {
-
-        let search_text = series_search_text.replace(" ", "%20"); // encode spaces in url
+        series_id: &str,
+    ) -> Result<Option<series::observation::DataPoint>, FredError> {
+        self.series_single_observation(series_id, series::observation::SortOrder::Descending)
+    }
 
-        let mut url: String = format!(
-            "{}series/search/related_tags?series_search_text={}&api_key={}&file_type=json",
-            self.url_base,
-            search_text,
-            self.api_key
-        );
+    /// Returns the earliest observation of a series, or None if the series has no observations, see [series_latest](#method.series_latest)
+    /// 
+    /// # Arguments
+    /// `series_id` - The id for a series [[Link]](https://research.stlouisfed.org/docs/api/fred/series_observation.html#series_id)
+    pub fn series_first(
+        &mut self,
+        series_id: &str,
+    ) -> Result<Option<series::observation::DataPoint>, FredError> {
+        self.series_single_observation(series_id, series::observation::SortOrder::Ascending)
+    }
 
-        match builder.build() {
-            Ok(s) => url.push_str(s.as_str()),
-            Err(msg) => return Err(msg),
-        }
-                
-        match self.get_request(url.as_str()) {
-            Ok(resp) => {
-                let text = resp.text().unwrap();
-                match serde_json::from_str(&text) {
-                    Ok(val) => Ok(val),
-                    Err(_e) => {
-                        match serde_json::from_str(&text) {
-                            Ok(e) => {
-                                let err: error::FredError = e;
-                                let err_msg = format!(
-                                    "ERROR {}: {}",
-                                    err.error_code,
-                                    err.error_message
-                                );
-                                return Err(err_msg);
-                            },
-                            Err(msg) => return Err(String::from(msg.to_string())),
-                        }
-                    },
-                }
-            },
-            Err(e) => return Err(e.to_string()),
+    /// Requests the first observation of a series in `order`
+    fn series_single_observation(
+        &mut self,
+        series_id: &str,
+        order: series::observation::SortOrder,
+    ) -> Result<Option<series::observation::DataPoint>, FredError> {
+        let mut builder = series::observation::Builder::new();
+        builder
+            .limit(1)
+            .sort_order(order);
+
+        match self.series_observation(series_id, Some(builder)) {
+            Ok(resp) => Ok(resp.observations.into_iter().next()),
+            Err(msg) => Err(msg),
         }
     }
 
-    // ----------------------------------------------------------------------
-    // Tags
-
-    /// [See fred_rs::tags](../tags/index.html)
-    pub fn tags(
+    /// Fetches the observations of several series
+    /// 
+    /// Each series is requested with the builder returned by `builder_fn`.  The responses are returned in the order of `series_ids` and the first failed request stops the batch and returns its error.
+    /// 
+    /// # Arguments
+    /// `series_ids` - The ids of the series to request
+    /// 
+    /// `builder_fn` - Called with each series id to create the observation::Builder for that request
+    pub fn series_observation_batch<F>(
         &mut self,
-        builder: Option<tags::Builder>
-    ) -> Result<tags::Response, String> {
-        let mut url: String = format!(
-            "{}tags?api_key={}&file_type=json",
-            self.url_base,
-            self.api_key
-        );
-
-        match builder {
-            Some(b) => url.push_str(b.build().as_str()),
-            None => (),
-        }
+        series_ids: &[&str],
+        builder_fn: F,
+    ) -> Result<Vec<series::observation::Response>, FredError>
+    where
+        F: Fn(&str) -> series::observation::Builder,
+    {
+        self.series_observation_batch_with_progress(series_ids, builder_fn, |_, _| ())
+    }
 
-        match self.get_request(url.as_str()) {
-            Ok(resp) => {
-                let text = resp.text().unwrap();
-                match serde_json::from_str(&text) {
-                    Ok(val) => Ok(val),
-                    Err(_e) => {
-                        match serde_json::from_str(&text) {
-                            Ok(e) => {
-                                let err: error::FredError = e;
-                                let err_msg = format!(
-                                    "ERROR {}: {}",
-                                    err.error_code,
-                                    err.error_message
-                                );
-                                return Err(err_msg);
-                            },
-                            Err(msg) => return Err(String::from(msg.to_string())),
-                        }
-                    },
-                }
-            },
-            Err(e) => return Err(e.to_string()),
+    /// Fetches the observations of several series, reporting progress after each request
+    /// 
+    /// Behaves like [series_observation_batch](#method.series_observation_batch) but calls `on_progress(completed, total)` after each successful request, which can drive a progress bar when downloading many series.
+    /// 
+    /// # Arguments
+    /// `series_ids` - The ids of the series to request
+    /// 
+    /// `builder_fn` - Called with each series id to create the observation::Builder for that request
+    /// 
+    /// `on_progress` - Called with the number of completed requests and the total number of requests
+    pub fn series_observation_batch_with_progress<F, P>(
+        &mut self,
+        series_ids: &[&str],
+        builder_fn: F,
+        mut on_progress: P,
+    ) -> Result<Vec<series::observation::Response>, FredError>
+    where
+        F: Fn(&str) -> series::observation::Builder,
+        P: FnMut(usize, usize),
+    {
+        let mut responses = Vec::with_capacity(series_ids.len());
+        for id in series_ids {
+            match self.series_observation(id, Some(builder_fn(id))) {
+                Ok(resp) => responses.push(resp),
+                Err(msg) => return Err(msg),
+            }
+            on_progress(responses.len(), series_ids.len());
         }
+        Ok(responses)
     }
 
-    /// [See fred_rs::tags::series](../tags/series/index.html)
-    pub fn tags_series(
+    /// Fetches the observations of several series with the same arguments
+    /// 
+    /// Unlike [series_observation_batch](#method.series_observation_batch) a failed request does not stop the others.  Each series id is returned with the result of its request, in the order of `series_ids`.  Requests are made one at a time, see [AsyncFredClient::series_observations_multi](struct.AsyncFredClient.html#method.series_observations_multi) to make them concurrently.
+    /// 
+    /// # Arguments
+    /// `series_ids` - The ids of the series to request
+    /// 
+    /// `builder` - The arguments applied to every request
+    pub fn series_observations_multi(
         &mut self,
-        builder: tags::series::Builder
-    ) -> Result<series::Response, String> {
-        let mut url: String = format!(
-            "{}tags/series?api_key={}&file_type=json",
-            self.url_base,
-            self.api_key
-        );
+        series_ids: &[&str],
+        builder: Option<series::observation::Builder>
+    ) -> Vec<(String, Result<series::observation::Response, FredError>)> {
+        series_ids.iter()
+            .map(|id| (String::from(*id), self.series_observation(id, builder.clone())))
+            .collect()
+    }
 
-        match builder.build() {
-            Ok(opt) => url.push_str(opt.as_str()),
+    /// Fetches several series and aligns them at a common frequency
+    /// 
+    /// Each series is requested in full, converted to `target_freq` with [Response::resample](../series/observation/struct.Response.html#method.resample) and the results are aligned by date into a [Panel](../series/observation/struct.Panel.html) with one column per series id, in the order given.  Converting locally rather than with the frequency argument lets series of different native frequencies be combined, since FRED cannot aggregate a series to a frequency finer than its own.
+    /// 
+    /// Only available with the `chrono` feature.
+    /// 
+    /// # Arguments
+    /// `series_ids` - The ids of the series to include
+    /// 
+    /// `target_freq` - The frequency of the panel
+    /// 
+    /// `method` - How to combine the observations of series finer than `target_freq`
+    #[cfg(feature = "chrono")]
+    pub fn build_panel(
+        &mut self,
+        series_ids: &[&str],
+        target_freq: series::observation::Frequency,
+        method: series::observation::AggregationMethod,
+    ) -> Result<series::observation::Panel, FredError> {
+        let responses = match self.series_observation_batch(series_ids, |_| series::observation::Builder::new()) {
+            Ok(responses) => responses,
             Err(msg) => return Err(msg),
-        }
+        };
 
-        match self.get_request(url.as_str()) {
-            Ok(resp) => {
-                let text = resp.text().unwrap();
-                match serde_json::from_str(&text) {
-                    Ok(val) => Ok(val),
-                    Err(_e) => {
-                        match serde_json::from_str(&text) {
-                            Ok(e) => {
-                                let err: error::FredError = e;
-                                let err_msg = format!(
-                                    "ERROR {}: {}",
-                                    err.error_code,
-                                    err.error_message
-                                );
-                                return Err(err_msg);
-                            },
-                            Err(msg) => return Err(String::from(msg.to_string())),
-                        }
-                    },
-                }
-            },
-            Err(e) => return Err(e.to_string()),
-        }
+        let columns = series_ids.iter()
+            .zip(responses.iter())
+            .map(|(id, resp)| (String::from(*id), resp.resample(target_freq, method)))
+            .collect();
+        Ok(series::observation::Panel::align(columns))
     }
 
-    // ----------------------------------------------------------------------
-    // Related Tags
-
-    /// [See fred_rs::related_tags](../related_tags/index.html)
-    pub fn related_tags(
+    /// Writes the observations of several series to `writer` as a single wide CSV
+    /// 
+    /// Each series is requested with the builder returned by `builder_fn` and the results are aligned by date.  The output has a `date` column followed by one column per series id, in the order given.  Dates missing from a series and missing values (".") are written as empty cells.
+    /// 
+    /// # Arguments
+    /// `series_ids` - The ids of the series to include
+    /// 
+    /// `builder_fn` - Called with each series id to create the observation::Builder for that request
+    /// 
+    /// `writer` - Destination for the CSV text
+    pub fn observations_wide_csv<F, W>(
         &mut self,
-        builder: related_tags::Builder
-    ) -> Result<tags::Response, String> {
-        let mut url: String = format!(
-            "{}related_tags?api_key={}&file_type=json",
-            self.url_base,
-            self.api_key
-        );
-
-        match builder.build() {
-            Ok(opt) => url.push_str(opt.as_str()),
+        series_ids: &[&str],
+        builder_fn: F,
+        writer: &mut W,
+    ) -> Result<(), FredError>
+    where
+        F: Fn(&str) -> series::observation::Builder,
+        W: Write,
+    {
+        let responses = match self.series_observation_batch(series_ids, builder_fn) {
+            Ok(responses) => responses,
             Err(msg) => return Err(msg),
-        }
+        };
 
-        match self.get_request(url.as_str()) {
-            Ok(resp) => {
-                let text = resp.text().unwrap();
-                match serde_json::from_str(&text) {
-                    Ok(val) => Ok(val),
-                    Err(_e) => {
-                        match serde_json::from_str(&text) {
-                            Ok(e) => {
-                                let err: error::FredError = e;
-                                let err_msg = format!(
-                                    "ERROR {}: {}",
-                                    err.error_code,
-                                    err.error_message
-                                );
-                                return Err(err_msg);
-                            },
-                            Err(msg) => return Err(String::from(msg.to_string())),
-                        }
-                    },
-                }
-            },
-            Err(e) => return Err(e.to_string()),
+        match write_wide_csv(writer, series_ids, &responses) {
+            Ok(_) => Ok(()),
+            Err(e) => Err(FredError::Io(e)),
         }
     }
 
-    // ----------------------------------------------------------------------
-    // Sources
-
-    /// [See fred_rs::sources](../sources/index.html)
-    pub fn sources(
+    /// [See fred_rs::series::release](../series/release/index.html)
+    /// 
+    /// # Arguments
+    /// `series_id` - The id for a series [[Link]](https://research.stlouisfed.org/docs/api/fred/series_release.html#series_id)
+    pub fn series_release(
         &mut self,
-        builder: Option<sources::Builder>
-    ) -> Result<source::Response, String> {
-        let mut url: String = format!(
-            "{}sources?api_key={}&file_type=json",
-            self.url_base,
-            self.api_key
-        );
-
-        match builder {
-            Some(b) => url.push_str(b.build().as_str()),
-            None => (),
-        }
-
-        match self.get_request(url.as_str()) {
-            Ok(resp) => {
-                let text = resp.text().unwrap();
-                match serde_json::from_str(&text) {
-                    Ok(val) => Ok(val),
-                    Err(_e) => {
-                        match serde_json::from_str(&text) {
-                            Ok(e) => {
-                                let err: error::FredError = e;
-                                let err_msg = format!(
-                                    "ERROR {}: {}",
-                                    err.error_code,
-                                    err.error_message
-                                );
-                                return Err(err_msg);
-                            },
-                            Err(msg) => return Err(String::from(msg.to_string())),
-                        }
-                    },
-                }
-            },
-            Err(e) => return Err(e.to_string()),
+        series_id: &str,
+        builder: Option<series::release::Builder>
+    ) -> Result<release::Response, FredError> {
+        match endpoint::series_release(&self.url_base, &self.api_key, series_id, builder) {
+            Ok(url) => self.fetch(url.as_str()),
+            Err(msg) => Err(msg),
         }
     }
-
-    // ----------------------------------------------------------------------
-    // Source
-
-    /// [See fred_rs::source](../source/index.html)
+    
+    /// [See fred_rs::series::tags](../series/tags/index.html)
     /// 
     /// # Arguments
-    /// `source_id` - The id for a source [[Link]](https://research.stlouisfed.org/docs/api/fred/source.html#source_id)
-    pub fn source(
+    /// `series_id` - The id for a series [[Link]](https://research.stlouisfed.org/docs/api/fred/series_tags.html#series_id)
+    pub fn series_tags(
         &mut self,
-        source_id: usize,
-        builder: Option<source::Builder>
-    ) -> Result<source::Response, String> {
-        let mut url: String = format!(
-            "{}source?source_id={}&api_key={}&file_type=json",
-            self.url_base,
-            source_id,
-            self.api_key
-        );
-
-        match builder {
-            Some(b) => url.push_str(b.build().as_str()),
-            None => (),
-        }
-
-        match self.get_request(url.as_str()) {
-            Ok(resp) => {
-                let text = resp.text().unwrap();
-                match serde_json::from_str(&text) {
-                    Ok(val) => Ok(val),
-                    Err(_e) => {
-                        match serde_json::from_str(&text) {
-                            Ok(e) => {
-                                let err: error::FredError = e;
-                                let err_msg = format!(
-                                    "ERROR {}: {}",
-                                    err.error_code,
-                                    err.error_message
-                                );
-                                return Err(err_msg);
-                            },
-                            Err(msg) => return Err(String::from(msg.to_string())),
-                        }
-                    },
-                }
-            },
-            Err(e) => return Err(e.to_string()),
+        series_id: &str,
+        builder: Option<series::tags::Builder>
+    ) -> Result<tags::Response, FredError> {
+        match endpoint::series_tags(&self.url_base, &self.api_key, series_id, builder) {
+            Ok(url) => self.fetch(url.as_str()),
+            Err(msg) => Err(msg),
         }
     }
 
-    /// [See fred_rs::source::releases](../source/releases/index.html)
+    /// Returns the names of the tags for a series
+    /// 
+    /// Wraps [series_tags](#method.series_tags) for callers that only need the tag names and not the rest of the tag data.
     /// 
     /// # Arguments
-    /// `source_id` - The id for a source [[Link]](https://research.stlouisfed.org/docs/api/fred/source_releases.html#source_id)
-    pub fn source_releases(
+    /// `series_id` - The id for a series [[Link]](https://research.stlouisfed.org/docs/api/fred/series_tags.html#series_id)
+    pub fn series_tag_names(
         &mut self,
-        source_id: usize,
-        builder: Option<source::releases::Builder>
-    ) -> Result<release::Response, String> {
-        let mut url: String = format!(
-            "{}source/releases?source_id={}&api_key={}&file_type=json",
-            self.url_base,
-            source_id,
-            self.api_key
-        );
-
-        match builder {
-            Some(b) => url.push_str(b.build().as_str()),
-            None => (),
+        series_id: &str,
+    ) -> Result<Vec<String>, FredError> {
+        match self.series_tags(series_id, None) {
+            Ok(resp) => Ok(resp.tags.into_iter().map(|tag| tag.name).collect()),
+            Err(msg) => Err(msg),
         }
+    }
 
-        match self.get_request(url.as_str()) {
-            Ok(resp) => {
-                let text = resp.text().unwrap();
-                match serde_json::from_str(&text) {
-                    Ok(val) => Ok(val),
-                    Err(_e) => {
-                        match serde_json::from_str(&text) {
-                            Ok(e) => {
-                                let err: error::FredError = e;
-                                let err_msg = format!(
-                                    "ERROR {}: {}",
-                                    err.error_code,
-                                    err.error_message
-                                );
-                                return Err(err_msg);
-                            },
-                            Err(msg) => return Err(String::from(msg.to_string())),
-                        }
-                    },
-                }
-            },
-            Err(e) => return Err(e.to_string()),
+    /// [See fred_rs::series::updates](../series/updates/index.html)
+    pub fn series_updates(
+        &mut self,
+        builder: Option<series::updates::Builder>
+    ) -> Result<series::updates::Response, FredError> {
+        match endpoint::series_updates(&self.url_base, &self.api_key, builder) {
+            Ok(url) => self.fetch(url.as_str()),
+            Err(msg) => Err(msg),
         }
     }
 
-    // ----------------------------------------------------------------------
-    // Category
-
-    /// [See fred_rs::category](../category/index.html)
+    /// [See fred_rs::series::vintagedates](../series/vintagedates/index.html)
     /// 
     /// # Arguments
-    /// `category_id` - The id for a category [[Link]](https://research.stlouisfed.org/docs/api/fred/category.html#category_id)
-    pub fn category(
+    /// `series_id` - The id for a series [[Link]](https://research.stlouisfed.org/docs/api/fred/series_vintagedates.html#series_id)
+    pub fn series_vintagedates(
         &mut self,
-        category_id: usize
-    ) -> Result<category::Response, String> {
-        let url: String = format!(
-            "{}category?category_id={}&api_key={}&file_type=json",
-            self.url_base,
-            category_id,
-            self.api_key
-        );
-
-        match self.get_request(url.as_str()) {
-            Ok(resp) => {
-                let text = resp.text().unwrap();
-                match serde_json::from_str(&text) {
-                    Ok(val) => Ok(val),
-                    Err(_e) => {
-                        match serde_json::from_str(&text) {
-                            Ok(e) => {
-                                let err: error::FredError = e;
-                                let err_msg = format!(
-                                    "ERROR {}: {}",
-                                    err.error_code,
-                                    err.error_message
-                                );
-                                return Err(err_msg);
-                            },
-                            Err(msg) => return Err(String::from(msg.to_string())),
-                        }
-                    },
-                }
-            },
-            Err(e) => return Err(e.to_string()),
+        series_id: &str,
+        builder: Option<series::vintagedates::Builder>
+    ) -> Result<series::vintagedates::Response, FredError> {
+        match endpoint::series_vintagedates(&self.url_base, &self.api_key, series_id, builder) {
+            Ok(url) => self.fetch(url.as_str()),
+            Err(msg) => Err(msg),
         }
     }
 
-    /// [See fred_rs::category::children](../category/children/index.html)
-    /// 
+    /// Returns the other series published in the same release as `series_id`
+    ///
+    /// The release is looked up with the fred/series/release endpoint and all of its series are then collected from fred/release/series, one page at a time.  The series matching `series_id` is excluded from the result.
+    ///
     /// # Arguments
-    /// `category_id` - The id for a category [[Link]](https://research.stlouisfed.org/docs/api/fred/category_children.html#category_id)
-    pub fn category_children(
+    /// `series_id` - The id for a series [[Link]](https://research.stlouisfed.org/docs/api/fred/series_release.html#series_id)
+    pub fn series_siblings(
         &mut self,
-        category_id: usize,
-        builder: Option<category::children::Builder>,
-    ) -> Result<category::Response, String> {
-        let mut url: String = format!(
-            "{}category/children?category_id={}&api_key={}&file_type=json",
-            self.url_base,
-            category_id,
-            self.api_key
-        );
+        series_id: &str,
+    ) -> Result<Vec<series::Series>, FredError> {
+        let release_id = match self.series_release(series_id, None) {
+            Ok(resp) => match resp.releases.first() {
+                Some(r) => r.id,
+                None => return Err(FredError::NotFound(format!("No release found for series {}", series_id))),
+            },
+            Err(msg) => return Err(msg),
+        };
 
-        match builder {
-            Some(b) => url.push_str(b.build().as_str()),
-            None => (),
+        let mut siblings = Vec::new();
+        let mut offset = 0;
+        loop {
+            let mut builder = release::series::Builder::new();
+            builder
+                .limit(1000)
+                .offset(offset);
+
+            let resp = match self.release_series(release_id, Some(builder)) {
+                Ok(resp) => resp,
+                Err(msg) => return Err(msg),
+            };
+
+            let page = resp.pagination();
+            siblings.extend(resp.seriess.into_iter().filter(|s| s.id != series_id));
+
+            if page.returned == 0 || !page.has_more() {
+                break;
+            }
+            offset = page.next_offset();
         }
 
-        match self.get_request(url.as_str()) {
-            Ok(resp) => {
-                let text = resp.text().unwrap();
-                match serde_json::from_str(&text) {
-                    Ok(val) => Ok(val),
-                    Err(_e) => {
-                        match serde_json::from_str(&text) {
-                            Ok(e) => {
-                                let err: error::FredError = e;
-                                let err_msg = format!(
-                                    "ERROR {}: {}",
-                                    err.error_code,
-                                    err.error_message
-                                );
-                                return Err(err_msg);
-                            },
-                            Err(msg) => return Err(String::from(msg.to_string())),
-                        }
-                    },
-                }
-            },
-            Err(e) => return Err(e.to_string()),
-        }
+        Ok(siblings)
     }
 
-    /// [See fred_rs::category::related](../category/related/index.html)
+    /// Finds the seasonally adjusted or not seasonally adjusted counterparts of a series
+    /// 
+    /// Many FRED series are published both with and without seasonal adjustment under different ids.  The title of `series_id` is used as the search text and the results are filtered to series with the same title and frequency but a different seasonal adjustment.  The series itself is not included.
     /// 
     /// # Arguments
-    /// `category_id` - The id for a category [[Link]](https://research.stlouisfed.org/docs/api/fred/category_related.html#category_id)
-    pub fn category_related(
+    /// `series_id` - The id for a series [[Link]](https://research.stlouisfed.org/docs/api/fred/series.html#series_id)
+    pub fn find_seasonal_variants(
         &mut self,
-        category_id: usize,
-        builder: Option<category::related::Builder>,
-    ) -> Result<category::Response, String> {
-        let mut url: String = format!(
-            "{}category/related?category_id={}&api_key={}&file_type=json",
-            self.url_base,
-            category_id,
-            self.api_key
-        );
+        series_id: &str,
+    ) -> Result<Vec<series::Series>, FredError> {
+        let base = match self.series(series_id, None) {
+            Ok(resp) => match resp.seriess.into_iter().next() {
+                Some(s) => s,
+                None => return Err(FredError::NotFound(format!("No series found for id {}", series_id))),
+            },
+            Err(msg) => return Err(msg),
+        };
 
-        match builder {
-            Some(b) => url.push_str(b.build().as_str()),
-            None => (),
-        }
+        let mut builder = series::search::Builder::new();
+        builder.limit(PAGE_LIMIT);
 
-        match self.get_request(url.as_str()) {
-            Ok(resp) => {
-                let text = resp.text().unwrap();
-                match serde_json::from_str(&text) {
-                    Ok(val) => Ok(val),
-                    Err(_e) => {
-                        match serde_json::from_str(&text) {
-                            Ok(e) => {
-                                let err: error::FredError = e;
-                                let err_msg = format!(
-                                    "ERROR {}: {}",
-                                    err.error_code,
-                                    err.error_message
-                                );
-                                return Err(err_msg);
-                            },
-                            Err(msg) => return Err(String::from(msg.to_string())),
-                        }
-                    },
-                }
-            },
-            Err(e) => return Err(e.to_string()),
-        }
+        let resp = match self.series_search(base.title.as_str(), Some(builder)) {
+            Ok(resp) => resp,
+            Err(msg) => return Err(msg),
+        };
+
+        Ok(resp.seriess
+            .into_iter()
+            .filter(|s| s.id != base.id
+                && s.title.eq_ignore_ascii_case(base.title.as_str())
+                && s.frequency == base.frequency
+                && s.seasonal_adjustment_short != base.seasonal_adjustment_short)
+            .collect())
     }
 
-    /// [See fred_rs::category::series](../category/series/index.html)
-    /// 
+    /// Looks up the title of each series in `series_ids`
+    ///
+    /// Returns a map from series id to title along with the ids which could not be resolved and the error for each (see [SeriesTitles](struct.SeriesTitles.html)), so one bad id does not discard the rest of the results.  An id FRED has no series for fails with FredError::NotFound.  If none of the ids can be resolved the last error is returned instead.
+    ///
     /// # Arguments
-    /// `category_id` - The id for a category [[Link]](https://research.stlouisfed.org/docs/api/fred/series.html#category_id)
-    pub fn category_series(
+    /// `series_ids` - The ids of the series to look up [[Link]](https://research.stlouisfed.org/docs/api/fred/series.html#series_id)
+    pub fn series_titles(
         &mut self,
-        category_id: usize,
-        builder: Option<category::series::Builder>
-    ) -> Result<series::Response, String> {
-        let mut url: String = format!(
-            "{}category/series?category_id={}&api_key={}&file_type=json",
-            self.url_base,
-            category_id,
-            self.api_key
-        );
-
-        match builder {
-            Some(b) => url.push_str(b.build().as_str()),
-            None => (),
+        series_ids: &[&str],
+    ) -> Result<SeriesTitles, FredError> {
+        let mut results = Vec::new();
+        for id in series_ids {
+            results.push((String::from(*id), self.series(id, None)));
         }
+        collect_titles(results)
+    }
 
-        match self.get_request(url.as_str()) {
-            Ok(resp) => {
-                let text = resp.text().unwrap();
-                match serde_json::from_str(&text) {
-                    Ok(val) => Ok(val),
-                    Err(_e) => {
-                        match serde_json::from_str(&text) {
-                            Ok(e) => {
-                                let err: error::FredError = e;
-                                let err_msg = format!(
-                                    "ERROR {}: {}",
-                                    err.error_code,
-                                    err.error_message
-                                );
-                                return Err(err_msg);
-                            },
-                            Err(msg) => return Err(String::from(msg.to_string())),
-                        }
-                    },
-                }
+    /// Fetches the observations for a series only if it was updated after `since`
+    ///
+    /// The series metadata is requested first and its last_updated timestamp is compared against `since`.  Returns None without downloading the observations if the series has not changed.
+    ///
+    /// # Arguments
+    /// `series_id` - The id for a series [[Link]](https://research.stlouisfed.org/docs/api/fred/series_observation.html#series_id)
+    ///
+    /// `since` - A timestamp in the format FRED uses for last_updated (e.g. 2013-07-31 09:26:16-05), usually saved from a previous request, or a date formatted as YYYY-MM-DD (taken as midnight UTC)
+    ///
+    /// The timestamps are compared as instants, so the UTC offset FRED reports (which changes with daylight saving time) is taken into account.  Returns Err without making a request if `since` cannot be parsed.
+    pub fn series_observation_if_changed(
+        &mut self,
+        series_id: &str,
+        since: &str,
+        builder: Option<series::observation::Builder>
+    ) -> Result<Option<series::observation::Response>, FredError> {
+        let since = match timestamp_seconds(since) {
+            Some(seconds) => seconds,
+            None => return Err(FredError::InvalidArgument(String::from(error::INVALID_TIMESTAMP_ERROR_TEXT))),
+        };
+        let last_updated = match self.series(series_id, None) {
+            Ok(resp) => match resp.seriess.first() {
+                Some(s) => s.last_updated.clone(),
+                None => return Err(FredError::NotFound(format!("No series found for id {}", series_id))),
             },
-            Err(e) => return Err(e.to_string()),
+            Err(msg) => return Err(msg),
+        };
+
+        if !updated_since(&last_updated, since) {
+            return Ok(None);
+        }
+
+        match self.series_observation(series_id, builder) {
+            Ok(resp) => Ok(Some(resp)),
+            Err(msg) => Err(msg),
         }
     }
 
-    /// [See fred_rs::category::tags](../category/tags/index.html)
+    // ----------------------------------------------------------------------
+    // Series/Search
+
+    /// [See fred_rs::series::search](../series/search/index.html)
     /// 
     /// # Arguments
-    /// `category_id` - The id for a category [[Link]](https://research.stlouisfed.org/docs/api/fred/category_tags.html#category_id)
-    pub fn category_tags(
+    /// `search_text` - The words to match against economic data series, as plain text (the client percent-encodes it) [[Link]](https://research.stlouisfed.org/docs/api/fred/series_search.html#search_text)
+    pub fn series_search(
         &mut self,
-        category_id: usize,
-        builder: Option<category::tags::Builder>
-    ) -> Result<tags::Response, String> {
-        let mut url: String = format!(
-            "{}category/tags?category_id={}&api_key={}&file_type=json",
-            self.url_base,
-            category_id,
-            self.api_key
-        );
-
-        match builder {
-            Some(b) => url.push_str(b.build().as_str()),
-            None => (),
+        search_text: &str,
+        builder: Option<series::search::Builder>
+    ) -> Result<series::Response, FredError> {
+        match endpoint::series_search(&self.url_base, &self.api_key, search_text, builder) {
+            Ok(url) => self.fetch(url.as_str()),
+            Err(msg) => Err(msg),
         }
+    }
 
-        match self.get_request(url.as_str()) {
-            Ok(resp) => {
-                let text = resp.text().unwrap();
-                match serde_json::from_str(&text) {
-                    Ok(val) => Ok(val),
-                    Err(_e) => {
-                        match serde_json::from_str(&text) {
-                            Ok(e) => {
-                                let err: error::FredError = e;
-                                let err_msg = format!(
-                                    "ERROR {}: {}",
-                                    err.error_code,
-                                    err.error_message
-                                );
-                                return Err(err_msg);
-                            },
-                            Err(msg) => return Err(String::from(msg.to_string())),
-                        }
-                    },
-                }
-            },
-            Err(e) => return Err(e.to_string()),
+    /// Returns the `n` most recently updated series matching a search
+    /// 
+    /// Requests fred/series/search ordered by last_updated in descending order, so the series with the freshest data come first.  At most 1000 series are returned.
+    /// 
+    /// # Arguments
+    /// `search_text` - The words to match against economic data series as plain text (the client percent-encodes it) [[Link]](https://research.stlouisfed.org/docs/api/fred/series_search.html#search_text)
+    /// 
+    /// `n` - The number of series to return
+    pub fn recently_updated(
+        &mut self,
+        search_text: &str,
+        n: usize,
+    ) -> Result<Vec<series::Series>, FredError> {
+        let mut builder = series::search::Builder::new();
+        builder
+            .order_by(series::search::OrderBy::LastUpdated)
+            .sort_order(series::search::SortOrder::Descending)
+            .limit(n);
+
+        match self.series_search(search_text, Some(builder)) {
+            Ok(resp) => Ok(resp.seriess),
+            Err(msg) => Err(msg),
         }
     }
 
-    /// [See fred_rs::category::related_tags](../category/related_tags/index.html)
+    /// Collects every page of results for a series search
+    /// 
+    /// The search is repeated with an increasing offset until all of the matching series have been returned or `max_results` series have been collected, whichever comes first.  Any limit or offset set on the builder is ignored.  When the budget stops the search early the result is flagged as truncated.
+    /// 
+    /// A broad search can match tens of thousands of series so setting a budget is recommended.
+    /// 
+    /// The other list endpoints have the same helper, e.g. [category_series_all](#method.category_series_all) and [tags_all](#method.tags_all).
     /// 
     /// # Arguments
-    /// `category_id` - The id for a category [[Link]](https://research.stlouisfed.org/docs/api/fred/category_related_tags.html#category_id)
-    pub fn category_related_tags(
+    /// `search_text` - The words to match against economic data series, as plain text (the client percent-encodes it) [[Link]](https://research.stlouisfed.org/docs/api/fred/series_search.html#search_text)
+    /// 
+    /// `max_results` - The maximum number of series to collect or None to collect every result
+    pub fn series_search_all(
         &mut self,
-        category_id: usize,
-        builder: category::related_tags::Builder
-    ) -> Result<tags::Response, String> {
-        let mut url: String = format!(
-            "{}category/related_tags?category_id={}&api_key={}&file_type=json",
-            self.url_base,
-            category_id,
-            self.api_key
-        );
+        search_text: &str,
+        builder: Option<series::search::Builder>,
+        max_results: Option<usize>
+    ) -> Result<Collected<series::Series>, FredError> {
+        match endpoint::series_search(&self.url_base, &self.api_key, search_text, builder) {
+            Ok(url) => self.collect_pages::<series::Response>(url.as_str(), max_results),
+            Err(msg) => Err(msg),
+        }
+    }
 
-        match builder.build() {
-            Ok(o) => url.push_str(o.as_str()),
-            Err(msg) => return Err(msg),
+    /// [See fred_rs::series::search::tags](../series/search/tags/index.html)
+    /// 
+    /// # Arguments
+    /// `series_search_text` - The words to match against economic data series, as plain text (the client percent-encodes it) [[Link]](https://research.stlouisfed.org/docs/api/fred/series_search_tags.html#search_text)
+    pub fn series_search_tags(
+        &mut self,
+        series_search_text: &str,
+        builder: Option<series::search::tags::Builder>
+    ) -> Result<tags::Response, FredError> {
+        match endpoint::series_search_tags(&self.url_base, &self.api_key, series_search_text, builder) {
+            Ok(url) => self.fetch(url.as_str()),
+            Err(msg) => Err(msg),
         }
+    }
 
-        match self.get_request(url.as_str()) {
-            Ok(resp) => {
-                let text = resp.text().unwrap();
-                match serde_json::from_str(&text) {
-                    Ok(val) => Ok(val),
-                    Err(_e) => {
-                        match serde_json::from_str(&text) {
-                            Ok(e) => {
-                                let err: error::FredError = e;
-                                let err_msg = format!(
-                                    "ERROR {}: {}",
-                                    err.error_code,
-                                    err.error_message
-                                );
-                                return Err(err_msg);
-                            },
-                            Err(msg) => return Err(String::from(msg.to_string())),
-                        }
-                    },
-                }
-            },
-            Err(e) => return Err(e.to_string()),
+    /// [See fred_rs::series::search::related_tags](../series/search/related_tags/index.html)
+    /// 
+    /// # Arguments
+    /// `series_search_text` - The words to match against economic data series, as plain text (the client percent-encodes it) [[Link]](https://research.stlouisfed.org/docs/api/fred/series_search_related_tags.html#search_text)
+    pub fn series_search_related_tags(
+        &mut self,
+        series_search_text: &str,
+        builder: series::search::related_tags::Builder
+    ) -> Result<tags::Response, FredError> {
+        match endpoint::series_search_related_tags(&self.url_base, &self.api_key, series_search_text, builder) {
+            Ok(url) => self.fetch(url.as_str()),
+            Err(msg) => Err(msg),
         }
     }
 
     // ----------------------------------------------------------------------
-    // Releases
+    // Tags
 
-    /// [See fred_rs::releases](../releases/index.html)
-    pub fn releases(
+    /// [See fred_rs::tags](../tags/index.html)
+    pub fn tags(
         &mut self,
-        builder: Option<releases::Builder>
-    ) -> Result<release::Response, String> {
-        let mut url: String = format!(
-            "{}releases?api_key={}&file_type=json",
-            self.url_base,
-            self.api_key
-        );
-
-        match builder {
-            Some(b) => url.push_str(b.build().as_str()),
-            None => (),
-        }
-
-        match self.get_request(url.as_str()) {
-            Ok(resp) => {
-                let text = resp.text().unwrap();
-                match serde_json::from_str(&text) {
-                    Ok(val) => Ok(val),
-                    Err(_e) => {
-                        match serde_json::from_str(&text) {
-                            Ok(e) => {
-                                let err: error::FredError = e;
-                                let err_msg = format!(
-                                    "ERROR {}: {}",
-                                    err.error_code,
-                                    err.error_message
-                                );
-                                return Err(err_msg);
-                            },
-                            Err(msg) => return Err(String::from(msg.to_string())),
-                        }
-                    },
-                }
-            },
-            Err(e) => return Err(e.to_string()),
+        builder: Option<tags::Builder>
+    ) -> Result<tags::Response, FredError> {
+        match endpoint::tags(&self.url_base, &self.api_key, builder) {
+            Ok(url) => self.fetch(url.as_str()),
+            Err(msg) => Err(msg),
         }
     }
 
-    /// [See fred_rs::releases::dates](../releases/dates/index.html)
-    pub fn releases_dates(
+    /// Collects every page of results for [tags](#method.tags)
+    /// 
+    /// Pages are requested as in [series_search_all](#method.series_search_all) until every result has been returned or `max_results` tags have been collected.  Any limit or offset set on the builder is ignored.
+    /// 
+    /// # Arguments
+    /// `max_results` - The maximum number of tags to collect or None to collect every result
+    pub fn tags_all(
         &mut self,
-        builder: Option<releases::dates::Builder>
-    ) -> Result<releases::dates::Response, String> {
-        let mut url: String = format!(
-            "{}releases/dates?api_key={}&file_type=json",
-            self.url_base,
-            self.api_key
-        );
-
-        match builder {
-            Some(b) => url.push_str(b.build().as_str()),
-            None => (),
+        builder: Option<tags::Builder>,
+        max_results: Option<usize>
+    ) -> Result<Collected<tags::Tag>, FredError> {
+        match endpoint::tags(&self.url_base, &self.api_key, builder) {
+            Ok(url) => self.collect_pages::<tags::Response>(url.as_str(), max_results),
+            Err(msg) => Err(msg),
         }
+    }
 
-        match self.get_request(url.as_str()) {
-            Ok(resp) => {
-                let text = resp.text().unwrap();
-                match serde_json::from_str(&text) {
-                    Ok(val) => Ok(val),
-                    Err(_e) => {
-                        match serde_json::from_str(&text) {
-                            Ok(e) => {
-                                let err: error::FredError = e;
-                                let err_msg = format!(
-                                    "ERROR {}: {}",
-                                    err.error_code,
-                                    err.error_message
-                                );
-                                return Err(err_msg);
-                            },
-                            Err(msg) => return Err(String::from(msg.to_string())),
-                        }
-                    },
-                }
-            },
-            Err(e) => return Err(e.to_string()),
+    /// [See fred_rs::tags::series](../tags/series/index.html)
+    pub fn tags_series(
+        &mut self,
+        builder: tags::series::Builder
+    ) -> Result<series::Response, FredError> {
+        match endpoint::tags_series(&self.url_base, &self.api_key, builder) {
+            Ok(url) => self.fetch(url.as_str()),
+            Err(msg) => Err(msg),
         }
     }
 
     // ----------------------------------------------------------------------
-    // Release
+    // Related Tags
 
-    /// [See fred_rs::release](../release/index.html)
-    /// 
-    /// # Arguments
-    /// `release_id` - The id for a release [[Link]](https://research.stlouisfed.org/docs/api/fred/release.html#release_id)
-    pub fn release(
+    /// [See fred_rs::related_tags](../related_tags/index.html)
+    pub fn related_tags(
         &mut self,
-        release_id: usize,
-        builder: Option<release::Builder>
-    ) -> Result<release::Response, String> {
-        let mut url: String = format!(
-            "{}release?release_id={}&api_key={}&file_type=json",
-            self.url_base,
-            release_id,
-            self.api_key
-        );
-
-        match builder {
-            Some(b) => url.push_str(b.build().as_str()),
-            None => (),
+        builder: related_tags::Builder
+    ) -> Result<tags::Response, FredError> {
+        match endpoint::related_tags(&self.url_base, &self.api_key, builder) {
+            Ok(url) => self.fetch(url.as_str()),
+            Err(msg) => Err(msg),
         }
+    }
 
-        match self.get_request(url.as_str()) {
-            Ok(resp) => {
-                let text = resp.text().unwrap();
-                match serde_json::from_str(&text) {
-                    Ok(val) => Ok(val),
-                    Err(_e) => {
-                        match serde_json::from_str(&text) {
-                            Ok(e) => {
-                                let err: error::FredError = e;
-                                let err_msg = format!(
-                                    "ERROR {}: {}",
-                                    err.error_code,
-                                    err.error_message
-                                );
-                                return Err(err_msg);
-                            },
-                            Err(msg) => return Err(String::from(msg.to_string())),
-                        }
-                    },
-                }
-            },
-            Err(e) => return Err(e.to_string()),
+    // ----------------------------------------------------------------------
+    // Sources
+
+    /// [See fred_rs::sources](../sources/index.html)
+    pub fn sources(
+        &mut self,
+        builder: Option<sources::Builder>
+    ) -> Result<source::Response, FredError> {
+        match endpoint::sources(&self.url_base, &self.api_key, builder) {
+            Ok(url) => self.fetch(url.as_str()),
+            Err(msg) => Err(msg),
         }
     }
 
-    /// [See fred_rs::release::series](../release/series/index.html)
+    /// Collects every page of results for [sources](#method.sources)
+    /// 
+    /// Pages are requested as in [series_search_all](#method.series_search_all) until every result has been returned or `max_results` sources have been collected.  Any limit or offset set on the builder is ignored.
     /// 
     /// # Arguments
-    /// `release_id` - The id for a release [[Link]](https://research.stlouisfed.org/docs/api/fred/release_series.html#release_id)
-    pub fn release_series(
+    /// `max_results` - The maximum number of sources to collect or None to collect every result
+    pub fn sources_all(
         &mut self,
-        release_id: usize,
-        builder: Option<release::series::Builder>
-    ) -> Result<series::Response, String> {
-        let mut url: String = format!(
-            "{}release/series?release_id={}&api_key={}&file_type=json",
-            self.url_base,
-            release_id,
-            self.api_key
-        );
-
-        match builder {
-            Some(b) => url.push_str(b.build().as_str()),
-            None => (),
+        builder: Option<sources::Builder>,
+        max_results: Option<usize>
+    ) -> Result<Collected<source::Source>, FredError> {
+        match endpoint::sources(&self.url_base, &self.api_key, builder) {
+            Ok(url) => self.collect_pages::<source::Response>(url.as_str(), max_results),
+            Err(msg) => Err(msg),
         }
+    }
 
-        match self.get_request(url.as_str()) {
-            Ok(resp) => {
-                let text = resp.text().unwrap();
-                match serde_json::from_str(&text) {
-                    Ok(val) => Ok(val),
-                    Err(_e) => {
-                        match serde_json::from_str(&text) {
-                            Ok(e) => {
-                                let err: error::FredError = e;
-                                let err_msg = format!(
-                                    "ERROR {}: {}",
-                                    err.error_code,
-                                    err.error_message
-                                );
-                                return Err(err_msg);
-                            },
-                            Err(msg) => return Err(String::from(msg.to_string())),
-                        }
-                    },
-                }
-            },
-            Err(e) => return Err(e.to_string()),
+    // ----------------------------------------------------------------------
+    // Source
+
+    /// [See fred_rs::source](../source/index.html)
+    /// 
+    /// # Arguments
+    /// `source_id` - The id for a source [[Link]](https://research.stlouisfed.org/docs/api/fred/source.html#source_id)
+    pub fn source(
+        &mut self,
+        source_id: usize,
+        builder: Option<source::Builder>
+    ) -> Result<source::Response, FredError> {
+        match endpoint::source(&self.url_base, &self.api_key, source_id, builder) {
+            Ok(url) => self.fetch(url.as_str()),
+            Err(msg) => Err(msg),
         }
     }
 
-    /// [See fred_rs::release::sources](../release/sources/index.html)
+    /// [See fred_rs::source::releases](../source/releases/index.html)
     /// 
     /// # Arguments
-    /// `release_id` - The id for a release [[Link]](https://research.stlouisfed.org/docs/api/fred/release_sources.html#release_id)
-    pub fn release_sources(
+    /// `source_id` - The id for a source [[Link]](https://research.stlouisfed.org/docs/api/fred/source_releases.html#source_id)
+    pub fn source_releases(
         &mut self,
-        release_id: usize,
-        builder: Option<release::sources::Builder>
-    ) -> Result<source::Response, String> {
-        let mut url: String = format!(
-            "{}release/sources?release_id={}&api_key={}&file_type=json",
-            self.url_base,
-            release_id,
-            self.api_key
-        );
-
-        match builder {
-            Some(b) => url.push_str(b.build().as_str()),
-            None => (),
+        source_id: usize,
+        builder: Option<source::releases::Builder>
+    ) -> Result<release::Response, FredError> {
+        match endpoint::source_releases(&self.url_base, &self.api_key, source_id, builder) {
+            Ok(url) => self.fetch(url.as_str()),
+            Err(msg) => Err(msg),
         }
+    }
 
-        match self.get_request(url.as_str()) {
-            Ok(resp) => {
-                let text = resp.text().unwrap();
-                match serde_json::from_str(&text) {
-                    Ok(val) => Ok(val),
-                    Err(_e) => {
-                        match serde_json::from_str(&text) {
-                            Ok(e) => {
-                                let err: error::FredError = e;
-                                let err_msg = format!(
-                                    "ERROR {}: {}",
-                                    err.error_code,
-                                    err.error_message
-                                );
-                                return Err(err_msg);
-                            },
-                            Err(msg) => return Err(String::from(msg.to_string())),
-                        }
-                    },
-                }
-            },
-            Err(e) => return Err(e.to_string()),
+    // ----------------------------------------------------------------------
+    // Category
+
+    /// [See fred_rs::category](../category/index.html)
+    /// 
+    /// The fred/category endpoint accepts no optional arguments (categories are not versioned by realtime period) so there is no builder.  The related endpoints which do accept realtime_start and realtime_end, such as [category_children](#method.category_children) and [category_related](#method.category_related), take a builder.
+    /// 
+    /// # Arguments
+    /// `category_id` - The id for a category [[Link]](https://research.stlouisfed.org/docs/api/fred/category.html#category_id)
+    pub fn category(
+        &mut self,
+        category_id: usize
+    ) -> Result<category::Response, FredError> {
+        match endpoint::category(&self.url_base, &self.api_key, category_id) {
+            Ok(url) => self.fetch(url.as_str()),
+            Err(msg) => Err(msg),
         }
     }
 
-    /// [See fred_rs::release::tags](../release/tags/index.html)
+    /// Returns a category together with its child categories and first page of series
+    /// 
+    /// Requests fred/category, fred/category/children and fred/category/series in turn, returning the first error encountered.
     /// 
     /// # Arguments
-    /// `release_id` - The id for a release [[Link]](https://research.stlouisfed.org/docs/api/fred/release_tags.html#release_id)
-    pub fn release_tags(
+    /// `category_id` - The id for a category [[Link]](https://research.stlouisfed.org/docs/api/fred/category.html#category_id)
+    pub fn category_overview(
         &mut self,
-        release_id: usize,
-        builder: Option<release::tags::Builder>
-    ) -> Result<tags::Response, String> {
-        let mut url: String = format!(
-            "{}release/tags?release_id={}&api_key={}&file_type=json",
-            self.url_base,
-            release_id,
-            self.api_key
-        );
+        category_id: usize
+    ) -> Result<category::CategoryOverview, FredError> {
+        let category = match self.category(category_id) {
+            Ok(resp) => match resp.categories.into_iter().next() {
+                Some(category) => category,
+                None => return Err(FredError::NotFound(String::from(error::CATEGORY_NOT_FOUND_ERROR_TEXT))),
+            },
+            Err(msg) => return Err(msg),
+        };
+        let children = match self.category_children(category_id, None) {
+            Ok(resp) => resp.categories,
+            Err(msg) => return Err(msg),
+        };
+        let series = match self.category_series(category_id, None) {
+            Ok(resp) => resp.seriess,
+            Err(msg) => return Err(msg),
+        };
 
-        match builder {
-            Some(b) => url.push_str(b.build().as_str()),
-            None => (),
-        }
+        Ok(category::CategoryOverview {
+            category,
+            children,
+            series,
+        })
+    }
 
-        match self.get_request(url.as_str()) {
-            Ok(resp) => {
-                let text = resp.text().unwrap();
-                match serde_json::from_str(&text) {
-                    Ok(val) => Ok(val),
-                    Err(_e) => {
-                        match serde_json::from_str(&text) {
-                            Ok(e) => {
-                                let err: error::FredError = e;
-                                let err_msg = format!(
-                                    "ERROR {}: {}",
-                                    err.error_code,
-                                    err.error_message
-                                );
-                                return Err(err_msg);
-                            },
-                            Err(msg) => return Err(String::from(msg.to_string())),
-                        }
-                    },
-                }
-            },
-            Err(e) => return Err(e.to_string()),
+    /// [See fred_rs::category::children](../category/children/index.html)
+    /// 
+    /// # Arguments
+    /// `category_id` - The id for a category [[Link]](https://research.stlouisfed.org/docs/api/fred/category_children.html#category_id)
+    pub fn category_children(
+        &mut self,
+        category_id: usize,
+        builder: Option<category::children::Builder>,
+    ) -> Result<category::Response, FredError> {
+        match endpoint::category_children(&self.url_base, &self.api_key, category_id, builder) {
+            Ok(url) => self.fetch(url.as_str()),
+            Err(msg) => Err(msg),
         }
     }
 
-    /// [See fred_rs::release::related_tags](../release/related_tags/index.html)
+    /// [See fred_rs::category::related](../category/related/index.html)
     /// 
     /// # Arguments
-    /// `release_id` - The id for a release [[Link]](https://research.stlouisfed.org/docs/api/fred/release_related_tags.html#release_id)
-    pub fn release_related_tags(
+    /// `category_id` - The id for a category [[Link]](https://research.stlouisfed.org/docs/api/fred/category_related.html#category_id)
+    pub fn category_related(
         &mut self,
-        release_id: usize,
-        builder: release::related_tags::Builder
-    ) -> Result<tags::Response, String> {
-        let mut url: String = format!(
-            "{}release/related_tags?release_id={}&api_key={}&file_type=json",
-            self.url_base,
-            release_id,
-            self.api_key
-        );
-
-        match builder.build() {
-            Ok(o) => url.push_str(o.as_str()),
-            Err(msg) => return Err(msg),
+        category_id: usize,
+        builder: Option<category::related::Builder>,
+    ) -> Result<category::Response, FredError> {
+        match endpoint::category_related(&self.url_base, &self.api_key, category_id, builder) {
+            Ok(url) => self.fetch(url.as_str()),
+            Err(msg) => Err(msg),
         }
+    }
 
-        match self.get_request(url.as_str()) {
-            Ok(resp) => {
-                let text = resp.text().unwrap();
-                match serde_json::from_str(&text) {
-                    Ok(val) => Ok(val),
-                    Err(_e) => {
-                        match serde_json::from_str(&text) {
-                            Ok(e) => {
-                                let err: error::FredError = e;
-                                let err_msg = format!(
-                                    "ERROR {}: {}",
-                                    err.error_code,
-                                    err.error_message
-                                );
-                                return Err(err_msg);
-                            },
-                            Err(msg) => return Err(String::from(msg.to_string())),
-                        }
-                    },
-                }
-            },
-            Err(e) => return Err(e.to_string()),
+    /// [See fred_rs::category::series](../category/series/index.html)
+    /// 
+    /// # Arguments
+    /// `category_id` - The id for a category [[Link]](https://research.stlouisfed.org/docs/api/fred/series.html#category_id)
+    pub fn category_series(
+        &mut self,
+        category_id: usize,
+        builder: Option<category::series::Builder>
+    ) -> Result<series::Response, FredError> {
+        match endpoint::category_series(&self.url_base, &self.api_key, category_id, builder) {
+            Ok(url) => self.fetch(url.as_str()),
+            Err(msg) => Err(msg),
         }
     }
 
-    /// [See fred_rs::release::tables](../release/tables/index.html)
+    /// Collects every page of results for [category_series](#method.category_series)
+    /// 
+    /// Pages are requested as in [series_search_all](#method.series_search_all) until every result has been returned or `max_results` series have been collected.  Any limit or offset set on the builder is ignored.
     /// 
     /// # Arguments
-    /// `release_id` - The id for a release [[Link]](https://research.stlouisfed.org/docs/api/fred/release_tables.html#release_id)
-    pub fn release_tables(
+    /// `category_id` - The id for a category [[Link]](https://research.stlouisfed.org/docs/api/fred/category_series.html#category_id)
+    /// 
+    /// `max_results` - The maximum number of series to collect or None to collect every result
+    pub fn category_series_all(
+        &mut self,
+        category_id: usize,
+        builder: Option<category::series::Builder>,
+        max_results: Option<usize>
+    ) -> Result<Collected<series::Series>, FredError> {
+        match endpoint::category_series(&self.url_base, &self.api_key, category_id, builder) {
+            Ok(url) => self.collect_pages::<series::Response>(url.as_str(), max_results),
+            Err(msg) => Err(msg),
+        }
+    }
+
+    /// Returns the `n` most popular series in a category
+    /// 
+    /// Requests fred/category/series ordered by popularity in descending order, so the headline indicators of the category come first.  At most 1000 series are returned.
+    /// 
+    /// # Arguments
+    /// `category_id` - The id for a category [[Link]](https://research.stlouisfed.org/docs/api/fred/category_series.html#category_id)
+    /// 
+    /// `n` - The number of series to return
+    pub fn category_top_series(
+        &mut self,
+        category_id: usize,
+        n: usize,
+    ) -> Result<Vec<series::Series>, FredError> {
+        let mut builder = category::series::Builder::new();
+        builder
+            .order_by(category::series::OrderBy::Popularity)
+            .sort_order(category::series::SortOrder::Descending)
+            .limit(n);
+
+        match self.category_series(category_id, Some(builder)) {
+            Ok(resp) => Ok(resp.seriess),
+            Err(msg) => Err(msg),
+        }
+    }
+
+    /// [See fred_rs::category::tags](../category/tags/index.html)
+    /// 
+    /// # Arguments
+    /// `category_id` - The id for a category [[Link]](https://research.stlouisfed.org/docs/api/fred/category_tags.html#category_id)
+    pub fn category_tags(
+        &mut self,
+        category_id: usize,
+        builder: Option<category::tags::Builder>
+    ) -> Result<tags::Response, FredError> {
+        match endpoint::category_tags(&self.url_base, &self.api_key, category_id, builder) {
+            Ok(url) => self.fetch(url.as_str()),
+            Err(msg) => Err(msg),
+        }
+    }
+
+    /// [See fred_rs::category::related_tags](../category/related_tags/index.html)
+    /// 
+    /// # Arguments
+    /// `category_id` - The id for a category [[Link]](https://research.stlouisfed.org/docs/api/fred/category_related_tags.html#category_id)
+    pub fn category_related_tags(
+        &mut self,
+        category_id: usize,
+        builder: category::related_tags::Builder
+    ) -> Result<tags::Response, FredError> {
+        match endpoint::category_related_tags(&self.url_base, &self.api_key, category_id, builder) {
+            Ok(url) => self.fetch(url.as_str()),
+            Err(msg) => Err(msg),
+        }
+    }
+
+    // ----------------------------------------------------------------------
+    // Releases
+
+    /// [See fred_rs::releases](../releases/index.html)
+    pub fn releases(
+        &mut self,
+        builder: Option<releases::Builder>
+    ) -> Result<release::Response, FredError> {
+        match endpoint::releases(&self.url_base, &self.api_key, builder) {
+            Ok(url) => self.fetch(url.as_str()),
+            Err(msg) => Err(msg),
+        }
+    }
+
+    /// Collects every page of results for [releases](#method.releases)
+    /// 
+    /// Pages are requested as in [series_search_all](#method.series_search_all) until every result has been returned or `max_results` releases have been collected.  Any limit or offset set on the builder is ignored.
+    /// 
+    /// # Arguments
+    /// `max_results` - The maximum number of releases to collect or None to collect every result
+    pub fn releases_all(
+        &mut self,
+        builder: Option<releases::Builder>,
+        max_results: Option<usize>
+    ) -> Result<Collected<release::Release>, FredError> {
+        match endpoint::releases(&self.url_base, &self.api_key, builder) {
+            Ok(url) => self.collect_pages::<release::Response>(url.as_str(), max_results),
+            Err(msg) => Err(msg),
+        }
+    }
+
+    /// [See fred_rs::releases::dates](../releases/dates/index.html)
+    pub fn releases_dates(
+        &mut self,
+        builder: Option<releases::dates::Builder>
+    ) -> Result<releases::dates::Response, FredError> {
+        match endpoint::releases_dates(&self.url_base, &self.api_key, builder) {
+            Ok(url) => self.fetch(url.as_str()),
+            Err(msg) => Err(msg),
+        }
+    }
+
+    // ----------------------------------------------------------------------
+    // Release
+
+    /// [See fred_rs::release](../release/index.html)
+    /// 
+    /// # Arguments
+    /// `release_id` - The id for a release [[Link]](https://research.stlouisfed.org/docs/api/fred/release.html#release_id)
+    pub fn release(
+        &mut self,
+        release_id: usize,
+        builder: Option<release::Builder>
+    ) -> Result<release::Response, FredError> {
+        match endpoint::release(&self.url_base, &self.api_key, release_id, builder) {
+            Ok(url) => self.fetch(url.as_str()),
+            Err(msg) => Err(msg),
+        }
+    }
+
+    /// [See fred_rs::release::series](../release/series/index.html)
+    /// 
+    /// # Arguments
+    /// `release_id` - The id for a release [[Link]](https://research.stlouisfed.org/docs/api/fred/release_series.html#release_id)
+    pub fn release_series(
+        &mut self,
+        release_id: usize,
+        builder: Option<release::series::Builder>
+    ) -> Result<series::Response, FredError> {
+        match endpoint::release_series(&self.url_base, &self.api_key, release_id, builder) {
+            Ok(url) => self.fetch(url.as_str()),
+            Err(msg) => Err(msg),
+        }
+    }
+
+    /// Collects every page of results for [release_series](#method.release_series)
+    /// 
+    /// Pages are requested as in [series_search_all](#method.series_search_all) until every result has been returned or `max_results` series have been collected.  Any limit or offset set on the builder is ignored.
+    /// 
+    /// # Arguments
+    /// `release_id` - The id for a release [[Link]](https://research.stlouisfed.org/docs/api/fred/release_series.html#release_id)
+    /// 
+    /// `max_results` - The maximum number of series to collect or None to collect every result
+    pub fn release_series_all(
+        &mut self,
+        release_id: usize,
+        builder: Option<release::series::Builder>,
+        max_results: Option<usize>
+    ) -> Result<Collected<series::Series>, FredError> {
+        match endpoint::release_series(&self.url_base, &self.api_key, release_id, builder) {
+            Ok(url) => self.collect_pages::<series::Response>(url.as_str(), max_results),
+            Err(msg) => Err(msg),
+        }
+    }
+
+    /// [See fred_rs::release::dates](../release/dates/index.html)
+    /// 
+    /// # Arguments
+    /// `release_id` - The id for a release [[Link]](https://research.stlouisfed.org/docs/api/fred/release_dates.html#release_id)
+    pub fn release_dates(
+        &mut self,
+        release_id: usize,
+        builder: Option<release::dates::Builder>
+    ) -> Result<releases::dates::Response, FredError> {
+        match endpoint::release_dates(&self.url_base, &self.api_key, release_id, builder) {
+            Ok(url) => self.fetch(url.as_str()),
+            Err(msg) => Err(msg),
+        }
+    }
+
+    /// Infers how often a release is published from its most recent release dates
+    /// 
+    /// The last 25 release dates are requested and passed to [ReleaseCadence::from_dates](../release/dates/enum.ReleaseCadence.html#method.from_dates).  Returns None if the release has fewer than three release dates.
+    /// 
+    /// # Arguments
+    /// `release_id` - The id for a release [[Link]](https://research.stlouisfed.org/docs/api/fred/release_dates.html#release_id)
+    pub fn release_frequency(
+        &mut self,
+        release_id: usize,
+    ) -> Result<Option<release::dates::ReleaseCadence>, FredError> {
+        let mut builder = release::dates::Builder::new();
+        builder
+            .sort_order(release::dates::SortOrder::Descending)
+            .limit(25);
+
+        match self.release_dates(release_id, Some(builder)) {
+            Ok(resp) => {
+                let dates: Vec<&str> = resp.release_dates.iter().map(|d| d.date.as_str()).collect();
+                Ok(release::dates::ReleaseCadence::from_dates(&dates))
+            },
+            Err(msg) => Err(msg),
+        }
+    }
+
+    /// [See fred_rs::release::sources](../release/sources/index.html)
+    /// 
+    /// # Arguments
+    /// `release_id` - The id for a release [[Link]](https://research.stlouisfed.org/docs/api/fred/release_sources.html#release_id)
+    pub fn release_sources(
+        &mut self,
+        release_id: usize,
+        builder: Option<release::sources::Builder>
+    ) -> Result<source::Response, FredError> {
+        match endpoint::release_sources(&self.url_base, &self.api_key, release_id, builder) {
+            Ok(url) => self.fetch(url.as_str()),
+            Err(msg) => Err(msg),
+        }
+    }
+
+    /// Returns the (name, link) of each source providing data for a release
+    /// 
+    /// The link is None for sources FRED has no website for.
+    /// 
+    /// # Arguments
+    /// `release_id` - The id for a release [[Link]](https://research.stlouisfed.org/docs/api/fred/release_sources.html#release_id)
+    pub fn release_source_links(
+        &mut self,
+        release_id: usize
+    ) -> Result<Vec<(String, Option<String>)>, FredError> {
+        match self.release_sources(release_id, None) {
+            Ok(resp) => Ok(resp.sources.into_iter().map(|item| (item.name, item.link)).collect()),
+            Err(msg) => Err(msg),
+        }
+    }
+
+    /// [See fred_rs::release::tags](../release/tags/index.html)
+    /// 
+    /// # Arguments
+    /// `release_id` - The id for a release [[Link]](https://research.stlouisfed.org/docs/api/fred/release_tags.html#release_id)
+    pub fn release_tags(
+        &mut self,
+        release_id: usize,
+        builder: Option<release::tags::Builder>
+    ) -> Result<tags::Response, FredError> {
+        match endpoint::release_tags(&self.url_base, &self.api_key, release_id, builder) {
+            Ok(url) => self.fetch(url.as_str()),
+            Err(msg) => Err(msg),
+        }
+    }
+
+    /// [See fred_rs::release::related_tags](../release/related_tags/index.html)
+    /// 
+    /// # Arguments
+    /// `release_id` - The id for a release [[Link]](https://research.stlouisfed.org/docs/api/fred/release_related_tags.html#release_id)
+    pub fn release_related_tags(
+        &mut self,
+        release_id: usize,
+        builder: release::related_tags::Builder
+    ) -> Result<tags::Response, FredError> {
+        match endpoint::release_related_tags(&self.url_base, &self.api_key, release_id, builder) {
+            Ok(url) => self.fetch(url.as_str()),
+            Err(msg) => Err(msg),
+        }
+    }
+
+    /// [See fred_rs::release::tables](../release/tables/index.html)
+    /// 
+    /// # Arguments
+    /// `release_id` - The id for a release [[Link]](https://research.stlouisfed.org/docs/api/fred/release_tables.html#release_id)
+    pub fn release_tables(
         &mut self,
         release_id: usize,
         builder: Option<release::tables::Builder>
-    ) -> Result<release::tables::Response, String> {
-        let mut url: String = format!(
-            "{}release/tables?release_id={}&api_key={}&file_type=json",
-            self.url_base,
-            release_id,
-            self.api_key
-        );
+    ) -> Result<release::tables::Response, FredError> {
+        match endpoint::release_tables(&self.url_base, &self.api_key, release_id, builder) {
+            Ok(url) => self.fetch(url.as_str()),
+            Err(msg) => Err(msg),
+        }
+    }
+
+    // ----------------------------------------------------------------------
+    // Maps
+
+    /// [See fred_rs::maps::series_data](../maps/series_data/index.html)
+    /// 
+    /// The request is made to the maps (GeoFRED) API, see [fred_rs::maps](../maps/index.html).
+    /// 
+    /// # Arguments
+    /// `series_id` - The id for a series in a regional series group [[Link]](https://research.stlouisfed.org/docs/api/geofred/series_data.html#series_id)
+    pub fn maps_series_data(
+        &mut self,
+        series_id: &str,
+        builder: Option<maps::series_data::Builder>
+    ) -> Result<maps::Response, FredError> {
+        match endpoint::maps_series_data(&self.url_base, &self.api_key, series_id, builder) {
+            Ok(url) => self.fetch(url.as_str()),
+            Err(msg) => Err(msg),
+        }
+    }
+
+    /// [See fred_rs::maps::regional_data](../maps/regional_data/index.html)
+    /// 
+    /// The request is made to the maps (GeoFRED) API, see [fred_rs::maps](../maps/index.html).
+    pub fn maps_regional_data(
+        &mut self,
+        builder: maps::regional_data::Builder
+    ) -> Result<maps::Response, FredError> {
+        match endpoint::maps_regional_data(&self.url_base, &self.api_key, builder) {
+            Ok(url) => self.fetch(url.as_str()),
+            Err(msg) => Err(msg),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+/// Sliding window of the requests made in the last minute
+struct RateLimiter {
+    per_minute: u32,
+    sent: VecDeque<Instant>,
+}
+
+impl RateLimiter {
+    fn new(per_minute: u32) -> RateLimiter {
+        RateLimiter {
+            per_minute,
+            sent: VecDeque::new(),
+        }
+    }
+
+    /// Returns how long to wait at `now` before another request may be made
+    fn wait_time(&mut self, now: Instant) -> Option<Duration> {
+        let window = Duration::from_secs(60);
+        while let Some(first) = self.sent.front() {
+            if now.duration_since(*first) >= window {
+                self.sent.pop_front();
+            } else {
+                break;
+            }
+        }
+
+        if self.sent.len() < self.per_minute as usize {
+            return None;
+        }
+        self.sent.front().map(|first| window - now.duration_since(*first))
+    }
 
-        match builder {
-            Some(b) => url.push_str(b.build().as_str()),
+    /// Sleeps until a request may be made and records it
+    fn acquire(&mut self) {
+        match self.wait_time(Instant::now()) {
+            Some(wait) => thread::sleep(wait),
             None => (),
         }
-        
-        match self.get_request(url.as_str()) {
-            Ok(resp) => {
-                let text = resp.text().unwrap();
-                match serde_json::from_str(&text) {
-                    Ok(val) => Ok(val),
-                    Err(_e) => {
-                        match serde_json::from_str(&text) {
-                            Ok(e) => {
-                                let err: error::FredError = e;
-                                let err_msg = format!(
-                                    "ERROR {}: {}",
-                                    err.error_code,
-                                    err.error_message
-                                );
-                                return Err(err_msg);
-                            },
-                            Err(msg) => return Err(String::from(msg.to_string())),
-                        }
-                    },
-                }
+        self.sent.push_back(Instant::now());
+    }
+}
+
+/// Returns the delay requested by the Retry-After header of a response, if it is given in seconds
+fn retry_after(headers: &header::HeaderMap) -> Option<Duration> {
+    let value = headers.get(header::RETRY_AFTER)?.to_str().ok()?;
+    value.trim().parse::<u64>().ok().map(Duration::from_secs)
+}
+
+/// Returns `url` with a trailing slash so endpoint paths can be appended to it
+fn base_url(url: &str) -> String {
+    if url.ends_with('/') {
+        String::from(url)
+    } else {
+        format!("{}/", url)
+    }
+}
+
+/// Returns how long to wait before retrying a request after `attempt` earlier retries
+/// 
+/// The delay doubles with each retry and stops growing after ten retries.
+fn retry_delay(attempt: u32) -> Duration {
+    RETRY_BASE_DELAY * 2u32.pow(attempt.min(10))
+}
+
+/// Returns true if the `last_updated` timestamp of a series is after `since` (in seconds since the epoch)
+/// 
+/// A last_updated FRED sends in an unexpected format counts as updated, so the data is fetched rather than silently skipped.
+fn updated_since(last_updated: &str, since: i64) -> bool {
+    match timestamp_seconds(last_updated) {
+        Some(updated) => updated > since,
+        None => true,
+    }
+}
+
+/// Splits the series responses of series_titles into a map of titles and the ids that failed
+/// 
+/// Returns the last error if no id resolved.
+fn collect_titles(
+    results: Vec<(String, Result<series::Response, FredError>)>
+) -> Result<SeriesTitles, FredError> {
+    let mut titles = HashMap::new();
+    let mut failures = Vec::new();
+
+    for (id, result) in results {
+        match result {
+            Ok(resp) => match resp.seriess.into_iter().next() {
+                Some(s) => {
+                    titles.insert(id, s.title);
+                },
+                None => {
+                    let err = FredError::NotFound(format!("No series found for id {}", id));
+                    failures.push((id, err));
+                },
             },
-            Err(e) => return Err(e.to_string()),
+            Err(e) => failures.push((id, e)),
+        }
+    }
+
+    if titles.is_empty() {
+        if let Some((_, e)) = failures.pop() {
+            return Err(e);
+        }
+    }
+    Ok(SeriesTitles { titles, failures })
+}
+
+/// Returns the key a cached observation response is stored under for the arguments of `builder`
+/// 
+/// The all flag is not a request argument so it is added to the hashed arguments, keeping a limited request and a request for every page apart.
+#[cfg(feature = "sqlite")]
+fn cache_key(builder: Option<&series::observation::Builder>) -> Result<String, FredError> {
+    let params = match builder {
+        Some(b) => match b.peek() {
+            Ok(params) if b.is_all() => params + "&all",
+            Ok(params) => params,
+            Err(msg) => return Err(FredError::InvalidArgument(msg)),
+        },
+        None => String::new(),
+    };
+    Ok(cache::params_hash(params.as_str()))
+}
+
+/// Appends the file_type argument to a request URL and removes repeated arguments
+fn request_url(url: &str, file_type: &str) -> String {
+    normalize_query(format!("{}&file_type={}", url, file_type).as_str())
+}
+
+/// Returns Err if the Content-Type of a response does not match the requested file type
+/// 
+/// FRED serves an HTML page instead of the requested format during maintenance windows.  The error carries the status code of the response so it can be matched on like any other API error.
+fn check_content_type(headers: &header::HeaderMap, status: StatusCode, file_type: &str) -> Result<(), FredError> {
+    let matches_format = match headers.get(header::CONTENT_TYPE) {
+        Some(ctype) => match ctype.to_str() {
+            Ok(ctype) => ctype.contains(file_type),
+            Err(_) => false,
+        },
+        None => true,
+    };
+    if !matches_format {
+        return Err(FredError::Api {
+            code: status.as_u16(),
+            message: format!("FRED returned a non-{} response (maintenance?)", file_type.to_uppercase()),
+        });
+    }
+    Ok(())
+}
+
+/// Parses a response body with `format`, returning Err with FRED's message if the body is an error response
+/// 
+/// The error response is checked first since responses whose fields all have defaults (e.g. category::Response) would otherwise parse from it.  The body must be valid UTF-8; a body that is not is reported as such rather than as a parse error.
+fn parse_body<T: DeserializeOwned, R: ResponseFormat>(format: &R, bytes: &[u8]) -> Result<T, FredError> {
+    match std::str::from_utf8(bytes) {
+        Ok(_) => (),
+        Err(e) => return Err(FredError::InvalidUtf8(e)),
+    }
+    match format.parse::<error::ErrorResponse>(bytes) {
+        Ok(err) => return Err(FredError::Api { code: err.error_code, message: err.error_message }),
+        Err(_) => (),
+    }
+    format.parse(bytes)
+}
+
+/// Removes repeated query arguments from a request URL, keeping the last occurrence of each
+/// 
+/// Every FRED argument takes a single value, so when an argument is set more than once (e.g. a builder overriding a value set by a client helper) the last value wins instead of FRED choosing one.
+fn normalize_query(url: &str) -> String {
+    let (base, query) = match url.find('?') {
+        Some(i) => (&url[..i], &url[i + 1..]),
+        None => return String::from(url),
+    };
+
+    let key = |arg: &str| match arg.find('=') {
+        Some(i) => String::from(&arg[..i]),
+        None => String::from(arg),
+    };
+    let args: Vec<&str> = query.split('&').filter(|arg| !arg.is_empty()).collect();
+    let kept: Vec<&str> = args.iter()
+        .enumerate()
+        .filter(|(i, arg)| !args[i + 1..].iter().any(|later| key(later) == key(arg)))
+        .map(|(_, arg)| *arg)
+        .collect();
+
+    format!("{}?{}", base, kept.join("&"))
+}
+
+/// Removes the limit and offset arguments from a builder's option string so a helper can page through the results itself
+fn without_paging(options: &str) -> String {
+    options
+        .split('&')
+        .filter(|arg| !arg.is_empty() && !arg.starts_with("limit=") && !arg.starts_with("offset="))
+        .map(|arg| format!("&{}", arg))
+        .collect()
+}
+
+/// Writes observation responses as a wide CSV with one row per date and one column per series
+fn write_wide_csv<W: Write>(
+    writer: &mut W,
+    series_ids: &[&str],
+    responses: &[series::observation::Response],
+) -> std::io::Result<()> {
+    let mut rows: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    for (col, resp) in responses.iter().enumerate() {
+        for obs in &resp.observations {
+            let row = rows.entry(obs.date.as_str()).or_insert_with(|| vec![""; responses.len()]);
+            if obs.value != "." {
+                row[col] = obs.value.as_str();
+            }
         }
     }
+
+    writeln!(writer, "date,{}", series_ids.join(","))?;
+    for (date, values) in rows {
+        writeln!(writer, "{},{}", date, values.join(","))?;
+    }
+    Ok(())
 }
 
 #[cfg(test)]
@@ -1519,4 +1929,938 @@ mod tests {
             },
         }
     }
+
+    #[test]
+    fn client_series_siblings() {
+        let mut c = match FredClient::new() {
+            Ok(c) => c,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+
+        let siblings = match c.series_siblings("UNRATE") {
+            Ok(s) => s,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+
+        assert!(siblings.iter().all(|s| s.id != "UNRATE"));
+    }
+
+    #[test]
+    fn client_series_titles() {
+        let mut c = match FredClient::new() {
+            Ok(c) => c,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+
+        let SeriesTitles { titles, failures } = match c.series_titles(&["UNRATE", "GNPCA", "NOTASERIES"]) {
+            Ok(t) => t,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+
+        assert_eq!(titles.len(), 2);
+        assert_eq!(titles["UNRATE"], "Unemployment Rate");
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].0, "NOTASERIES");
+    }
+
+    #[test]
+    fn client_collect_titles() {
+        let mut found = series::Response::default();
+        found.seriess.push(series::Series {
+            id: String::from("UNRATE"),
+            title: String::from("Unemployment Rate"),
+            ..series::Series::default()
+        });
+
+        let results = vec![
+            (String::from("UNRATE"), Ok(found)),
+            (String::from("UNRTAE"), Err(FredError::Api { code: 400, message: String::from("Bad Request.  The series does not exist.") })),
+            (String::from("EMPTY"), Ok(series::Response::default())),
+        ];
+        match collect_titles(results) {
+            Ok(SeriesTitles { titles, failures }) => {
+                assert_eq!(titles.len(), 1);
+                assert_eq!(titles["UNRATE"], "Unemployment Rate");
+                assert_eq!(failures.len(), 2);
+                match &failures[0] {
+                    (id, FredError::Api { code, .. }) => {
+                        assert_eq!(id, "UNRTAE");
+                        assert_eq!(*code, 400);
+                    },
+                    _ => assert_eq!(2, 1),
+                }
+                match &failures[1] {
+                    (id, FredError::NotFound(_)) => assert_eq!(id, "EMPTY"),
+                    _ => assert_eq!(2, 1),
+                }
+            },
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+            },
+        }
+
+        // nothing resolved
+        let results = vec![(String::from("UNRTAE"), Err(FredError::MissingApiKey))];
+        match collect_titles(results) {
+            Err(FredError::MissingApiKey) => (),
+            _ => assert_eq!(2, 1),
+        }
+    }
+
+    #[test]
+    fn client_series_observation_if_changed() {
+        let mut c = match FredClient::new() {
+            Ok(c) => c,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+
+        match c.series_observation_if_changed("UNRATE", "1900-01-01", None) {
+            Ok(resp) => assert!(resp.is_some()),
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+            },
+        }
+
+        match c.series_observation_if_changed("UNRATE", "9999-12-31", None) {
+            Ok(resp) => assert!(resp.is_none()),
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+            },
+        }
+    }
+
+    #[test]
+    fn client_series_values() {
+        let mut c = match FredClient::new() {
+            Ok(c) => c,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+
+        let mut builder = series::observation::Builder::new();
+        builder
+            .observation_start("2000-01-01")
+            .observation_end("2000-12-31");
+
+        match c.series_values("UNRATE", Some(builder)) {
+            Ok(values) => {
+                assert_eq!(values.len(), 12);
+                assert!(values.iter().all(|v| v.is_some()));
+            },
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+            },
+        }
+    }
+
+    #[test]
+    fn client_series_latest() {
+        let mut c = match FredClient::new() {
+            Ok(c) => c,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+
+        let first = match c.series_first("UNRATE") {
+            Ok(Some(item)) => item,
+            Ok(None) => {
+                assert_eq!(2, 1);
+                return
+            },
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+        assert_eq!(first.date, "1948-01-01");
+
+        match c.series_latest("UNRATE") {
+            Ok(Some(item)) => assert!(item.date > first.date),
+            Ok(None) => assert_eq!(2, 1),
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+            },
+        }
+    }
+
+    #[test]
+    fn client_series_observation_all() {
+        let mut c = match FredClient::new() {
+            Ok(c) => c,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+
+        let mut builder = series::observation::Builder::new();
+        builder
+            .limit(5)
+            .all();
+
+        match c.series_observation("GNPCA", Some(builder)) {
+            Ok(resp) => {
+                assert!(resp.observations.len() > 5);
+                assert_eq!(resp.observations.len(), resp.count);
+            },
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+            },
+        }
+    }
+
+    #[test]
+    fn client_without_paging() {
+        assert_eq!(
+            without_paging("&limit=5&order_by=popularity&offset=10&sort_order=desc"),
+            "&order_by=popularity&sort_order=desc"
+        );
+        assert_eq!(without_paging(""), "");
+    }
+
+    #[test]
+    fn client_series_search_all_budget() {
+        let mut c = match FredClient::new() {
+            Ok(c) => c,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+
+        let resp = match c.series_search_all("money", None, Some(1500)) {
+            Ok(resp) => resp,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+
+        assert_eq!(resp.items.len(), 1500);
+        assert!(resp.truncated);
+    }
+
+    #[test]
+    fn client_category_series_all() {
+        let mut c = match FredClient::new() {
+            Ok(c) => c,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+
+        let mut builder = category::series::Builder::new();
+        builder.limit(5);
+
+        // category 125 (Trade Balance) has fewer series than one page
+        let resp = match c.category_series_all(125, Some(builder), None) {
+            Ok(resp) => resp,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+
+        assert!(resp.items.len() > 5);
+        assert!(!resp.truncated);
+    }
+
+    #[test]
+    fn client_observations_wide_csv() {
+        let mut c = match FredClient::new() {
+            Ok(c) => c,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+
+        let mut out = Vec::new();
+        let result = c.observations_wide_csv(
+            &["UNRATE", "CPIAUCSL"],
+            |_| {
+                let mut builder = series::observation::Builder::new();
+                builder
+                    .observation_start("2000-01-01")
+                    .observation_end("2000-03-01");
+                builder
+            },
+            &mut out,
+        );
+        match result {
+            Ok(_) => {
+                let text = String::from_utf8(out).unwrap();
+                let lines: Vec<&str> = text.lines().collect();
+                assert_eq!(lines.len(), 4);
+                assert_eq!(lines[0], "date,UNRATE,CPIAUCSL");
+                assert!(lines[1].starts_with("2000-01-01,"));
+            },
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+            },
+        }
+    }
+
+    #[test]
+    fn client_write_wide_csv() {
+        let point = |date: &str, value: &str| series::observation::DataPoint {
+            date: String::from(date),
+            value: String::from(value),
+            ..series::observation::DataPoint::default()
+        };
+        let mut a = series::observation::Response::default();
+        a.observations = vec![point("2020-01-01", "1.0"), point("2020-01-02", ".")];
+        let mut b = series::observation::Response::default();
+        b.observations = vec![point("2020-01-02", "5"), point("2020-01-03", "6")];
+
+        let mut out = Vec::new();
+        write_wide_csv(&mut out, &["A", "B"], &[a, b]).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "date,A,B\n2020-01-01,1.0,\n2020-01-02,,5\n2020-01-03,,6\n"
+        );
+    }
+
+    #[test]
+    fn client_series_observation_with_fallback() {
+        let mut c = match FredClient::new() {
+            Ok(c) => c,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+
+        let mut builder = series::observation::Builder::new();
+        builder
+            .units(series::observation::Units::PC1)
+            .observation_start("2000-01-01")
+            .observation_end("2000-12-31");
+
+        match c.series_observation_with_fallback("UNRATE", Some(builder)) {
+            Ok(resp) => {
+                assert!(!resp.fell_back);
+                assert_eq!(resp.units, "pc1");
+                assert_eq!(resp.response.observations.len(), 12);
+            },
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+            },
+        }
+    }
+
+    #[test]
+    fn client_series_empty_id() {
+        let mut c = match FredClient::new_offline() {
+            Ok(c) => c,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+
+        for id in ["", "  "].iter() {
+            match c.series(id, None) {
+                Ok(_) => assert_eq!(2, 1),
+                Err(FredError::InvalidArgument(msg)) => assert_eq!(msg.as_str(), error::EMPTY_SERIES_ID_ERROR_TEXT),
+                Err(_) => assert_eq!(2, 1),
+            }
+        }
+    }
+
+    #[test]
+    fn client_find_seasonal_variants() {
+        let mut c = match FredClient::new() {
+            Ok(c) => c,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+
+        match c.find_seasonal_variants("PAYEMS") {
+            Ok(variants) => {
+                assert!(variants.iter().any(|s| s.id == "PAYNSA"));
+                assert!(variants.iter().all(|s| s.id != "PAYEMS"));
+            },
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+            },
+        }
+    }
+
+    #[test]
+    fn client_category_top_series() {
+        let mut c = match FredClient::new() {
+            Ok(c) => c,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+
+        match c.category_top_series(125, 5) {
+            Ok(top) => {
+                assert_eq!(top.len(), 5);
+                assert!(top.windows(2).all(|w| w[0].popularity >= w[1].popularity));
+            },
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+            },
+        }
+    }
+
+    #[test]
+    fn client_release_source_links() {
+        let mut c = match FredClient::new() {
+            Ok(c) => c,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+
+        match c.release_source_links(51) {
+            Ok(links) => {
+                assert!(!links.is_empty());
+                for (name, link) in links {
+                    println!("{}: {:?}", name, link);
+                }
+            },
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+            },
+        }
+    }
+
+    #[test]
+    fn client_series_observation_batch_with_progress() {
+        let mut c = match FredClient::new() {
+            Ok(c) => c,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+
+        let mut progress = Vec::new();
+        let ids = ["GNPCA", "UNRATE"];
+        let builder_fn = |_: &str| {
+            let mut builder = series::observation::Builder::new();
+            builder.limit(5);
+            builder
+        };
+        match c.series_observation_batch_with_progress(&ids, builder_fn, |done, total| progress.push((done, total))) {
+            Ok(responses) => assert_eq!(responses.len(), 2),
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+            },
+        }
+        assert_eq!(progress, vec![(1, 2), (2, 2)]);
+    }
+
+    #[test]
+    fn client_recently_updated() {
+        let mut c = match FredClient::new() {
+            Ok(c) => c,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+
+        match c.recently_updated("unemployment rate", 5) {
+            Ok(series) => {
+                assert_eq!(series.len(), 5);
+                for item in series {
+                    println!("{}: {}", item.id, item.last_updated);
+                }
+            },
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+            },
+        }
+    }
+
+    #[test]
+    fn client_config() {
+        let mut c = match FredClient::new_offline() {
+            Ok(c) => c,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+        c.with_key("abcdefghijklmnopqrstuvwxyz123456");
+
+        let config = c.config();
+        assert_eq!(config.url_base, FRED_BASE_URL);
+
+        c.with_base_url("http://127.0.0.1:8080/fred");
+        assert_eq!(c.config().url_base, "http://127.0.0.1:8080/fred/");
+        c.with_base_url("http://127.0.0.1:8080/fred/");
+        assert_eq!(c.config().url_base, "http://127.0.0.1:8080/fred/");
+        assert_eq!(config.timeout_secs, 30);
+        assert_eq!(config.retries, 0);
+        assert_eq!(config.file_type, "json");
+
+        c.with_retries(3);
+        assert_eq!(c.config().retries, 3);
+        assert_eq!(config.rate_limit, None);
+
+        c.with_rate_limit(120);
+        assert_eq!(c.config().rate_limit, Some(120));
+        c.with_rate_limit(0);
+        assert_eq!(c.config().rate_limit, None);
+
+        let json = serde_json::to_string(&config).unwrap();
+        assert!(!json.contains("abcdefghijklmnopqrstuvwxyz123456"));
+    }
+
+    #[cfg(feature = "xml")]
+    #[test]
+    fn client_series_observation_xml() {
+        let mut c = match FredClient::new() {
+            Ok(c) => c.with_file_type(FileType::Xml),
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+
+        let mut builder = series::observation::Builder::new();
+        builder
+            .observation_start("2000-01-01")
+            .limit(5);
+
+        match c.series_observation("GNPCA", Some(builder)) {
+            Ok(resp) => {
+                assert_eq!(resp.observations.len(), 5);
+                assert_eq!(resp.observations[0].date, "2000-01-01");
+            },
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+            },
+        }
+    }
+
+    #[test]
+    fn client_new_offline() {
+        let mut c = match FredClient::new_offline() {
+            Ok(c) => c,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+        c.with_key("");
+
+        // fails before any request is made
+        match c.series("GNPCA", None) {
+            Err(FredError::MissingApiKey) => (),
+            _ => assert_eq!(2, 1),
+        }
+    }
+
+    #[test]
+    fn client_invalid_date() {
+        let mut c = match FredClient::new_offline() {
+            Ok(c) => c,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+        c.with_key("abcdefghijklmnopqrstuvwxyz123456");
+        // nothing listens here so a request that was sent would fail with a network error
+        c.with_base_url("http://127.0.0.1:9/fred");
+
+        let mut builder = series::Builder::new();
+        builder.realtime_start("yesterday");
+        match c.series("GNPCA", Some(builder)) {
+            Err(FredError::InvalidArgument(msg)) => assert!(msg.contains(error::INVALID_DATE_ERROR_TEXT)),
+            _ => assert_eq!(2, 1),
+        }
+    }
+
+    #[test]
+    fn client_series_observations_multi() {
+        let mut c = match FredClient::new_offline() {
+            Ok(c) => c,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+        c.with_key("");
+
+        let mut builder = series::observation::Builder::new();
+        builder.limit(5);
+
+        let results = c.series_observations_multi(&["GNPCA", "UNRATE"], Some(builder));
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].0, "GNPCA");
+        assert_eq!(results[1].0, "UNRATE");
+        for (_, result) in results {
+            match result {
+                Err(FredError::MissingApiKey) => (),
+                _ => assert_eq!(2, 1),
+            }
+        }
+    }
+
+    #[test]
+    fn client_retry_delay() {
+        assert_eq!(retry_delay(0), Duration::from_millis(250));
+        assert_eq!(retry_delay(1), Duration::from_millis(500));
+        assert_eq!(retry_delay(2), Duration::from_secs(1));
+        assert_eq!(retry_delay(50), retry_delay(10));
+    }
+
+    #[test]
+    fn client_rate_limiter() {
+        let mut limiter = RateLimiter::new(2);
+        let start = Instant::now();
+
+        assert_eq!(limiter.wait_time(start), None);
+        limiter.sent.push_back(start);
+        assert_eq!(limiter.wait_time(start), None);
+        limiter.sent.push_back(start + Duration::from_secs(10));
+
+        assert_eq!(limiter.wait_time(start + Duration::from_secs(20)), Some(Duration::from_secs(40)));
+        // the first request has left the window
+        assert_eq!(limiter.wait_time(start + Duration::from_secs(60)), None);
+        assert_eq!(limiter.sent.len(), 1);
+    }
+
+    #[test]
+    fn client_throttled_retries() {
+        let mut c = match FredClient::new_offline() {
+            Ok(c) => c,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+        assert_eq!(c.throttled_retries(), 0);
+
+        // a rate limited client honors Retry-After without with_retries
+        c.with_rate_limit(120);
+        assert_eq!(c.throttled_retries(), 1);
+
+        c.with_retries(3);
+        assert_eq!(c.throttled_retries(), 3);
+
+        c.with_rate_limit(0);
+        assert_eq!(c.throttled_retries(), 3);
+    }
+
+    #[test]
+    fn client_retry_after() {
+        let mut headers = header::HeaderMap::new();
+        assert_eq!(retry_after(&headers), None);
+
+        headers.insert(header::RETRY_AFTER, header::HeaderValue::from_static("30"));
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(30)));
+
+        headers.insert(header::RETRY_AFTER, header::HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"));
+        assert_eq!(retry_after(&headers), None);
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn client_cache_key() {
+        let mut limited = series::observation::Builder::new();
+        limited.limit(5);
+        let mut all = limited.clone();
+        all.all();
+
+        match (cache_key(Some(&limited)), cache_key(Some(&all))) {
+            (Ok(limited), Ok(all)) => assert_ne!(limited, all),
+            _ => assert_eq!(2, 1),
+        }
+        match (cache_key(Some(&limited)), cache_key(Some(&limited.clone()))) {
+            (Ok(a), Ok(b)) => assert_eq!(a, b),
+            _ => assert_eq!(2, 1),
+        }
+    }
+
+    #[test]
+    fn client_updated_since() {
+        let since = timestamp_seconds("2020-11-01 01:30:00-05").unwrap();
+        // an hour later, reported after the change from daylight to standard time
+        assert!(updated_since("2020-11-01 01:30:00-06", since));
+        // earlier, although it orders after `since` as a string
+        assert!(!updated_since("2020-11-01 01:45:00-04", since));
+        assert!(!updated_since("2020-11-01 00:30:00-06", since));
+
+        let since = timestamp_seconds("2020-11-01").unwrap();
+        assert!(updated_since("2020-11-01 09:26:16-05", since));
+        assert!(!updated_since("2020-10-31 18:59:59-05", since));
+
+        assert!(updated_since("unknown", since));
+    }
+
+    #[test]
+    fn client_check_content_type() {
+        let mut headers = header::HeaderMap::new();
+        // FRED does not always send a Content-Type
+        assert!(check_content_type(&headers, StatusCode::OK, "json").is_ok());
+
+        headers.insert(header::CONTENT_TYPE, header::HeaderValue::from_static("application/json; charset=UTF-8"));
+        assert!(check_content_type(&headers, StatusCode::OK, "json").is_ok());
+
+        headers.insert(header::CONTENT_TYPE, header::HeaderValue::from_static("text/html"));
+        match check_content_type(&headers, StatusCode::SERVICE_UNAVAILABLE, "json") {
+            Err(FredError::Api { code, message }) => {
+                assert_eq!(code, 503);
+                assert_eq!(message, "FRED returned a non-JSON response (maintenance?)");
+            },
+            _ => assert_eq!(2, 1),
+        }
+        match check_content_type(&headers, StatusCode::OK, "xml") {
+            Err(FredError::Api { code, message }) => {
+                assert_eq!(code, 200);
+                assert_eq!(message, "FRED returned a non-XML response (maintenance?)");
+            },
+            _ => assert_eq!(2, 1),
+        }
+    }
+
+    #[test]
+    fn client_series_observation_dual() {
+        let mut c = match FredClient::new() {
+            Ok(c) => c,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+
+        let mut builder = series::observation::Builder::new();
+        builder.observation_start("2010-01-01");
+
+        match c.series_observation_dual("GNPCA", series::observation::Units::PCH, Some(builder)) {
+            Ok((levels, changes)) => {
+                assert_eq!(levels.units, "lin");
+                assert_eq!(changes.units, "pch");
+                assert_eq!(levels.observations.len(), changes.observations.len());
+                for (level, change) in levels.observations.iter().zip(changes.observations.iter()) {
+                    assert_eq!(level.date, change.date);
+                }
+            },
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+            },
+        }
+    }
+
+    #[test]
+    fn client_parse_body() {
+        let body = br#"{"categories":[{"id":125,"name":"Trade Balance","parent_id":13}]}"#;
+        match parse_body::<category::Response, _>(&Json, body) {
+            Ok(resp) => assert_eq!(resp.categories[0].id, 125),
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+            },
+        }
+
+        let body = br#"{"error_code":400,"error_message":"Bad Request.  Variable api_key is not set."}"#;
+        match parse_body::<category::Response, _>(&Json, body) {
+            Err(FredError::Api { code, message }) => {
+                assert_eq!(code, 400);
+                assert_eq!(message, "Bad Request.  Variable api_key is not set.");
+            },
+            _ => assert_eq!(2, 1),
+        }
+
+        // a multi-byte character cut off part way through
+        let body = b"{\"categories\":[{\"id\":125,\"name\":\"Trade \xE2\x82";
+        match parse_body::<category::Response, _>(&Json, body) {
+            Err(FredError::InvalidUtf8(e)) => assert_eq!(e.valid_up_to(), 39),
+            _ => assert_eq!(2, 1),
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn client_build_panel() {
+        let mut c = match FredClient::new() {
+            Ok(c) => c,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+
+        let panel = match c.build_panel(
+            &["GDPC1", "UNRATE"],
+            series::observation::Frequency::Q,
+            series::observation::AggregationMethod::AVG,
+        ) {
+            Ok(panel) => panel,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+
+        assert_eq!(panel.columns.len(), 2);
+        assert_eq!(panel.columns[0].0, "GDPC1");
+        for (_, values) in panel.columns.iter() {
+            assert_eq!(values.len(), panel.dates.len());
+        }
+    }
+
+    #[test]
+    fn client_timeseries() {
+        let mut c = match FredClient::new() {
+            Ok(c) => c,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+
+        let mut builder = series::observation::Builder::new();
+        builder
+            .observation_start("2000-01-01")
+            .observation_end("2000-12-31");
+
+        match c.timeseries("UNRATE", Some(builder)) {
+            Ok(ts) => {
+                assert_eq!(ts.meta.id, "UNRATE");
+                assert_eq!(ts.values().len(), 12);
+                assert_eq!(ts.latest().unwrap().date, "2000-12-01");
+            },
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+            },
+        }
+    }
+
+    #[test]
+    fn client_normalize_query() {
+        assert_eq!(
+            normalize_query("https://x/fred/series?series_id=GDP&realtime_start=2000-01-01&api_key=k&realtime_start=2010-01-01"),
+            "https://x/fred/series?series_id=GDP&api_key=k&realtime_start=2010-01-01"
+        );
+        assert_eq!(
+            normalize_query("https://x/fred/tags?api_key=k&tag_names=usa;gdp&&limit=5"),
+            "https://x/fred/tags?api_key=k&tag_names=usa;gdp&limit=5"
+        );
+        assert_eq!(normalize_query("https://x/fred/tags"), "https://x/fred/tags");
+    }
+
+    #[test]
+    fn client_release_frequency() {
+        let mut c = match FredClient::new() {
+            Ok(c) => c,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+
+        // Employment Situation
+        match c.release_frequency(50) {
+            Ok(cadence) => assert_eq!(cadence, Some(release::dates::ReleaseCadence::Monthly)),
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+            },
+        }
+    }
+
+    #[test]
+    fn client_series_tag_names() {
+        let mut c = match FredClient::new() {
+            Ok(c) => c,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+
+        match c.series_tag_names("STLFSI") {
+            Ok(names) => {
+                assert!(!names.is_empty());
+                assert!(names.iter().any(|name| name == "weekly"));
+            },
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+            },
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,1116 @@
+//! Non-blocking client for use inside an async runtime
+//! 
+//! Requires the `async` feature.
+//! 
+//! ```
+//! use fred_rs::client::AsyncFredClient;
+//! use fred_rs::series::observation::{Builder, Units, Frequency, Response};
+//! 
+//! let mut rt = tokio::runtime::Runtime::new().unwrap();
+//! rt.block_on(async {
+//!     // Create the client object
+//!     let c = match AsyncFredClient::new().await {
+//!         Ok(c) => c,
+//!         Err(msg) => {
+//!             println!("{}", msg);
+//!             return
+//!         },
+//!     };
+//! 
+//!     // Create the argument builder
+//!     let mut builder = Builder::new();
+//!     builder
+//!         .observation_start("2000-01-01")
+//!         .units(Units::PCH)
+//!         .frequency(Frequency::M);
+//! 
+//!     // Make the request and await the response
+//!     let resp: Response = match c.series_observation("GNPCA", Some(builder)).await {
+//!         Ok(resp) => resp,
+//!         Err(msg) => {
+//!             println!("{}", msg);
+//!             return
+//!         },
+//!     };
+//! });
+//! ```
+
+use futures::future::{join_all, try_join3};
+use reqwest::Client;
+use serde::de::DeserializeOwned;
+
+use std::time::Duration;
+use std::env;
+
+use crate::*;
+use crate::common::check_date_args;
+use crate::error::{self, FredError};
+use crate::format::{FileType, Json, ResponseFormat};
+
+use super::{
+    endpoint,
+    paging,
+    base_url,
+    check_content_type,
+    parse_body,
+    request_url,
+    Collected,
+    FredClientConfig,
+    DEFAULT_TIMEOUT,
+    FRED_API_KEY,
+    FRED_BASE_URL,
+};
+
+const DEFAULT_CONCURRENCY: usize = 4;
+
+#[derive(Clone, Debug)]
+/// Persistent client object used to access the FRED API without blocking
+/// 
+/// Offers the same endpoint methods as [FredClient](struct.FredClient.html), each returning a future which resolves to the same response object.  Requests are built identically for both clients.
+/// 
+/// Methods take `&self` so a single client can make several requests concurrently.
+pub struct AsyncFredClient<R: ResponseFormat = Json> {
+    client: Client,
+    url_base: String,
+    api_key: String,
+    timeout: Duration,
+    concurrency: usize,
+    format: R,
+}
+
+impl AsyncFredClient {
+
+    /// Creates and initializes a new client object
+    /// 
+    /// The client will attempt to load an API key from the environment variable 'FRED_API_KEY'.  If this variable is undefined, the key remains empty.
+    /// 
+    /// If a connection cannot be made to the FRED API, it returns Err containing an error message.
+    pub async fn new() -> Result<AsyncFredClient, FredError> {
+
+        let fred = match AsyncFredClient::new_offline() {
+            Ok(fred) => fred,
+            Err(e) => return Err(e),
+        };
+
+        let url = format!("{}category?category_id=125&api_key={}&file_type={}", fred.url_base, fred.api_key, fred.format.file_type());
+        match fred.client.get(url.as_str()).send().await {
+            Ok(_) => (),
+            Err(e) => return Err(FredError::Network(e)),
+        }
+
+        return Ok(fred)
+
+    }
+
+    /// Creates a new client object without checking the connection to the FRED API
+    /// 
+    /// Behaves like [new](#method.new) but makes no request, so it does not need to be awaited.
+    /// 
+    /// Returns Err if the underlying HTTP client cannot be created.
+    pub fn new_offline() -> Result<AsyncFredClient, FredError> {
+        let client = match Client::builder().timeout(DEFAULT_TIMEOUT).build() {
+            Ok(c) => c,
+            Err(e) => return Err(FredError::Network(e)),
+        };
+
+        let api_key = match env::var(FRED_API_KEY) {
+            Ok(val) => val,
+            Err(_) => String::from(""),
+        };
+
+        Ok(AsyncFredClient {
+            client,
+            url_base: String::from(FRED_BASE_URL),
+            api_key,
+            timeout: DEFAULT_TIMEOUT,
+            concurrency: DEFAULT_CONCURRENCY,
+            format: Json,
+        })
+    }
+}
+
+impl<R: ResponseFormat> AsyncFredClient<R> {
+
+    /// Changes the format the client requests and parses responses with
+    /// 
+    /// # Arguments
+    /// * `format` - The [ResponseFormat](../format/trait.ResponseFormat.html) to use for subsequent requests
+    pub fn with_format<G: ResponseFormat>(self, format: G) -> AsyncFredClient<G> {
+        AsyncFredClient {
+            client: self.client,
+            url_base: self.url_base,
+            api_key: self.api_key,
+            timeout: self.timeout,
+            concurrency: self.concurrency,
+            format,
+        }
+    }
+
+    /// Changes the file type the client requests, see [FileType](../format/enum.FileType.html)
+    /// 
+    /// # Arguments
+    /// * `file_type` - The file type to use for subsequent requests
+    pub fn with_file_type(self, file_type: FileType) -> AsyncFredClient<FileType> {
+        self.with_format(file_type)
+    }
+
+    /// Returns the settings of the client, without the API key
+    /// 
+    /// The async client does not retry or rate limit requests so `retries` is always 0 and `rate_limit` is always None.
+    pub fn config(&self) -> FredClientConfig {
+        FredClientConfig {
+            url_base: self.url_base.clone(),
+            timeout_secs: self.timeout.as_secs(),
+            retries: 0,
+            rate_limit: None,
+            file_type: String::from(self.format.file_type()),
+        }
+    }
+
+    /// Sets the FRED API key for the client
+    /// 
+    /// # Arguments
+    /// * `key` - The [API key](https://research.stlouisfed.org/docs/api/api_key.html) generated to access FRED 
+    pub fn with_key(&mut self, key: &str) {
+        self.api_key = String::from(key);
+    }
+
+    /// Sets the URL every request is made relative to
+    /// 
+    /// The default is https://api.stlouisfed.org/fred/.  Pointing the client at a local mock server lets tests run against recorded responses without network access or an API key.  A trailing slash is added if `url` does not end with one.
+    /// 
+    /// # Arguments
+    /// * `url` - The base URL, e.g. http://127.0.0.1:8080/fred/
+    pub fn with_base_url(&mut self, url: &str) {
+        self.url_base = base_url(url);
+    }
+
+    /// Limits how many requests [series_observations_multi](#method.series_observations_multi) has in flight at once
+    /// 
+    /// The default is 4.  A limit of 0 is treated as 1.
+    /// 
+    /// # Arguments
+    /// * `limit` - The maximum number of concurrent requests
+    pub fn with_concurrency(&mut self, limit: usize) {
+        self.concurrency = std::cmp::max(limit, 1);
+    }
+
+    async fn fetch<T: DeserializeOwned>(&self, url: &str) -> Result<T, FredError> {
+        if self.api_key.is_empty() {
+            return Err(FredError::MissingApiKey);
+        }
+
+        match check_date_args(url) {
+            Ok(()) => (),
+            Err(e) => return Err(e),
+        }
+        let url = request_url(url, self.format.file_type());
+        let resp = match self.client.get(url.as_str()).send().await {
+            Ok(r) => r,
+            Err(e) => return Err(FredError::Network(e)),
+        };
+
+        match check_content_type(resp.headers(), resp.status(), self.format.file_type()) {
+            Ok(()) => (),
+            Err(msg) => return Err(msg),
+        }
+
+        let bytes = match resp.bytes().await {
+            Ok(bytes) => bytes,
+            Err(e) => return Err(FredError::Network(e)),
+        };
+        parse_body(&self.format, &bytes)
+    }
+
+    /// Requests pages of `url` until every result has been collected or `max_results` is reached
+    /// 
+    /// Pages are requested one at a time so paging never has more than one request in flight.
+    async fn collect_pages<P: paging::Page>(
+        &self,
+        url: &str,
+        max_results: Option<usize>
+    ) -> Result<Collected<P::Item>, FredError> {
+        let mut pager = paging::Pager::new(max_results);
+        while let Some(page_url) = pager.next_url(url) {
+            let page: P = match self.fetch(page_url.as_str()).await {
+                Ok(page) => page,
+                Err(msg) => return Err(msg),
+            };
+            pager.add(page);
+        }
+        Ok(pager.finish())
+    }
+
+    // ----------------------------------------------------------------------
+    // Series
+
+    /// [See fred_rs::series](../series/index.html)
+    /// 
+    /// Returns Err without making a request if `series_id` is empty.
+    /// 
+    /// # Arguments
+    /// `series_id` - The id for a series [[Link]](https://research.stlouisfed.org/docs/api/fred/series.html#series_id)
+    pub async fn series(
+        &self,
+        series_id: &str,
+        builder: Option<series::Builder>
+    ) -> Result<series::Response, FredError> {
+        match endpoint::series(&self.url_base, &self.api_key, series_id, builder) {
+            Ok(url) => self.fetch(url.as_str()).await,
+            Err(msg) => Err(msg),
+        }
+    }
+
+    /// [See fred_rs::series::categories](../series/categories/index.html)
+    /// 
+    /// # Arguments
+    /// `series_id` - The id for a series [[Link]](https://research.stlouisfed.org/docs/api/fred/series_categories.html#series_id)
+    pub async fn series_categories(
+        &self,
+        series_id: &str,
+        builder: Option<series::categories::Builder>
+    ) -> Result<category::Response, FredError> {
+        match endpoint::series_categories(&self.url_base, &self.api_key, series_id, builder) {
+            Ok(url) => self.fetch(url.as_str()).await,
+            Err(msg) => Err(msg),
+        }
+    }
+
+    /// [See fred_rs::series::observation](../series/observation/index.html)
+    /// 
+    /// # Arguments
+    /// `series_id` - The id for a series [[Link]](https://research.stlouisfed.org/docs/api/fred/series_observation.html#series_id)
+    pub async fn series_observation(
+        &self,
+        series_id: &str,
+        builder: Option<series::observation::Builder>
+    ) -> Result<series::observation::Response, FredError> {
+        let all = match &builder {
+            Some(b) => b.is_all(),
+            None => false,
+        };
+        let url = match endpoint::series_observation(&self.url_base, &self.api_key, series_id, builder) {
+            Ok(url) => url,
+            Err(msg) => return Err(msg),
+        };
+
+        if all {
+            return self.series_observation_pages(url.as_str()).await;
+        }
+
+        self.fetch(url.as_str()).await
+    }
+
+    /// Fetches the observations of several series with the same arguments, see [FredClient::series_observations_multi](struct.FredClient.html#method.series_observations_multi)
+    /// 
+    /// Up to the limit set with [with_concurrency](#method.with_concurrency) requests are made at once.  Each series id is returned with the result of its request, in the order of `series_ids`.
+    /// 
+    /// # Arguments
+    /// `series_ids` - The ids of the series to request
+    /// 
+    /// `builder` - The arguments applied to every request
+    pub async fn series_observations_multi(
+        &self,
+        series_ids: &[&str],
+        builder: Option<series::observation::Builder>
+    ) -> Vec<(String, Result<series::observation::Response, FredError>)> {
+        let mut results = Vec::with_capacity(series_ids.len());
+        for ids in series_ids.chunks(self.concurrency) {
+            let responses = join_all(ids.iter().map(|id| self.series_observation(id, builder.clone()))).await;
+            results.extend(ids.iter().map(|id| String::from(*id)).zip(responses));
+        }
+        results
+    }
+
+    /// Requests every page of observations for `url` and merges them into a single response
+    async fn series_observation_pages(
+        &self,
+        url: &str
+    ) -> Result<series::observation::Response, FredError> {
+        let first_url = endpoint::observation_page(url, 0);
+        let mut resp: series::observation::Response = match self.fetch(first_url.as_str()).await {
+            Ok(resp) => resp,
+            Err(msg) => return Err(msg),
+        };
+
+        while resp.observations.len() < resp.count {
+            let page_url = endpoint::observation_page(url, resp.observations.len());
+            let page: series::observation::Response = match self.fetch(page_url.as_str()).await {
+                Ok(page) => page,
+                Err(msg) => return Err(msg),
+            };
+            if page.observations.is_empty() {
+                break;
+            }
+            resp.observations.extend(page.observations);
+        }
+
+        resp.offset = 0;
+        resp.limit = resp.observations.len();
+        Ok(resp)
+    }
+
+    /// [See fred_rs::series::release](../series/release/index.html)
+    /// 
+    /// # Arguments
+    /// `series_id` - The id for a series [[Link]](https://research.stlouisfed.org/docs/api/fred/series_release.html#series_id)
+    pub async fn series_release(
+        &self,
+        series_id: &str,
+        builder: Option<series::release::Builder>
+    ) -> Result<release::Response, FredError> {
+        match endpoint::series_release(&self.url_base, &self.api_key, series_id, builder) {
+            Ok(url) => self.fetch(url.as_str()).await,
+            Err(msg) => Err(msg),
+        }
+    }
+
+    /// [See fred_rs::series::tags](../series/tags/index.html)
+    /// 
+    /// # Arguments
+    /// `series_id` - The id for a series [[Link]](https://research.stlouisfed.org/docs/api/fred/series_tags.html#series_id)
+    pub async fn series_tags(
+        &self,
+        series_id: &str,
+        builder: Option<series::tags::Builder>
+    ) -> Result<tags::Response, FredError> {
+        match endpoint::series_tags(&self.url_base, &self.api_key, series_id, builder) {
+            Ok(url) => self.fetch(url.as_str()).await,
+            Err(msg) => Err(msg),
+        }
+    }
+
+    /// [See fred_rs::series::updates](../series/updates/index.html)
+    pub async fn series_updates(
+        &self,
+        builder: Option<series::updates::Builder>
+    ) -> Result<series::updates::Response, FredError> {
+        match endpoint::series_updates(&self.url_base, &self.api_key, builder) {
+            Ok(url) => self.fetch(url.as_str()).await,
+            Err(msg) => Err(msg),
+        }
+    }
+
+    /// [See fred_rs::series::vintagedates](../series/vintagedates/index.html)
+    /// 
+    /// # Arguments
+    /// `series_id` - The id for a series [[Link]](https://research.stlouisfed.org/docs/api/fred/series_vintagedates.html#series_id)
+    pub async fn series_vintagedates(
+        &self,
+        series_id: &str,
+        builder: Option<series::vintagedates::Builder>
+    ) -> Result<series::vintagedates::Response, FredError> {
+        match endpoint::series_vintagedates(&self.url_base, &self.api_key, series_id, builder) {
+            Ok(url) => self.fetch(url.as_str()).await,
+            Err(msg) => Err(msg),
+        }
+    }
+
+    // ----------------------------------------------------------------------
+    // Series/Search
+
+    /// [See fred_rs::series::search](../series/search/index.html)
+    /// 
+    /// # Arguments
+    /// `search_text` - The words to match against economic data series, as plain text (the client percent-encodes it) [[Link]](https://research.stlouisfed.org/docs/api/fred/series_search.html#search_text)
+    pub async fn series_search(
+        &self,
+        search_text: &str,
+        builder: Option<series::search::Builder>
+    ) -> Result<series::Response, FredError> {
+        match endpoint::series_search(&self.url_base, &self.api_key, search_text, builder) {
+            Ok(url) => self.fetch(url.as_str()).await,
+            Err(msg) => Err(msg),
+        }
+    }
+
+    /// Collects every page of results for a series search
+    /// 
+    /// The search is repeated with an increasing offset until all of the matching series have been returned or `max_results` series have been collected, whichever comes first.  Any limit or offset set on the builder is ignored.  When the budget stops the search early the result is flagged as truncated.
+    /// 
+    /// Pages are requested one after another rather than concurrently, so collecting a large result set does not flood FRED with requests.
+    /// 
+    /// # Arguments
+    /// `search_text` - The words to match against economic data series, as plain text (the client percent-encodes it) [[Link]](https://research.stlouisfed.org/docs/api/fred/series_search.html#search_text)
+    /// 
+    /// `max_results` - The maximum number of series to collect or None to collect every result
+    pub async fn series_search_all(
+        &self,
+        search_text: &str,
+        builder: Option<series::search::Builder>,
+        max_results: Option<usize>
+    ) -> Result<Collected<series::Series>, FredError> {
+        match endpoint::series_search(&self.url_base, &self.api_key, search_text, builder) {
+            Ok(url) => self.collect_pages::<series::Response>(url.as_str(), max_results).await,
+            Err(msg) => Err(msg),
+        }
+    }
+
+    /// [See fred_rs::series::search::tags](../series/search/tags/index.html)
+    /// 
+    /// # Arguments
+    /// `series_search_text` - The words to match against economic data series, as plain text (the client percent-encodes it) [[Link]](https://research.stlouisfed.org/docs/api/fred/series_search_tags.html#search_text)
+    pub async fn series_search_tags(
+        &self,
+        series_search_text: &str,
+        builder: Option<series::search::tags::Builder>
+    ) -> Result<tags::Response, FredError> {
+        match endpoint::series_search_tags(&self.url_base, &self.api_key, series_search_text, builder) {
+            Ok(url) => self.fetch(url.as_str()).await,
+            Err(msg) => Err(msg),
+        }
+    }
+
+    /// [See fred_rs::series::search::related_tags](../series/search/related_tags/index.html)
+    /// 
+    /// # Arguments
+    /// `series_search_text` - The words to match against economic data series, as plain text (the client percent-encodes it) [[Link]](https://research.stlouisfed.org/docs/api/fred/series_search_related_tags.html#search_text)
+    pub async fn series_search_related_tags(
+        &self,
+        series_search_text: &str,
+        builder: series::search::related_tags::Builder
+    ) -> Result<tags::Response, FredError> {
+        match endpoint::series_search_related_tags(&self.url_base, &self.api_key, series_search_text, builder) {
+            Ok(url) => self.fetch(url.as_str()).await,
+            Err(msg) => Err(msg),
+        }
+    }
+
+    // ----------------------------------------------------------------------
+    // Tags
+
+    /// [See fred_rs::tags](../tags/index.html)
+    pub async fn tags(
+        &self,
+        builder: Option<tags::Builder>
+    ) -> Result<tags::Response, FredError> {
+        match endpoint::tags(&self.url_base, &self.api_key, builder) {
+            Ok(url) => self.fetch(url.as_str()).await,
+            Err(msg) => Err(msg),
+        }
+    }
+
+    /// Collects every page of results for [tags](#method.tags)
+    /// 
+    /// Pages are requested as in [series_search_all](#method.series_search_all) until every result has been returned or `max_results` tags have been collected.  Any limit or offset set on the builder is ignored.
+    /// 
+    /// # Arguments
+    /// `max_results` - The maximum number of tags to collect or None to collect every result
+    pub async fn tags_all(
+        &self,
+        builder: Option<tags::Builder>,
+        max_results: Option<usize>
+    ) -> Result<Collected<tags::Tag>, FredError> {
+        match endpoint::tags(&self.url_base, &self.api_key, builder) {
+            Ok(url) => self.collect_pages::<tags::Response>(url.as_str(), max_results).await,
+            Err(msg) => Err(msg),
+        }
+    }
+
+    /// [See fred_rs::tags::series](../tags/series/index.html)
+    pub async fn tags_series(
+        &self,
+        builder: tags::series::Builder
+    ) -> Result<series::Response, FredError> {
+        match endpoint::tags_series(&self.url_base, &self.api_key, builder) {
+            Ok(url) => self.fetch(url.as_str()).await,
+            Err(msg) => Err(msg),
+        }
+    }
+
+    // ----------------------------------------------------------------------
+    // Related Tags
+
+    /// [See fred_rs::related_tags](../related_tags/index.html)
+    pub async fn related_tags(
+        &self,
+        builder: related_tags::Builder
+    ) -> Result<tags::Response, FredError> {
+        match endpoint::related_tags(&self.url_base, &self.api_key, builder) {
+            Ok(url) => self.fetch(url.as_str()).await,
+            Err(msg) => Err(msg),
+        }
+    }
+
+    // ----------------------------------------------------------------------
+    // Sources
+
+    /// [See fred_rs::sources](../sources/index.html)
+    pub async fn sources(
+        &self,
+        builder: Option<sources::Builder>
+    ) -> Result<source::Response, FredError> {
+        match endpoint::sources(&self.url_base, &self.api_key, builder) {
+            Ok(url) => self.fetch(url.as_str()).await,
+            Err(msg) => Err(msg),
+        }
+    }
+
+    /// Collects every page of results for [sources](#method.sources)
+    /// 
+    /// Pages are requested as in [series_search_all](#method.series_search_all) until every result has been returned or `max_results` sources have been collected.  Any limit or offset set on the builder is ignored.
+    /// 
+    /// # Arguments
+    /// `max_results` - The maximum number of sources to collect or None to collect every result
+    pub async fn sources_all(
+        &self,
+        builder: Option<sources::Builder>,
+        max_results: Option<usize>
+    ) -> Result<Collected<source::Source>, FredError> {
+        match endpoint::sources(&self.url_base, &self.api_key, builder) {
+            Ok(url) => self.collect_pages::<source::Response>(url.as_str(), max_results).await,
+            Err(msg) => Err(msg),
+        }
+    }
+
+    // ----------------------------------------------------------------------
+    // Source
+
+    /// [See fred_rs::source](../source/index.html)
+    /// 
+    /// # Arguments
+    /// `source_id` - The id for a source [[Link]](https://research.stlouisfed.org/docs/api/fred/source.html#source_id)
+    pub async fn source(
+        &self,
+        source_id: usize,
+        builder: Option<source::Builder>
+    ) -> Result<source::Response, FredError> {
+        match endpoint::source(&self.url_base, &self.api_key, source_id, builder) {
+            Ok(url) => self.fetch(url.as_str()).await,
+            Err(msg) => Err(msg),
+        }
+    }
+
+    /// [See fred_rs::source::releases](../source/releases/index.html)
+    /// 
+    /// # Arguments
+    /// `source_id` - The id for a source [[Link]](https://research.stlouisfed.org/docs/api/fred/source_releases.html#source_id)
+    pub async fn source_releases(
+        &self,
+        source_id: usize,
+        builder: Option<source::releases::Builder>
+    ) -> Result<release::Response, FredError> {
+        match endpoint::source_releases(&self.url_base, &self.api_key, source_id, builder) {
+            Ok(url) => self.fetch(url.as_str()).await,
+            Err(msg) => Err(msg),
+        }
+    }
+
+    // ----------------------------------------------------------------------
+    // Category
+
+    /// [See fred_rs::category](../category/index.html)
+    /// 
+    /// The fred/category endpoint accepts no optional arguments (categories are not versioned by realtime period) so there is no builder.  The related endpoints which do accept realtime_start and realtime_end, such as [category_children](#method.category_children) and [category_related](#method.category_related), take a builder.
+    /// 
+    /// # Arguments
+    /// `category_id` - The id for a category [[Link]](https://research.stlouisfed.org/docs/api/fred/category.html#category_id)
+    pub async fn category(
+        &self,
+        category_id: usize
+    ) -> Result<category::Response, FredError> {
+        match endpoint::category(&self.url_base, &self.api_key, category_id) {
+            Ok(url) => self.fetch(url.as_str()).await,
+            Err(msg) => Err(msg),
+        }
+    }
+
+    /// Returns a category together with its child categories and first page of series
+    /// 
+    /// Requests fred/category, fred/category/children and fred/category/series concurrently, returning the first error encountered.
+    /// 
+    /// # Arguments
+    /// `category_id` - The id for a category [[Link]](https://research.stlouisfed.org/docs/api/fred/category.html#category_id)
+    pub async fn category_overview(
+        &self,
+        category_id: usize
+    ) -> Result<category::CategoryOverview, FredError> {
+        let (category, children, series) = match try_join3(
+            self.category(category_id),
+            self.category_children(category_id, None),
+            self.category_series(category_id, None),
+        ).await {
+            Ok(responses) => responses,
+            Err(msg) => return Err(msg),
+        };
+
+        let category = match category.categories.into_iter().next() {
+            Some(category) => category,
+            None => return Err(FredError::NotFound(String::from(error::CATEGORY_NOT_FOUND_ERROR_TEXT))),
+        };
+        Ok(category::CategoryOverview {
+            category,
+            children: children.categories,
+            series: series.seriess,
+        })
+    }
+
+    /// [See fred_rs::category::children](../category/children/index.html)
+    /// 
+    /// # Arguments
+    /// `category_id` - The id for a category [[Link]](https://research.stlouisfed.org/docs/api/fred/category_children.html#category_id)
+    pub async fn category_children(
+        &self,
+        category_id: usize,
+        builder: Option<category::children::Builder>,
+    ) -> Result<category::Response, FredError> {
+        match endpoint::category_children(&self.url_base, &self.api_key, category_id, builder) {
+            Ok(url) => self.fetch(url.as_str()).await,
+            Err(msg) => Err(msg),
+        }
+    }
+
+    /// [See fred_rs::category::related](../category/related/index.html)
+    /// 
+    /// # Arguments
+    /// `category_id` - The id for a category [[Link]](https://research.stlouisfed.org/docs/api/fred/category_related.html#category_id)
+    pub async fn category_related(
+        &self,
+        category_id: usize,
+        builder: Option<category::related::Builder>,
+    ) -> Result<category::Response, FredError> {
+        match endpoint::category_related(&self.url_base, &self.api_key, category_id, builder) {
+            Ok(url) => self.fetch(url.as_str()).await,
+            Err(msg) => Err(msg),
+        }
+    }
+
+    /// [See fred_rs::category::series](../category/series/index.html)
+    /// 
+    /// # Arguments
+    /// `category_id` - The id for a category [[Link]](https://research.stlouisfed.org/docs/api/fred/series.html#category_id)
+    pub async fn category_series(
+        &self,
+        category_id: usize,
+        builder: Option<category::series::Builder>
+    ) -> Result<series::Response, FredError> {
+        match endpoint::category_series(&self.url_base, &self.api_key, category_id, builder) {
+            Ok(url) => self.fetch(url.as_str()).await,
+            Err(msg) => Err(msg),
+        }
+    }
+
+    /// Collects every page of results for [category_series](#method.category_series)
+    /// 
+    /// Pages are requested as in [series_search_all](#method.series_search_all) until every result has been returned or `max_results` series have been collected.  Any limit or offset set on the builder is ignored.
+    /// 
+    /// # Arguments
+    /// `category_id` - The id for a category [[Link]](https://research.stlouisfed.org/docs/api/fred/category_series.html#category_id)
+    /// 
+    /// `max_results` - The maximum number of series to collect or None to collect every result
+    pub async fn category_series_all(
+        &self,
+        category_id: usize,
+        builder: Option<category::series::Builder>,
+        max_results: Option<usize>
+    ) -> Result<Collected<series::Series>, FredError> {
+        match endpoint::category_series(&self.url_base, &self.api_key, category_id, builder) {
+            Ok(url) => self.collect_pages::<series::Response>(url.as_str(), max_results).await,
+            Err(msg) => Err(msg),
+        }
+    }
+
+    /// [See fred_rs::category::tags](../category/tags/index.html)
+    /// 
+    /// # Arguments
+    /// `category_id` - The id for a category [[Link]](https://research.stlouisfed.org/docs/api/fred/category_tags.html#category_id)
+    pub async fn category_tags(
+        &self,
+        category_id: usize,
+        builder: Option<category::tags::Builder>
+    ) -> Result<tags::Response, FredError> {
+        match endpoint::category_tags(&self.url_base, &self.api_key, category_id, builder) {
+            Ok(url) => self.fetch(url.as_str()).await,
+            Err(msg) => Err(msg),
+        }
+    }
+
+    /// [See fred_rs::category::related_tags](../category/related_tags/index.html)
+    /// 
+    /// # Arguments
+    /// `category_id` - The id for a category [[Link]](https://research.stlouisfed.org/docs/api/fred/category_related_tags.html#category_id)
+    pub async fn category_related_tags(
+        &self,
+        category_id: usize,
+        builder: category::related_tags::Builder
+    ) -> Result<tags::Response, FredError> {
+        match endpoint::category_related_tags(&self.url_base, &self.api_key, category_id, builder) {
+            Ok(url) => self.fetch(url.as_str()).await,
+            Err(msg) => Err(msg),
+        }
+    }
+
+    // ----------------------------------------------------------------------
+    // Releases
+
+    /// [See fred_rs::releases](../releases/index.html)
+    pub async fn releases(
+        &self,
+        builder: Option<releases::Builder>
+    ) -> Result<release::Response, FredError> {
+        match endpoint::releases(&self.url_base, &self.api_key, builder) {
+            Ok(url) => self.fetch(url.as_str()).await,
+            Err(msg) => Err(msg),
+        }
+    }
+
+    /// Collects every page of results for [releases](#method.releases)
+    /// 
+    /// Pages are requested as in [series_search_all](#method.series_search_all) until every result has been returned or `max_results` releases have been collected.  Any limit or offset set on the builder is ignored.
+    /// 
+    /// # Arguments
+    /// `max_results` - The maximum number of releases to collect or None to collect every result
+    pub async fn releases_all(
+        &self,
+        builder: Option<releases::Builder>,
+        max_results: Option<usize>
+    ) -> Result<Collected<release::Release>, FredError> {
+        match endpoint::releases(&self.url_base, &self.api_key, builder) {
+            Ok(url) => self.collect_pages::<release::Response>(url.as_str(), max_results).await,
+            Err(msg) => Err(msg),
+        }
+    }
+
+    /// [See fred_rs::releases::dates](../releases/dates/index.html)
+    pub async fn releases_dates(
+        &self,
+        builder: Option<releases::dates::Builder>
+    ) -> Result<releases::dates::Response, FredError> {
+        match endpoint::releases_dates(&self.url_base, &self.api_key, builder) {
+            Ok(url) => self.fetch(url.as_str()).await,
+            Err(msg) => Err(msg),
+        }
+    }
+
+    // ----------------------------------------------------------------------
+    // Release
+
+    /// [See fred_rs::release](../release/index.html)
+    /// 
+    /// # Arguments
+    /// `release_id` - The id for a release [[Link]](https://research.stlouisfed.org/docs/api/fred/release.html#release_id)
+    pub async fn release(
+        &self,
+        release_id: usize,
+        builder: Option<release::Builder>
+    ) -> Result<release::Response, FredError> {
+        match endpoint::release(&self.url_base, &self.api_key, release_id, builder) {
+            Ok(url) => self.fetch(url.as_str()).await,
+            Err(msg) => Err(msg),
+        }
+    }
+
+    /// [See fred_rs::release::series](../release/series/index.html)
+    /// 
+    /// # Arguments
+    /// `release_id` - The id for a release [[Link]](https://research.stlouisfed.org/docs/api/fred/release_series.html#release_id)
+    pub async fn release_series(
+        &self,
+        release_id: usize,
+        builder: Option<release::series::Builder>
+    ) -> Result<series::Response, FredError> {
+        match endpoint::release_series(&self.url_base, &self.api_key, release_id, builder) {
+            Ok(url) => self.fetch(url.as_str()).await,
+            Err(msg) => Err(msg),
+        }
+    }
+
+    /// Collects every page of results for [release_series](#method.release_series)
+    /// 
+    /// Pages are requested as in [series_search_all](#method.series_search_all) until every result has been returned or `max_results` series have been collected.  Any limit or offset set on the builder is ignored.
+    /// 
+    /// # Arguments
+    /// `release_id` - The id for a release [[Link]](https://research.stlouisfed.org/docs/api/fred/release_series.html#release_id)
+    /// 
+    /// `max_results` - The maximum number of series to collect or None to collect every result
+    pub async fn release_series_all(
+        &self,
+        release_id: usize,
+        builder: Option<release::series::Builder>,
+        max_results: Option<usize>
+    ) -> Result<Collected<series::Series>, FredError> {
+        match endpoint::release_series(&self.url_base, &self.api_key, release_id, builder) {
+            Ok(url) => self.collect_pages::<series::Response>(url.as_str(), max_results).await,
+            Err(msg) => Err(msg),
+        }
+    }
+
+    /// [See fred_rs::release::dates](../release/dates/index.html)
+    /// 
+    /// # Arguments
+    /// `release_id` - The id for a release [[Link]](https://research.stlouisfed.org/docs/api/fred/release_dates.html#release_id)
+    pub async fn release_dates(
+        &self,
+        release_id: usize,
+        builder: Option<release::dates::Builder>
+    ) -> Result<releases::dates::Response, FredError> {
+        match endpoint::release_dates(&self.url_base, &self.api_key, release_id, builder) {
+            Ok(url) => self.fetch(url.as_str()).await,
+            Err(msg) => Err(msg),
+        }
+    }
+
+    /// [See fred_rs::release::sources](../release/sources/index.html)
+    /// 
+    /// # Arguments
+    /// `release_id` - The id for a release [[Link]](https://research.stlouisfed.org/docs/api/fred/release_sources.html#release_id)
+    pub async fn release_sources(
+        &self,
+        release_id: usize,
+        builder: Option<release::sources::Builder>
+    ) -> Result<source::Response, FredError> {
+        match endpoint::release_sources(&self.url_base, &self.api_key, release_id, builder) {
+            Ok(url) => self.fetch(url.as_str()).await,
+            Err(msg) => Err(msg),
+        }
+    }
+
+    /// [See fred_rs::release::tags](../release/tags/index.html)
+    /// 
+    /// # Arguments
+    /// `release_id` - The id for a release [[Link]](https://research.stlouisfed.org/docs/api/fred/release_tags.html#release_id)
+    pub async fn release_tags(
+        &self,
+        release_id: usize,
+        builder: Option<release::tags::Builder>
+    ) -> Result<tags::Response, FredError> {
+        match endpoint::release_tags(&self.url_base, &self.api_key, release_id, builder) {
+            Ok(url) => self.fetch(url.as_str()).await,
+            Err(msg) => Err(msg),
+        }
+    }
+
+    /// [See fred_rs::release::related_tags](../release/related_tags/index.html)
+    /// 
+    /// # Arguments
+    /// `release_id` - The id for a release [[Link]](https://research.stlouisfed.org/docs/api/fred/release_related_tags.html#release_id)
+    pub async fn release_related_tags(
+        &self,
+        release_id: usize,
+        builder: release::related_tags::Builder
+    ) -> Result<tags::Response, FredError> {
+        match endpoint::release_related_tags(&self.url_base, &self.api_key, release_id, builder) {
+            Ok(url) => self.fetch(url.as_str()).await,
+            Err(msg) => Err(msg),
+        }
+    }
+
+    /// [See fred_rs::release::tables](../release/tables/index.html)
+    /// 
+    /// # Arguments
+    /// `release_id` - The id for a release [[Link]](https://research.stlouisfed.org/docs/api/fred/release_tables.html#release_id)
+    pub async fn release_tables(
+        &self,
+        release_id: usize,
+        builder: Option<release::tables::Builder>
+    ) -> Result<release::tables::Response, FredError> {
+        match endpoint::release_tables(&self.url_base, &self.api_key, release_id, builder) {
+            Ok(url) => self.fetch(url.as_str()).await,
+            Err(msg) => Err(msg),
+        }
+    }
+
+    // ----------------------------------------------------------------------
+    // Maps
+
+    /// [See fred_rs::maps::series_data](../maps/series_data/index.html)
+    /// 
+    /// The request is made to the maps (GeoFRED) API, see [fred_rs::maps](../maps/index.html).
+    /// 
+    /// # Arguments
+    /// `series_id` - The id for a series in a regional series group [[Link]](https://research.stlouisfed.org/docs/api/geofred/series_data.html#series_id)
+    pub async fn maps_series_data(
+        &self,
+        series_id: &str,
+        builder: Option<maps::series_data::Builder>
+    ) -> Result<maps::Response, FredError> {
+        match endpoint::maps_series_data(&self.url_base, &self.api_key, series_id, builder) {
+            Ok(url) => self.fetch(url.as_str()).await,
+            Err(msg) => Err(msg),
+        }
+    }
+
+    /// [See fred_rs::maps::regional_data](../maps/regional_data/index.html)
+    /// 
+    /// The request is made to the maps (GeoFRED) API, see [fred_rs::maps](../maps/index.html).
+    pub async fn maps_regional_data(
+        &self,
+        builder: maps::regional_data::Builder
+    ) -> Result<maps::Response, FredError> {
+        match endpoint::maps_regional_data(&self.url_base, &self.api_key, builder) {
+            Ok(url) => self.fetch(url.as_str()).await,
+            Err(msg) => Err(msg),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn async_client_series_observation() {
+        let mut rt = match tokio::runtime::Runtime::new() {
+            Ok(rt) => rt,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+
+        rt.block_on(async {
+            let c = match AsyncFredClient::new().await {
+                Ok(c) => c,
+                Err(msg) => {
+                    println!("{}", msg);
+                    assert_eq!(2, 1);
+                    return
+                },
+            };
+
+            let mut builder = series::observation::Builder::new();
+            builder
+                .observation_start("2000-01-01")
+                .limit(5);
+
+            match c.series_observation("GNPCA", Some(builder)).await {
+                Ok(resp) => {
+                    assert_eq!(resp.observations.len(), 5);
+                    assert_eq!(resp.observations[0].date, "2000-01-01");
+                },
+                Err(msg) => {
+                    println!("{}", msg);
+                    assert_eq!(2, 1);
+                },
+            }
+        });
+    }
+
+    #[test]
+    fn async_client_category() {
+        let mut rt = match tokio::runtime::Runtime::new() {
+            Ok(rt) => rt,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+
+        rt.block_on(async {
+            let c = match AsyncFredClient::new().await {
+                Ok(c) => c,
+                Err(msg) => {
+                    println!("{}", msg);
+                    assert_eq!(2, 1);
+                    return
+                },
+            };
+
+            match c.category(125).await {
+                Ok(resp) => assert_eq!(resp.categories[0].id, 125),
+                Err(msg) => {
+                    println!("{}", msg);
+                    assert_eq!(2, 1);
+                },
+            }
+        });
+    }
+
+    #[test]
+    fn async_client_category_overview() {
+        let mut rt = match tokio::runtime::Runtime::new() {
+            Ok(rt) => rt,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+
+        rt.block_on(async {
+            let c = match AsyncFredClient::new().await {
+                Ok(c) => c,
+                Err(msg) => {
+                    println!("{}", msg);
+                    assert_eq!(2, 1);
+                    return
+                },
+            };
+
+            match c.category_overview(125).await {
+                Ok(overview) => {
+                    assert_eq!(overview.category.id, 125);
+                    for child in overview.children.iter() {
+                        assert_eq!(child.parent_id, 125);
+                    }
+                },
+                Err(msg) => {
+                    println!("{}", msg);
+                    assert_eq!(2, 1);
+                },
+            }
+        });
+    }
+
+    #[test]
+    fn async_client_category_overview_offline() {
+        let mut rt = match tokio::runtime::Runtime::new() {
+            Ok(rt) => rt,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+
+        let mut c = match AsyncFredClient::new_offline() {
+            Ok(c) => c,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+        c.with_key("");
+
+        match rt.block_on(c.category_overview(125)) {
+            Err(FredError::MissingApiKey) => (),
+            _ => assert_eq!(2, 1),
+        }
+    }
+
+    #[test]
+    fn async_client_series_observations_multi() {
+        let mut rt = match tokio::runtime::Runtime::new() {
+            Ok(rt) => rt,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+
+        let mut c = match AsyncFredClient::new_offline() {
+            Ok(c) => c,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+        c.with_key("");
+        c.with_concurrency(2);
+
+        let results = rt.block_on(c.series_observations_multi(&["GNPCA", "UNRATE", "CPIAUCSL"], None));
+        let ids: Vec<&str> = results.iter().map(|(id, _)| id.as_str()).collect();
+        assert_eq!(ids, vec!["GNPCA", "UNRATE", "CPIAUCSL"]);
+        for (_, result) in results {
+            match result {
+                Err(FredError::MissingApiKey) => (),
+                _ => assert_eq!(2, 1),
+            }
+        }
+    }
+}
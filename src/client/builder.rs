@@ -0,0 +1,191 @@
+//! Configuration of a [FredClient](../struct.FredClient.html) before it is created
+
+use reqwest::blocking::Client;
+
+use std::time::Duration;
+use std::env;
+
+use crate::error::FredError;
+use crate::format::Json;
+
+use super::{
+    base_url,
+    FredClient,
+    RateLimiter,
+    DEFAULT_TIMEOUT,
+    FRED_API_KEY,
+    FRED_BASE_URL,
+};
+
+#[derive(Clone, Debug)]
+/// Builds a [FredClient](struct.FredClient.html) with settings other than the defaults
+/// 
+/// Unlike [FredClient::new](struct.FredClient.html#method.new), [build](#method.build) makes no request so the client can be created offline.
+/// 
+/// ```
+/// use fred_rs::client::FredClientBuilder;
+/// use std::time::Duration;
+/// 
+/// let client = match FredClientBuilder::new()
+///     .api_key("abcdefghijklmnopqrstuvwxyz123456")
+///     .timeout(Duration::from_secs(10))
+///     .retries(3)
+///     .build() {
+///     Ok(c) => c,
+///     Err(msg) => {
+///         println!("{}", msg);
+///         return
+///     },
+/// };
+/// ```
+pub struct FredClientBuilder {
+    api_key: Option<String>,
+    timeout: Duration,
+    base_url: String,
+    user_agent: Option<String>,
+    retries: u32,
+    rate_limit: u32,
+}
+
+impl FredClientBuilder {
+    /// Creates a builder with the default settings
+    /// 
+    /// The defaults are those of [FredClient::new](struct.FredClient.html#method.new): the API key from the FRED_API_KEY environment variable, a 30 second timeout, the public FRED API, no retries and no rate limit.
+    pub fn new() -> FredClientBuilder {
+        FredClientBuilder {
+            api_key: None,
+            timeout: DEFAULT_TIMEOUT,
+            base_url: String::from(FRED_BASE_URL),
+            user_agent: None,
+            retries: 0,
+            rate_limit: 0,
+        }
+    }
+
+    /// Sets the FRED API key, instead of reading it from the FRED_API_KEY environment variable
+    /// 
+    /// # Arguments
+    /// * `key` - The [API key](https://research.stlouisfed.org/docs/api/api_key.html) generated to access FRED
+    pub fn api_key(&mut self, key: &str) -> &mut FredClientBuilder {
+        self.api_key = Some(String::from(key));
+        self
+    }
+
+    /// Sets the timeout for each request
+    /// 
+    /// # Arguments
+    /// * `timeout` - How long to wait for a response before giving up
+    pub fn timeout(&mut self, timeout: Duration) -> &mut FredClientBuilder {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets the URL every request is made relative to, see [FredClient::with_base_url](struct.FredClient.html#method.with_base_url)
+    /// 
+    /// # Arguments
+    /// * `url` - The base URL, e.g. http://127.0.0.1:8080/fred/
+    pub fn base_url(&mut self, url: &str) -> &mut FredClientBuilder {
+        self.base_url = base_url(url);
+        self
+    }
+
+    /// Sets the User-Agent header sent with each request
+    /// 
+    /// # Arguments
+    /// * `agent` - The value of the header
+    pub fn user_agent(&mut self, agent: &str) -> &mut FredClientBuilder {
+        self.user_agent = Some(String::from(agent));
+        self
+    }
+
+    /// Sets how many times a request is retried after a transient failure, see [FredClient::with_retries](struct.FredClient.html#method.with_retries)
+    /// 
+    /// # Arguments
+    /// * `max` - The maximum number of retries for each request
+    pub fn retries(&mut self, max: u32) -> &mut FredClientBuilder {
+        self.retries = max;
+        self
+    }
+
+    /// Limits how many requests the client makes per minute, see [FredClient::with_rate_limit](struct.FredClient.html#method.with_rate_limit)
+    /// 
+    /// # Arguments
+    /// * `per_minute` - The maximum number of requests to make in any minute, or 0 for no limit
+    pub fn rate_limit(&mut self, per_minute: u32) -> &mut FredClientBuilder {
+        self.rate_limit = per_minute;
+        self
+    }
+
+    /// Creates the client
+    /// 
+    /// Returns Err if the underlying HTTP client cannot be created.
+    pub fn build(&self) -> Result<FredClient, FredError> {
+        let mut client = Client::builder().timeout(self.timeout);
+        match &self.user_agent {
+            Some(agent) => client = client.user_agent(agent.as_str()),
+            None => (),
+        }
+        let client = match client.build() {
+            Ok(c) => c,
+            Err(e) => return Err(FredError::Network(e)),
+        };
+
+        let api_key = match &self.api_key {
+            Some(key) => key.clone(),
+            None => match env::var(FRED_API_KEY) {
+                Ok(val) => val,
+                Err(_) => String::from(""),
+            },
+        };
+
+        Ok(FredClient {
+            client,
+            url_base: self.base_url.clone(),
+            api_key,
+            timeout: self.timeout,
+            retries: self.retries,
+            limiter: match self.rate_limit {
+                0 => None,
+                n => Some(RateLimiter::new(n)),
+            },
+            format: Json,
+        })
+    }
+}
+
+impl Default for FredClientBuilder {
+    fn default() -> FredClientBuilder {
+        FredClientBuilder::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fred_client_builder() {
+        let c = match FredClientBuilder::new()
+            .api_key("abcdefghijklmnopqrstuvwxyz123456")
+            .timeout(Duration::from_secs(5))
+            .base_url("http://127.0.0.1:8080/fred")
+            .user_agent("fred-rs tests")
+            .retries(2)
+            .rate_limit(60)
+            .build() {
+            Ok(c) => c,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+
+        let config = c.config();
+        assert_eq!(config.url_base, "http://127.0.0.1:8080/fred/");
+        assert_eq!(config.timeout_secs, 5);
+        assert_eq!(config.retries, 2);
+        assert_eq!(config.rate_limit, Some(60));
+        assert_eq!(c.api_key, "abcdefghijklmnopqrstuvwxyz123456");
+    }
+}
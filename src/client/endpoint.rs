@@ -0,0 +1,805 @@
+//! Request URLs for each FRED endpoint
+//! 
+//! Shared by the blocking and async clients so both make identical requests.  The file_type argument is appended when the request is made.
+
+use crate::*;
+use crate::encode::encode_query_value;
+use crate::error::FredError;
+
+use super::without_paging;
+
+const OBSERVATION_PAGE_LIMIT: usize = 1000000;
+
+/// Returns the request URL for fred/series
+pub(super) fn series(
+    url_base: &str,
+    api_key: &str,
+    series_id: &str,
+    builder: Option<series::Builder>
+) -> Result<String, FredError> {
+    if series_id.trim().is_empty() {
+        return Err(FredError::InvalidArgument(String::from(error::EMPTY_SERIES_ID_ERROR_TEXT)));
+    }
+
+    let mut url: String = format!(
+        "{}series?series_id={}&api_key={}",
+        url_base,
+        encode_query_value(series_id),
+        api_key
+    );
+
+    match builder {
+        Some(b) => url.push_str(b.build().as_str()),
+        None => (),
+    }
+
+    Ok(url)
+}
+
+/// Returns the request URL for fred/series/categories
+pub(super) fn series_categories(
+    url_base: &str,
+    api_key: &str,
+    series_id: &str,
+    builder: Option<series::categories::Builder>
+) -> Result<String, FredError> {
+    let mut url: String = format!(
+        "{}series/categories?series_id={}&api_key={}",
+        url_base,
+        encode_query_value(series_id),
+        api_key
+    );
+
+    match builder {
+        Some(b) => url.push_str(b.build().as_str()),
+        None => (),
+    }
+
+    Ok(url)
+}
+
+/// Returns the request URL for fred/series/observations
+/// 
+/// When the builder requests every observation its limit and offset are dropped so pages can be requested with [observation_page](fn.observation_page.html).
+pub(super) fn series_observation(
+    url_base: &str,
+    api_key: &str,
+    series_id: &str,
+    builder: Option<series::observation::Builder>
+) -> Result<String, FredError> {
+    let mut url: String = format!(
+        "{}series/observations?series_id={}&api_key={}",
+        url_base,
+        encode_query_value(series_id),
+        api_key
+    );
+
+    match builder {
+        Some(b) => {
+            let all = b.is_all();
+            match b.build() {
+                Ok(o) if all => url.push_str(without_paging(o.as_str()).as_str()),
+                Ok(o) => url.push_str(o.as_str()),
+                Err(msg) => return Err(FredError::InvalidArgument(msg)),
+            }
+        },
+        None => (),
+    }
+
+    Ok(url)
+}
+
+/// Returns the URL for the page of observations starting at `offset`
+pub(super) fn observation_page(url: &str, offset: usize) -> String {
+    format!("{}&limit={}&offset={}", url, OBSERVATION_PAGE_LIMIT, offset)
+}
+
+/// Returns the request URL for fred/series/release
+pub(super) fn series_release(
+    url_base: &str,
+    api_key: &str,
+    series_id: &str,
+    builder: Option<series::release::Builder>
+) -> Result<String, FredError> {
+    let mut url: String = format!(
+        "{}series/release?series_id={}&api_key={}",
+        url_base,
+        encode_query_value(series_id),
+        api_key
+    );
+
+    match builder {
+        Some(b) => url.push_str(b.build().as_str()),
+        None => (),
+    }
+
+    Ok(url)
+}
+
+/// Returns the request URL for fred/series/tags
+pub(super) fn series_tags(
+    url_base: &str,
+    api_key: &str,
+    series_id: &str,
+    builder: Option<series::tags::Builder>
+) -> Result<String, FredError> {
+    let mut url: String = format!(
+        "{}series/tags?series_id={}&api_key={}",
+        url_base,
+        encode_query_value(series_id),
+        api_key
+    );
+
+    match builder {
+        Some(b) => url.push_str(b.build().as_str()),
+        None => (),
+    }
+
+    Ok(url)
+}
+
+/// Returns the request URL for fred/series/updates
+pub(super) fn series_updates(
+    url_base: &str,
+    api_key: &str,
+    builder: Option<series::updates::Builder>
+) -> Result<String, FredError> {
+    let mut url: String = format!(
+        "{}series/updates?api_key={}",
+        url_base,
+        api_key
+    );
+
+    match builder {
+        Some(b) => url.push_str(b.build().as_str()),
+        None => (),
+    }
+
+    Ok(url)
+}
+
+/// Returns the request URL for fred/series/vintagedates
+pub(super) fn series_vintagedates(
+    url_base: &str,
+    api_key: &str,
+    series_id: &str,
+    builder: Option<series::vintagedates::Builder>
+) -> Result<String, FredError> {
+    let mut url: String = format!(
+        "{}series/vintagedates?series_id={}&api_key={}",
+        url_base,
+        encode_query_value(series_id),
+        api_key
+    );
+
+    match builder {
+        Some(b) => url.push_str(b.build().as_str()),
+        None => (),
+    }
+
+    Ok(url)
+}
+
+/// Returns the request URL for fred/series/search
+pub(super) fn series_search(
+    url_base: &str,
+    api_key: &str,
+    search_text: &str,
+    builder: Option<series::search::Builder>
+) -> Result<String, FredError> {
+    let search_text = encode_query_value(search_text);
+
+    let mut url: String = format!(
+        "{}series/search?search_text={}&api_key={}",
+        url_base,
+        search_text,
+        api_key
+    );
+
+    match builder {
+        Some(b) => url.push_str(b.build().as_str()),
+        None => (),
+    }
+
+    Ok(url)
+}
+
+/// Returns the request URL for fred/series/search/tags
+pub(super) fn series_search_tags(
+    url_base: &str,
+    api_key: &str,
+    series_search_text: &str,
+    builder: Option<series::search::tags::Builder>
+) -> Result<String, FredError> {
+    let search_text = encode_query_value(series_search_text);
+
+    let mut url: String = format!(
+        "{}series/search/tags?series_search_text={}&api_key={}",
+        url_base,
+        search_text,
+        api_key
+    );
+
+    match builder {
+        Some(b) => url.push_str(b.build().as_str()),
+        None => (),
+    }
+
+    Ok(url)
+}
+
+/// Returns the request URL for fred/series/search/related_tags
+pub(super) fn series_search_related_tags(
+    url_base: &str,
+    api_key: &str,
+    series_search_text: &str,
+    builder: series::search::related_tags::Builder
+) -> Result<String, FredError> {
+    let search_text = encode_query_value(series_search_text);
+
+    let mut url: String = format!(
+        "{}series/search/related_tags?series_search_text={}&api_key={}",
+        url_base,
+        search_text,
+        api_key
+    );
+
+    match builder.build() {
+        Ok(s) => url.push_str(s.as_str()),
+        Err(msg) => return Err(FredError::InvalidArgument(msg)),
+    }
+
+    Ok(url)
+}
+
+/// Returns the request URL for fred/tags
+pub(super) fn tags(
+    url_base: &str,
+    api_key: &str,
+    builder: Option<tags::Builder>
+) -> Result<String, FredError> {
+    let mut url: String = format!(
+        "{}tags?api_key={}",
+        url_base,
+        api_key
+    );
+
+    match builder {
+        Some(b) => url.push_str(b.build().as_str()),
+        None => (),
+    }
+
+    Ok(url)
+}
+
+/// Returns the request URL for fred/tags/series
+pub(super) fn tags_series(
+    url_base: &str,
+    api_key: &str,
+    builder: tags::series::Builder
+) -> Result<String, FredError> {
+    let mut url: String = format!(
+        "{}tags/series?api_key={}",
+        url_base,
+        api_key
+    );
+
+    match builder.build() {
+        Ok(opt) => url.push_str(opt.as_str()),
+        Err(msg) => return Err(FredError::InvalidArgument(msg)),
+    }
+
+    Ok(url)
+}
+
+/// Returns the request URL for fred/related_tags
+pub(super) fn related_tags(
+    url_base: &str,
+    api_key: &str,
+    builder: related_tags::Builder
+) -> Result<String, FredError> {
+    let mut url: String = format!(
+        "{}related_tags?api_key={}",
+        url_base,
+        api_key
+    );
+
+    match builder.build() {
+        Ok(opt) => url.push_str(opt.as_str()),
+        Err(msg) => return Err(FredError::InvalidArgument(msg)),
+    }
+
+    Ok(url)
+}
+
+/// Returns the request URL for fred/sources
+pub(super) fn sources(
+    url_base: &str,
+    api_key: &str,
+    builder: Option<sources::Builder>
+) -> Result<String, FredError> {
+    let mut url: String = format!(
+        "{}sources?api_key={}",
+        url_base,
+        api_key
+    );
+
+    match builder {
+        Some(b) => url.push_str(b.build().as_str()),
+        None => (),
+    }
+
+    Ok(url)
+}
+
+/// Returns the request URL for fred/source
+pub(super) fn source(
+    url_base: &str,
+    api_key: &str,
+    source_id: usize,
+    builder: Option<source::Builder>
+) -> Result<String, FredError> {
+    let mut url: String = format!(
+        "{}source?source_id={}&api_key={}",
+        url_base,
+        source_id,
+        api_key
+    );
+
+    match builder {
+        Some(b) => url.push_str(b.build().as_str()),
+        None => (),
+    }
+
+    Ok(url)
+}
+
+/// Returns the request URL for fred/source/releases
+pub(super) fn source_releases(
+    url_base: &str,
+    api_key: &str,
+    source_id: usize,
+    builder: Option<source::releases::Builder>
+) -> Result<String, FredError> {
+    let mut url: String = format!(
+        "{}source/releases?source_id={}&api_key={}",
+        url_base,
+        source_id,
+        api_key
+    );
+
+    match builder {
+        Some(b) => url.push_str(b.build().as_str()),
+        None => (),
+    }
+
+    Ok(url)
+}
+
+/// Returns the request URL for fred/category
+pub(super) fn category(
+    url_base: &str,
+    api_key: &str,
+    category_id: usize
+) -> Result<String, FredError> {
+    let url: String = format!(
+        "{}category?category_id={}&api_key={}",
+        url_base,
+        category_id,
+        api_key
+    );
+
+    Ok(url)
+}
+
+/// Returns the request URL for fred/category/children
+pub(super) fn category_children(
+    url_base: &str,
+    api_key: &str,
+    category_id: usize,
+    builder: Option<category::children::Builder>,
+) -> Result<String, FredError> {
+    let mut url: String = format!(
+        "{}category/children?category_id={}&api_key={}",
+        url_base,
+        category_id,
+        api_key
+    );
+
+    match builder {
+        Some(b) => url.push_str(b.build().as_str()),
+        None => (),
+    }
+
+    Ok(url)
+}
+
+/// Returns the request URL for fred/category/related
+pub(super) fn category_related(
+    url_base: &str,
+    api_key: &str,
+    category_id: usize,
+    builder: Option<category::related::Builder>,
+) -> Result<String, FredError> {
+    let mut url: String = format!(
+        "{}category/related?category_id={}&api_key={}",
+        url_base,
+        category_id,
+        api_key
+    );
+
+    match builder {
+        Some(b) => url.push_str(b.build().as_str()),
+        None => (),
+    }
+
+    Ok(url)
+}
+
+/// Returns the request URL for fred/category/series
+pub(super) fn category_series(
+    url_base: &str,
+    api_key: &str,
+    category_id: usize,
+    builder: Option<category::series::Builder>
+) -> Result<String, FredError> {
+    let mut url: String = format!(
+        "{}category/series?category_id={}&api_key={}",
+        url_base,
+        category_id,
+        api_key
+    );
+
+    match builder {
+        Some(b) => url.push_str(b.build().as_str()),
+        None => (),
+    }
+
+    Ok(url)
+}
+
+/// Returns the request URL for fred/category/tags
+pub(super) fn category_tags(
+    url_base: &str,
+    api_key: &str,
+    category_id: usize,
+    builder: Option<category::tags::Builder>
+) -> Result<String, FredError> {
+    let mut url: String = format!(
+        "{}category/tags?category_id={}&api_key={}",
+        url_base,
+        category_id,
+        api_key
+    );
+
+    match builder {
+        Some(b) => url.push_str(b.build().as_str()),
+        None => (),
+    }
+
+    Ok(url)
+}
+
+/// Returns the request URL for fred/category/related_tags
+pub(super) fn category_related_tags(
+    url_base: &str,
+    api_key: &str,
+    category_id: usize,
+    builder: category::related_tags::Builder
+) -> Result<String, FredError> {
+    let mut url: String = format!(
+        "{}category/related_tags?category_id={}&api_key={}",
+        url_base,
+        category_id,
+        api_key
+    );
+
+    match builder.build() {
+        Ok(o) => url.push_str(o.as_str()),
+        Err(msg) => return Err(FredError::InvalidArgument(msg)),
+    }
+
+    Ok(url)
+}
+
+/// Returns the request URL for fred/releases
+pub(super) fn releases(
+    url_base: &str,
+    api_key: &str,
+    builder: Option<releases::Builder>
+) -> Result<String, FredError> {
+    let mut url: String = format!(
+        "{}releases?api_key={}",
+        url_base,
+        api_key
+    );
+
+    match builder {
+        Some(b) => url.push_str(b.build().as_str()),
+        None => (),
+    }
+
+    Ok(url)
+}
+
+/// Returns the request URL for fred/releases/dates
+pub(super) fn releases_dates(
+    url_base: &str,
+    api_key: &str,
+    builder: Option<releases::dates::Builder>
+) -> Result<String, FredError> {
+    let mut url: String = format!(
+        "{}releases/dates?api_key={}",
+        url_base,
+        api_key
+    );
+
+    match builder {
+        Some(b) => url.push_str(b.build().as_str()),
+        None => (),
+    }
+
+    Ok(url)
+}
+
+/// Returns the request URL for fred/release
+pub(super) fn release(
+    url_base: &str,
+    api_key: &str,
+    release_id: usize,
+    builder: Option<release::Builder>
+) -> Result<String, FredError> {
+    let mut url: String = format!(
+        "{}release?release_id={}&api_key={}",
+        url_base,
+        release_id,
+        api_key
+    );
+
+    match builder {
+        Some(b) => url.push_str(b.build().as_str()),
+        None => (),
+    }
+
+    Ok(url)
+}
+
+/// Returns the request URL for fred/release/series
+pub(super) fn release_series(
+    url_base: &str,
+    api_key: &str,
+    release_id: usize,
+    builder: Option<release::series::Builder>
+) -> Result<String, FredError> {
+    let mut url: String = format!(
+        "{}release/series?release_id={}&api_key={}",
+        url_base,
+        release_id,
+        api_key
+    );
+
+    match builder {
+        Some(b) => url.push_str(b.build().as_str()),
+        None => (),
+    }
+
+    Ok(url)
+}
+
+/// Returns the request URL for fred/release/dates
+pub(super) fn release_dates(
+    url_base: &str,
+    api_key: &str,
+    release_id: usize,
+    builder: Option<release::dates::Builder>
+) -> Result<String, FredError> {
+    let mut url: String = format!(
+        "{}release/dates?release_id={}&api_key={}",
+        url_base,
+        release_id,
+        api_key
+    );
+
+    match builder {
+        Some(b) => url.push_str(b.build().as_str()),
+        None => (),
+    }
+
+    Ok(url)
+}
+
+/// Returns the request URL for fred/release/sources
+pub(super) fn release_sources(
+    url_base: &str,
+    api_key: &str,
+    release_id: usize,
+    builder: Option<release::sources::Builder>
+) -> Result<String, FredError> {
+    let mut url: String = format!(
+        "{}release/sources?release_id={}&api_key={}",
+        url_base,
+        release_id,
+        api_key
+    );
+
+    match builder {
+        Some(b) => url.push_str(b.build().as_str()),
+        None => (),
+    }
+
+    Ok(url)
+}
+
+/// Returns the request URL for fred/release/tags
+pub(super) fn release_tags(
+    url_base: &str,
+    api_key: &str,
+    release_id: usize,
+    builder: Option<release::tags::Builder>
+) -> Result<String, FredError> {
+    let mut url: String = format!(
+        "{}release/tags?release_id={}&api_key={}",
+        url_base,
+        release_id,
+        api_key
+    );
+
+    match builder {
+        Some(b) => url.push_str(b.build().as_str()),
+        None => (),
+    }
+
+    Ok(url)
+}
+
+/// Returns the request URL for fred/release/related_tags
+pub(super) fn release_related_tags(
+    url_base: &str,
+    api_key: &str,
+    release_id: usize,
+    builder: release::related_tags::Builder
+) -> Result<String, FredError> {
+    let mut url: String = format!(
+        "{}release/related_tags?release_id={}&api_key={}",
+        url_base,
+        release_id,
+        api_key
+    );
+
+    match builder.build() {
+        Ok(o) => url.push_str(o.as_str()),
+        Err(msg) => return Err(FredError::InvalidArgument(msg)),
+    }
+
+    Ok(url)
+}
+
+/// Returns the request URL for fred/release/tables
+pub(super) fn release_tables(
+    url_base: &str,
+    api_key: &str,
+    release_id: usize,
+    builder: Option<release::tables::Builder>
+) -> Result<String, FredError> {
+    let mut url: String = format!(
+        "{}release/tables?release_id={}&api_key={}",
+        url_base,
+        release_id,
+        api_key
+    );
+
+    match builder {
+        Some(b) => url.push_str(b.build().as_str()),
+        None => (),
+    }
+
+    Ok(url)
+}
+
+/// Returns the base URL of the maps (GeoFRED) API for a client base URL
+/// 
+/// The maps API is served next to the FRED API, so a trailing fred/ is replaced with geofred/ and any other base has geofred/ appended.
+fn maps_base(url_base: &str) -> String {
+    match url_base.strip_suffix("fred/") {
+        Some(root) => format!("{}geofred/", root),
+        None => format!("{}geofred/", url_base),
+    }
+}
+
+/// Returns the request URL for geofred/series/data
+pub(super) fn maps_series_data(
+    url_base: &str,
+    api_key: &str,
+    series_id: &str,
+    builder: Option<maps::series_data::Builder>
+) -> Result<String, FredError> {
+    if series_id.trim().is_empty() {
+        return Err(FredError::InvalidArgument(String::from(error::EMPTY_SERIES_ID_ERROR_TEXT)));
+    }
+
+    let mut url: String = format!(
+        "{}series/data?series_id={}&api_key={}",
+        maps_base(url_base),
+        encode_query_value(series_id),
+        api_key
+    );
+
+    match builder {
+        Some(b) => url.push_str(b.build().as_str()),
+        None => (),
+    }
+
+    Ok(url)
+}
+
+/// Returns the request URL for geofred/regional/data
+pub(super) fn maps_regional_data(
+    url_base: &str,
+    api_key: &str,
+    builder: maps::regional_data::Builder
+) -> Result<String, FredError> {
+    let mut url: String = format!(
+        "{}regional/data?api_key={}",
+        maps_base(url_base),
+        api_key
+    );
+
+    match builder.build() {
+        Ok(opt) => url.push_str(opt.as_str()),
+        Err(msg) => return Err(FredError::InvalidArgument(msg)),
+    }
+
+    Ok(url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn endpoint_series() {
+        match series("https://api.stlouisfed.org/fred/", "KEY", "GNPCA", None) {
+            Ok(url) => assert_eq!(url, "https://api.stlouisfed.org/fred/series?series_id=GNPCA&api_key=KEY"),
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+            },
+        }
+        assert!(series("https://api.stlouisfed.org/fred/", "KEY", " ", None).is_err());
+    }
+
+    #[test]
+    fn endpoint_series_observation_all() {
+        let mut builder = series::observation::Builder::new();
+        builder
+            .limit(5)
+            .all();
+
+        let url = match series_observation("https://api.stlouisfed.org/fred/", "KEY", "GNPCA", Some(builder)) {
+            Ok(url) => url,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+        assert!(!url.contains("limit=5"));
+        assert_eq!(
+            observation_page(url.as_str(), 10),
+            format!("{}&limit=1000000&offset=10", url)
+        );
+    }
+
+    #[test]
+    fn endpoint_maps_series_data() {
+        match maps_series_data("https://api.stlouisfed.org/fred/", "KEY", "WIPCPI", None) {
+            Ok(url) => assert_eq!(url, "https://api.stlouisfed.org/geofred/series/data?series_id=WIPCPI&api_key=KEY"),
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+            },
+        }
+        assert_eq!(maps_base("http://127.0.0.1:8080/"), "http://127.0.0.1:8080/geofred/");
+        assert!(maps_regional_data("https://api.stlouisfed.org/fred/", "KEY", maps::regional_data::Builder::new()).is_err());
+    }
+}
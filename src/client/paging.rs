@@ -0,0 +1,146 @@
+//! Collection of every page of results for the list endpoints
+//! 
+//! Used by the `_all` helpers of both clients.  The pager only decides which page to request next, so the blocking and async clients share the same paging rules.
+
+use serde::de::DeserializeOwned;
+
+use crate::*;
+use crate::common::Paged;
+
+use super::{Collected, PAGE_LIMIT};
+
+/// A list response whose results can be collected page by page
+pub(super) trait Page: DeserializeOwned + Paged {
+    type Item;
+
+    /// The results on this page
+    fn into_items(self) -> Vec<Self::Item>;
+}
+
+impl Page for series::Response {
+    type Item = series::Series;
+
+    fn into_items(self) -> Vec<series::Series> {
+        self.seriess
+    }
+}
+
+impl Page for tags::Response {
+    type Item = tags::Tag;
+
+    fn into_items(self) -> Vec<tags::Tag> {
+        self.tags
+    }
+}
+
+impl Page for release::Response {
+    type Item = release::Release;
+
+    fn into_items(self) -> Vec<release::Release> {
+        self.releases
+    }
+}
+
+impl Page for source::Response {
+    type Item = source::Source;
+
+    fn into_items(self) -> Vec<source::Source> {
+        self.sources
+    }
+}
+
+/// Tracks the results collected so far and the page to request next
+pub(super) struct Pager<T> {
+    collected: Collected<T>,
+    max_results: Option<usize>,
+    done: bool,
+}
+
+impl<T> Pager<T> {
+    pub(super) fn new(max_results: Option<usize>) -> Pager<T> {
+        Pager {
+            collected: Collected { items: Vec::new(), truncated: false },
+            max_results,
+            done: false,
+        }
+    }
+
+    /// Returns the URL of the next page to request, or None once paging is finished
+    /// 
+    /// The limit and offset are appended to `url` so they replace any set by the builder when the query is normalized.
+    pub(super) fn next_url(&self, url: &str) -> Option<String> {
+        if self.done {
+            return None;
+        }
+        let limit = match self.max_results {
+            Some(max) => std::cmp::min(PAGE_LIMIT, max - self.collected.items.len()),
+            None => PAGE_LIMIT,
+        };
+        if limit == 0 {
+            return None;
+        }
+        Some(format!("{}&limit={}&offset={}", url, limit, self.collected.items.len()))
+    }
+
+    /// Adds the results of a page
+    pub(super) fn add<P: Page<Item = T>>(&mut self, page: P) {
+        let count = page.pagination().count;
+        let items = page.into_items();
+        let returned = items.len();
+        self.collected.items.extend(items);
+
+        if returned == 0 || self.collected.items.len() >= count {
+            self.done = true;
+        } else if self.max_results == Some(self.collected.items.len()) {
+            self.collected.truncated = true;
+            self.done = true;
+        }
+    }
+
+    pub(super) fn finish(self) -> Collected<T> {
+        self.collected
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(count: usize, ids: &[&str]) -> series::Response {
+        let mut resp = series::Response::default();
+        resp.count = Some(count);
+        for id in ids {
+            resp.seriess.push(series::Series {
+                id: String::from(*id),
+                ..series::Series::default()
+            });
+        }
+        resp
+    }
+
+    #[test]
+    fn pager_collects_every_page() {
+        let mut pager = Pager::new(None);
+        assert_eq!(pager.next_url("base?a=1"), Some(String::from("base?a=1&limit=1000&offset=0")));
+
+        pager.add(page(3, &["A", "B"]));
+        assert_eq!(pager.next_url("base?a=1"), Some(String::from("base?a=1&limit=1000&offset=2")));
+
+        pager.add(page(3, &["C"]));
+        assert_eq!(pager.next_url("base?a=1"), None);
+
+        let collected = pager.finish();
+        assert_eq!(collected.items.len(), 3);
+        assert!(!collected.truncated);
+    }
+
+    #[test]
+    fn pager_budget() {
+        let mut pager = Pager::new(Some(2));
+        assert_eq!(pager.next_url("base?a=1"), Some(String::from("base?a=1&limit=2&offset=0")));
+
+        pager.add(page(5, &["A", "B"]));
+        assert_eq!(pager.next_url("base?a=1"), None);
+        assert!(pager.finish().truncated);
+    }
+}
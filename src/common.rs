@@ -0,0 +1,465 @@
+//! Argument types shared by several endpoints
+//! 
+//! Each endpoint module re-exports the types it uses, so `fred_rs::tags::SortOrder` and `fred_rs::series::observation::SortOrder` are both this [SortOrder](enum.SortOrder.html).
+
+use serde::{Deserialize, Serialize};
+
+use crate::encode::encode_query_value;
+use crate::error::{FredError, INVALID_DATE_ERROR_TEXT, REALTIME_PERIOD_ORDER_ERROR_TEXT};
+
+#[cfg(feature = "chrono")]
+use chrono::NaiveDate;
+
+/// Sort order options for the endpoints that accept a sort_order argument
+/// 
+/// [https://research.stlouisfed.org/docs/api/fred/series_observations.html#sort_order](https://research.stlouisfed.org/docs/api/fred/series_observations.html#sort_order)
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq)]
+pub enum SortOrder {
+    /// Results returned in ascending order (default)
+    #[serde(rename = "asc")]
+    Ascending,
+    /// Results returned in descending order
+    #[serde(rename = "desc")]
+    Descending,
+}
+
+impl SortOrder {
+    /// Returns the value FRED expects for the sort_order argument
+    pub fn as_param(&self) -> &'static str {
+        match self {
+            SortOrder::Ascending => "asc",
+            SortOrder::Descending => "desc",
+        }
+    }
+}
+
+/// Seasonal adjustment of a series as FRED names it in series metadata
+/// 
+/// Used to filter series by seasonal adjustment, for example with [category::series::Builder::seasonal_adjustment_filter](../category/series/struct.Builder.html#method.seasonal_adjustment_filter).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SeasonalAdjustment {
+    /// Seasonally Adjusted
+    SA,
+    /// Not Seasonally Adjusted
+    NSA,
+    /// Seasonally Adjusted Annual Rate
+    SAAR,
+    /// Smoothed Seasonally Adjusted
+    SSA,
+}
+
+impl SeasonalAdjustment {
+    /// Returns the seasonal adjustment as FRED names it (e.g. "Not Seasonally Adjusted")
+    pub fn name(&self) -> &'static str {
+        match self {
+            SeasonalAdjustment::SA => "Seasonally Adjusted",
+            SeasonalAdjustment::NSA => "Not Seasonally Adjusted",
+            SeasonalAdjustment::SAAR => "Seasonally Adjusted Annual Rate",
+            SeasonalAdjustment::SSA => "Smoothed Seasonally Adjusted",
+        }
+    }
+}
+
+/// The position of a page of results within all the results matching a request
+/// 
+/// Built from the count, offset and limit FRED returns with each page, see [Paged](trait.Paged.html).
+#[derive(Serialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct Pagination {
+    /// Total number of results matching the request
+    pub count: usize,
+    /// Position of the first result on this page
+    pub offset: usize,
+    /// Maximum number of results on a page
+    pub limit: usize,
+    /// Number of results on this page
+    pub returned: usize,
+}
+
+impl Pagination {
+    /// Returns true if there are results after this page
+    pub fn has_more(&self) -> bool {
+        self.offset + self.returned < self.count
+    }
+
+    /// Returns the offset to request the next page with
+    pub fn next_offset(&self) -> usize {
+        self.offset + self.returned
+    }
+}
+
+/// A response holding one page of a list of results
+/// 
+/// ```
+/// use fred_rs::common::Paged;
+/// use fred_rs::tags::Response;
+/// 
+/// let resp = Response::default();
+/// if resp.has_more() {
+///     println!("next page starts at {}", resp.pagination().next_offset());
+/// }
+/// ```
+pub trait Paged {
+    /// Returns the position of this page within all the results
+    fn pagination(&self) -> Pagination;
+
+    /// Returns true if there are results after this page
+    fn has_more(&self) -> bool {
+        self.pagination().has_more()
+    }
+}
+
+/// Normalizes a date to the YYYY-MM-DD format FRED expects
+/// 
+/// Accepts dates formatted as YYYY-MM-DD, YYYY/MM/DD or MM/DD/YYYY, with or without leading zeros on the month and day.  Returns FredError::InvalidArgument if the input is in none of these formats or is not a real calendar date.
+/// 
+/// The date arguments of every builder are passed through this function.  The client rejects a request with a date argument this function does not accept before sending it.
+/// 
+/// ```
+/// use fred_rs::common::normalize_date;
+/// use fred_rs::error::FredError;
+/// 
+/// assert_eq!(normalize_date("2020/01/31").ok(), Some(String::from("2020-01-31")));
+/// assert_eq!(normalize_date("1/31/2020").ok(), Some(String::from("2020-01-31")));
+/// match normalize_date("2020-02-30") {
+///     Err(FredError::InvalidArgument(msg)) => println!("{}", msg),
+///     _ => assert_eq!(2, 1),
+/// }
+/// ```
+pub fn normalize_date(input: &str) -> Result<String, FredError> {
+    let input = input.trim();
+    let parts: Vec<&str> = if input.contains('-') {
+        input.split('-').collect()
+    } else {
+        input.split('/').collect()
+    };
+    if parts.len() != 3 || parts.iter().any(|p| p.is_empty() || !p.bytes().all(|b| b.is_ascii_digit())) {
+        return Err(FredError::InvalidArgument(String::from(INVALID_DATE_ERROR_TEXT)));
+    }
+
+    let (year, month, day) = if parts[0].len() == 4 && parts[1].len() <= 2 && parts[2].len() <= 2 {
+        (parts[0], parts[1], parts[2])
+    } else if input.contains('/') && parts[2].len() == 4 && parts[0].len() <= 2 && parts[1].len() <= 2 {
+        (parts[2], parts[0], parts[1])
+    } else {
+        return Err(FredError::InvalidArgument(String::from(INVALID_DATE_ERROR_TEXT)));
+    };
+    let (year, month, day): (u32, u32, u32) = match (year.parse(), month.parse(), day.parse()) {
+        (Ok(y), Ok(m), Ok(d)) => (y, m, d),
+        _ => return Err(FredError::InvalidArgument(String::from(INVALID_DATE_ERROR_TEXT))),
+    };
+
+    let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if leap => 29,
+        2 => 28,
+        _ => return Err(FredError::InvalidArgument(String::from(INVALID_DATE_ERROR_TEXT))),
+    };
+    if day < 1 || day > days_in_month {
+        return Err(FredError::InvalidArgument(String::from(INVALID_DATE_ERROR_TEXT)));
+    }
+
+    Ok(format!("{:04}-{:02}-{:02}", year, month, day))
+}
+
+/// Normalizes the dates of a realtime period, returning Err if either is malformed or start_date is after end_date
+/// 
+/// Used by the realtime_period function the realtime_period! macro adds to the builders.
+pub(crate) fn realtime_period(start_date: &str, end_date: &str) -> Result<(String, String), String> {
+    let (start, end) = match (normalize_date(start_date), normalize_date(end_date)) {
+        (Ok(start), Ok(end)) => (start, end),
+        (Err(e), _) | (_, Err(e)) => return Err(e.to_string()),
+    };
+    // YYYY-MM-DD dates order correctly as strings
+    if start > end {
+        return Err(String::from(REALTIME_PERIOD_ORDER_ERROR_TEXT));
+    }
+    Ok((start, end))
+}
+
+/// Adds a realtime_period function to the builder whose impl block it is used in
+/// 
+/// The builder must have realtime_start and realtime_end functions.  Both are called, so any state they track (e.g. the vintage date conflict check of the observation builder) is kept.
+macro_rules! realtime_period {
+    () => {
+        /// Adds realtime_start and realtime_end arguments to the builder together
+        /// 
+        /// Returns Err, without adding either argument, if a date is malformed or start_date is after end_date.
+        /// 
+        /// # Arguments
+        /// * `start_date` - date formatted as YYYY-MM-DD
+        /// * `end_date` - date formatted as YYYY-MM-DD
+        pub fn realtime_period(&mut self, start_date: &str, end_date: &str) -> Result<&mut Builder, String> {
+            match crate::common::realtime_period(start_date, end_date) {
+                Ok((start, end)) => Ok(self.realtime_start(&start).realtime_end(&end)),
+                Err(msg) => Err(msg),
+            }
+        }
+    };
+}
+
+/// Returns a date argument for a request, normalized if possible and otherwise only encoded for the URL
+/// 
+/// Builder setters cannot return an error, so input normalize_date rejects is kept in the arguments and reported by [check_date_args](fn.check_date_args.html) before the request is sent.
+pub(crate) fn date_arg(input: &str) -> String {
+    match normalize_date(input) {
+        Ok(date) => date,
+        Err(_) => encode_query_value(input),
+    }
+}
+
+/// Arguments whose values are set with date_arg
+const DATE_ARGS: [&str; 8] = [
+    "realtime_start",
+    "realtime_end",
+    "observation_start",
+    "observation_end",
+    "observation_date",
+    "start_date",
+    "date",
+    "vintage_dates",
+];
+
+/// Returns Err if a date argument of a request URL or builder query string is not a valid YYYY-MM-DD date
+/// 
+/// vintage_dates is checked as a comma separated list of dates.
+pub(crate) fn check_date_args(query: &str) -> Result<(), FredError> {
+    for arg in query.split('&') {
+        let (name, value) = match arg.find('=') {
+            Some(i) => (&arg[..i], &arg[i + 1..]),
+            None => continue,
+        };
+        if !DATE_ARGS.contains(&name) {
+            continue;
+        }
+        for date in value.split(',') {
+            match normalize_date(date) {
+                Ok(ref normalized) if normalized == date => (),
+                _ => return Err(FredError::InvalidArgument(format!(
+                    "Invalid {} argument \"{}\".  {}",
+                    name,
+                    value,
+                    INVALID_DATE_ERROR_TEXT
+                ))),
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Parses the realtime_start and realtime_end of a response into dates
+/// 
+/// FRED uses 9999-12-31 as the realtime_end of data that is still current.  It is a valid date and is returned as is, so it compares after every real date.
+#[cfg(feature = "chrono")]
+pub(crate) fn realtime_range(start: &str, end: &str) -> Result<(NaiveDate, NaiveDate), String> {
+    let parse = |date: &str| match NaiveDate::parse_from_str(date, "%Y-%m-%d") {
+        Ok(d) => Ok(d),
+        Err(_) => Err(format!("Invalid realtime date {}", date)),
+    };
+    match (parse(start), parse(end)) {
+        (Ok(start), Ok(end)) => Ok((start, end)),
+        (Err(msg), _) | (_, Err(msg)) => Err(msg),
+    }
+}
+
+/// Converts a date formatted as YYYY-MM-DD to the number of days since 1970-01-01, as used by Arrow's Date32 and for comparing the spacing of dates
+pub(crate) fn days_since_epoch(date: &str) -> Option<i32> {
+    let bytes = date.as_bytes();
+    if bytes.len() != 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+        return None;
+    }
+    let year: i64 = date[0..4].parse().ok()?;
+    let month: i64 = date[5..7].parse().ok()?;
+    let day: i64 = date[8..10].parse().ok()?;
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+
+    // proleptic Gregorian calendar with years starting in March so the leap day falls at the end
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    Some((era * 146097 + day_of_era - 719468) as i32)
+}
+
+/// Converts a FRED timestamp (e.g. 2013-07-31 09:26:16-05) to the number of seconds since 1970-01-01 00:00:00 UTC
+/// 
+/// The UTC offset may be given as hours (-05) or hours and minutes (-05:00) and the seconds may be left off the time.  A date with no time (YYYY-MM-DD) is taken as midnight UTC.  Returns None if the input is in none of these formats.
+pub(crate) fn timestamp_seconds(timestamp: &str) -> Option<i64> {
+    let timestamp = timestamp.trim();
+    if timestamp.len() < 10 || !timestamp.is_char_boundary(10) {
+        return None;
+    }
+    let days = i64::from(days_since_epoch(&timestamp[..10])?);
+    let rest = &timestamp[10..];
+    if rest.is_empty() {
+        return Some(days * 86400);
+    }
+    if !rest.starts_with(' ') && !rest.starts_with('T') {
+        return None;
+    }
+
+    let rest = &rest[1..];
+    let (time, offset) = match rest.find(&['+', '-'][..]) {
+        Some(i) => (&rest[..i], &rest[i..]),
+        None => (rest, ""),
+    };
+
+    let mut seconds = 0;
+    let fields: Vec<&str> = time.split(':').collect();
+    if fields.len() < 2 || fields.len() > 3 {
+        return None;
+    }
+    for (field, scale) in fields.iter().zip([3600, 60, 1].iter()) {
+        if field.len() != 2 || !field.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        seconds += field.parse::<i64>().ok()? * scale;
+    }
+
+    let offset_seconds = if offset.is_empty() {
+        0
+    } else {
+        let sign = if offset.starts_with('-') { -1 } else { 1 };
+        let fields: Vec<&str> = offset[1..].split(':').collect();
+        let (hours, minutes) = match fields.as_slice() {
+            [h] if h.len() == 2 => (*h, "0"),
+            [h, m] if h.len() == 2 && m.len() == 2 => (*h, *m),
+            _ => return None,
+        };
+        match (hours.parse::<i64>(), minutes.parse::<i64>()) {
+            (Ok(h), Ok(m)) => sign * (h * 3600 + m * 60),
+            _ => return None,
+        }
+    };
+
+    Some(days * 86400 + seconds - offset_seconds)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn common_sort_order_as_param() {
+        assert_eq!(SortOrder::Ascending.as_param(), "asc");
+        assert_eq!(SortOrder::Descending.as_param(), "desc");
+    }
+
+    #[test]
+    fn common_sort_order_deserialize() {
+        let order: SortOrder = serde_json::from_str(r#""desc""#).unwrap();
+        assert_eq!(order, SortOrder::Descending);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn common_realtime_range() {
+        match realtime_range("2013-08-14", "9999-12-31") {
+            Ok((start, end)) => {
+                assert_eq!(start, NaiveDate::from_ymd_opt(2013, 8, 14).unwrap());
+                assert_eq!(end, NaiveDate::from_ymd_opt(9999, 12, 31).unwrap());
+            },
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1)
+            },
+        }
+        assert!(realtime_range("2013-08-14", "").is_err());
+    }
+
+    #[test]
+    fn common_days_since_epoch() {
+        assert_eq!(days_since_epoch("1970-01-01"), Some(0));
+        assert_eq!(days_since_epoch("2000-03-01"), Some(11017));
+        assert_eq!(days_since_epoch("1947-01-01"), Some(-8401));
+        assert_eq!(days_since_epoch("2020-13-01"), None);
+        assert_eq!(days_since_epoch("."), None);
+    }
+
+    #[test]
+    fn common_timestamp_seconds() {
+        assert_eq!(timestamp_seconds("1970-01-01"), Some(0));
+        assert_eq!(timestamp_seconds("1970-01-01 00:00:00-05"), Some(5 * 3600));
+        assert_eq!(timestamp_seconds("1970-01-01 00:00:00+05:30"), Some(-(5 * 3600 + 1800)));
+        assert_eq!(timestamp_seconds("1970-01-02 01:02"), Some(86400 + 3600 + 120));
+        // the same instant in central daylight and standard time
+        assert_eq!(timestamp_seconds("2020-11-01 01:30:00-05"), timestamp_seconds("2020-11-01 00:30:00-06"));
+
+        assert_eq!(timestamp_seconds("2020-11-01 1:30:00-05"), None);
+        assert_eq!(timestamp_seconds("2020-11-01 01:30:00-5"), None);
+        assert_eq!(timestamp_seconds("2020-11-01x"), None);
+        assert_eq!(timestamp_seconds(""), None);
+    }
+
+    #[test]
+    fn common_normalize_date() {
+        assert_eq!(normalize_date("2020-01-31").ok(), Some(String::from("2020-01-31")));
+        assert_eq!(normalize_date("2020/1/5").ok(), Some(String::from("2020-01-05")));
+        assert_eq!(normalize_date("12/31/1999").ok(), Some(String::from("1999-12-31")));
+        assert_eq!(normalize_date(" 2020-02-29 ").ok(), Some(String::from("2020-02-29")));
+        assert_eq!(normalize_date("9999-12-31").ok(), Some(String::from("9999-12-31")));
+
+        assert!(normalize_date("2019-02-29").is_err());
+        assert!(normalize_date("2020-13-01").is_err());
+        assert!(normalize_date("12-31-1999").is_err());
+        assert!(normalize_date("2020/01-01").is_err());
+        assert!(normalize_date("20200101").is_err());
+        match normalize_date("") {
+            Err(FredError::InvalidArgument(msg)) => assert_eq!(msg.as_str(), INVALID_DATE_ERROR_TEXT),
+            _ => assert_eq!(2, 1),
+        }
+
+        assert_eq!(date_arg("2020/01/01"), "2020-01-01");
+        assert_eq!(date_arg("yesterday"), "yesterday");
+    }
+
+    #[test]
+    fn common_check_date_args() {
+        assert!(check_date_args("&realtime_start=2020-01-01&realtime_end=9999-12-31&limit=5").is_ok());
+        assert!(check_date_args("https://api.stlouisfed.org/fred/series?series_id=GNPCA&date=2020-01-01").is_ok());
+        assert!(check_date_args("&vintage_dates=2019-01-01,2020-01-01").is_ok());
+        // not a date argument
+        assert!(check_date_args("&search_text=2020/01/01").is_ok());
+
+        let mut builder = crate::series::Builder::new();
+        builder.realtime_start("yesterday");
+        match check_date_args(builder.into_query().as_str()) {
+            Err(FredError::InvalidArgument(msg)) => assert!(msg.starts_with("Invalid realtime_start argument \"yesterday\"")),
+            _ => assert_eq!(2, 1),
+        }
+        assert!(check_date_args("&vintage_dates=2019-01-01,2019-02-30").is_err());
+        assert!(check_date_args("&observation_start=").is_err());
+    }
+
+    #[test]
+    fn common_pagination() {
+        let page = Pagination { count: 25, offset: 10, limit: 10, returned: 10 };
+        assert!(page.has_more());
+        assert_eq!(page.next_offset(), 20);
+
+        let page = Pagination { count: 25, offset: 20, limit: 10, returned: 5 };
+        assert!(!page.has_more());
+
+        assert!(!Pagination::default().has_more());
+    }
+
+    #[test]
+    fn common_realtime_period() {
+        assert_eq!(
+            realtime_period("2000/1/1", "9999-12-31"),
+            Ok((String::from("2000-01-01"), String::from("9999-12-31")))
+        );
+        assert_eq!(
+            realtime_period("2001-01-01", "2000-01-01"),
+            Err(String::from(REALTIME_PERIOD_ORDER_ERROR_TEXT))
+        );
+        assert_eq!(
+            realtime_period("2000-01-01", "2000-02-30"),
+            Err(String::from(INVALID_DATE_ERROR_TEXT))
+        );
+    }
+}
@@ -0,0 +1,42 @@
+//! Percent-encoding of values placed in request URLs
+
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+
+/// Every byte other than the RFC 3986 unreserved characters
+const QUERY_VALUE: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// Percent-encodes `text` for use as a URL query value
+/// 
+/// Every byte other than the RFC 3986 unreserved characters (letters, digits, `-`, `.`, `_` and `~`) is encoded, so characters such as `&`, `+`, `%`, `;` and `#` reach FRED as literal text.  Callers should pass plain, unencoded text.
+/// 
+/// Every value a builder or the client places in a request URL is passed through this function.
+pub(crate) fn encode_query_value(text: &str) -> String {
+    utf8_percent_encode(text, QUERY_VALUE).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_query_value_spaces() {
+        assert_eq!(encode_query_value("monetary service index"), "monetary%20service%20index");
+    }
+
+    #[test]
+    fn encode_query_value_reserved() {
+        assert_eq!(encode_query_value("S&P 500"), "S%26P%20500");
+        assert_eq!(encode_query_value("a+b"), "a%2Bb");
+        assert_eq!(encode_query_value("100%"), "100%25");
+        assert_eq!(encode_query_value("gdp_real-2.0~"), "gdp_real-2.0~");
+    }
+
+    #[test]
+    fn encode_query_value_unicode() {
+        assert_eq!(encode_query_value("é"), "%C3%A9");
+    }
+}
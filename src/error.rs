@@ -1,10 +1,169 @@
+//! Errors returned by the client
+//! 
+//! ```
+//! use fred_rs::client::FredClient;
+//! use fred_rs::error::FredError;
+//! 
+//! let mut c = match FredClient::new() {
+//!     Ok(c) => c,
+//!     Err(msg) => {
+//!         println!("{}", msg);
+//!         return
+//!     },
+//! };
+//! 
+//! match c.series("GNPCA", None) {
+//!     Ok(resp) => println!("{}", resp.seriess[0].title),
+//!     Err(FredError::Api { code, message }) => println!("FRED rejected the request ({}): {}", code, message),
+//!     Err(e) => println!("{}", e),
+//! }
+//! ```
 
 use serde::Deserialize;
 
+use std::fmt;
+
 pub(crate) const TAG_NAME_REQUIRED_ERROR_TEXT: &str = "At least one tag must be specified using the tag_name() function of the related_tags::Builder.";
+pub(crate) const VINTAGE_REALTIME_CONFLICT_ERROR_TEXT: &str = "Vintage dates cannot be combined with realtime_start or realtime_end in the observation::Builder.";
+pub(crate) const REALTIME_PERIOD_ORDER_ERROR_TEXT: &str = "The realtime_start date must not be after the realtime_end date.";
+pub(crate) const OBSERVATION_PERIOD_ORDER_ERROR_TEXT: &str = "The observation_start date must not be after the observation_end date in the observation::Builder.";
+pub(crate) const AGGREGATION_WITHOUT_FREQUENCY_ERROR_TEXT: &str = "An aggregation_method is only used with a frequency in the observation::Builder.";
+pub(crate) const LIMIT_EXCEEDED_ERROR_TEXT: &str = "The requested limit exceeds the maximum number of results this endpoint returns per request.";
+pub(crate) const EMPTY_SERIES_ID_ERROR_TEXT: &str = "A series id must be specified.";
+pub(crate) const REGIONAL_DATA_REQUIRED_ERROR_TEXT: &str = "The series_group, region_type, date, units, frequency and season arguments must all be specified in the maps::regional_data::Builder.";
+pub(crate) const INVALID_DATE_ERROR_TEXT: &str = "Dates must be valid and formatted as YYYY-MM-DD, YYYY/MM/DD or MM/DD/YYYY.";
+pub(crate) const INVALID_TIMESTAMP_ERROR_TEXT: &str = "Timestamps must be formatted as YYYY-MM-DD or YYYY-MM-DD HH:MM:SS with an optional UTC offset (e.g. 2013-07-31 09:26:16-05).";
+pub(crate) const CATEGORY_NOT_FOUND_ERROR_TEXT: &str = "FRED returned no category for the requested category id.";
+pub(crate) const INVALID_UTF8_ERROR_TEXT: &str = "FRED returned a response that is not valid UTF-8 (truncated or corrupted transfer?)";
+pub(crate) const MISSING_API_KEY_ERROR_TEXT: &str = "No FRED API key is set.  Set the FRED_API_KEY environment variable or call with_key() on the client.";
+
+#[derive(Debug)]
+/// An error returned by a client method
+pub enum FredError {
+    /// The request could not be made or the response could not be read
+    Network(reqwest::Error),
+    /// FRED answered the request with an error response
+    Api {
+        /// The HTTP status code FRED reported
+        code: u16,
+        /// The message FRED gave for the error
+        message: String,
+    },
+    /// The response body could not be deserialized
+    Deserialize(serde_json::Error),
+    /// The XML response body could not be deserialized
+    #[cfg(feature = "xml")]
+    XmlDeserialize(serde_xml_rs::Error),
+    /// The client has no API key so no request was made
+    MissingApiKey,
+    /// The request arguments were rejected before a request was made
+    InvalidArgument(String),
+    /// The response body is not valid UTF-8 (e.g. a truncated or corrupted transfer)
+    InvalidUtf8(std::str::Utf8Error),
+    /// FRED returned no results for an item the method requires
+    NotFound(String),
+    /// Writing the results failed
+    Io(std::io::Error),
+    /// Reading or writing the local cache failed
+    #[cfg(feature = "sqlite")]
+    Cache(String),
+}
+
+impl fmt::Display for FredError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FredError::Network(e) => write!(f, "{}", e),
+            FredError::Api { code, message } => write!(f, "ERROR {}: {}", code, message),
+            FredError::Deserialize(e) => write!(f, "{}", e),
+            #[cfg(feature = "xml")]
+            FredError::XmlDeserialize(e) => write!(f, "{}", e),
+            FredError::MissingApiKey => write!(f, "{}", MISSING_API_KEY_ERROR_TEXT),
+            FredError::InvalidArgument(msg) => write!(f, "{}", msg),
+            FredError::InvalidUtf8(e) => write!(f, "{}: {}", INVALID_UTF8_ERROR_TEXT, e),
+            FredError::NotFound(msg) => write!(f, "{}", msg),
+            FredError::Io(e) => write!(f, "{}", e),
+            #[cfg(feature = "sqlite")]
+            FredError::Cache(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl std::error::Error for FredError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FredError::Network(e) => Some(e),
+            FredError::Deserialize(e) => Some(e),
+            #[cfg(feature = "xml")]
+            FredError::XmlDeserialize(e) => Some(e),
+            FredError::InvalidUtf8(e) => Some(e),
+            FredError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for FredError {
+    fn from(e: reqwest::Error) -> FredError {
+        FredError::Network(e)
+    }
+}
+
+impl From<serde_json::Error> for FredError {
+    fn from(e: serde_json::Error) -> FredError {
+        FredError::Deserialize(e)
+    }
+}
+
+impl From<std::str::Utf8Error> for FredError {
+    fn from(e: std::str::Utf8Error) -> FredError {
+        FredError::InvalidUtf8(e)
+    }
+}
+
+impl From<std::io::Error> for FredError {
+    fn from(e: std::io::Error) -> FredError {
+        FredError::Io(e)
+    }
+}
 
 #[derive(Deserialize)]
-pub(crate) struct FredError {
-    pub(crate) error_code: usize,
+/// The body FRED sends in place of a response when a request fails
+/// 
+/// XML error responses carry the same values in the code and message attributes.
+pub(crate) struct ErrorResponse {
+    #[serde(alias = "code")]
+    pub(crate) error_code: u16,
+    #[serde(alias = "message")]
     pub(crate) error_message: String,
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    #[test]
+    fn fred_error_display() {
+        let err = FredError::Api {
+            code: 400,
+            message: String::from("Bad Request.  Variable api_key is not set."),
+        };
+        assert_eq!(err.to_string(), "ERROR 400: Bad Request.  Variable api_key is not set.");
+        assert!(err.source().is_none());
+
+        let err = FredError::from(serde_json::from_str::<ErrorResponse>("{").err().unwrap());
+        match err {
+            FredError::Deserialize(_) => assert!(err.source().is_some()),
+            _ => assert_eq!(2, 1),
+        }
+
+        let truncated = vec![0xE2, 0x82];
+        let err = FredError::from(std::str::from_utf8(&truncated).err().unwrap());
+        match err {
+            FredError::InvalidUtf8(_) => assert!(err.source().is_some()),
+            _ => assert_eq!(2, 1),
+        }
+        assert!(err.to_string().starts_with(INVALID_UTF8_ERROR_TEXT));
+
+        assert_eq!(FredError::MissingApiKey.to_string(), MISSING_API_KEY_ERROR_TEXT);
+    }
+}
@@ -0,0 +1,149 @@
+//! Response formats the client can request from FRED
+//! 
+//! The client sends the format's file_type argument with every request and parses each response body through the format, so supporting another format (or a custom parser) only requires an implementation of [ResponseFormat](trait.ResponseFormat.html).
+//! 
+//! ```
+//! use fred_rs::client::FredClient;
+//! use fred_rs::format::Json;
+//! 
+//! let c = match FredClient::new() {
+//!     Ok(c) => c.with_format(Json),
+//!     Err(msg) => {
+//!         println!("{}", msg);
+//!         return
+//!     },
+//! };
+//! ```
+
+use serde::de::DeserializeOwned;
+
+use crate::error::FredError;
+
+/// A FRED file type and the parser for its response bodies
+pub trait ResponseFormat {
+    /// Returns the value sent as the file_type argument (e.g. `json`)
+    /// 
+    /// Responses whose Content-Type does not mention this value are rejected before parsing.
+    fn file_type(&self) -> &'static str;
+
+    /// Deserializes a response body
+    /// 
+    /// Returns Err if the body cannot be parsed as `T`.
+    fn parse<T: DeserializeOwned>(&self, bytes: &[u8]) -> Result<T, FredError>;
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+/// JSON responses parsed with serde_json
+/// 
+/// This is the format used by [FredClient::new](../client/struct.FredClient.html#method.new).
+pub struct Json;
+
+impl ResponseFormat for Json {
+    fn file_type(&self) -> &'static str {
+        "json"
+    }
+
+    fn parse<T: DeserializeOwned>(&self, bytes: &[u8]) -> Result<T, FredError> {
+        match serde_json::from_slice(bytes) {
+            Ok(val) => Ok(val),
+            Err(e) => Err(FredError::Deserialize(e)),
+        }
+    }
+}
+
+#[cfg(feature = "xml")]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+/// XML responses parsed with serde-xml-rs
+/// 
+/// Requires the `xml` feature.  Lists of results are read from the repeated child elements FRED returns (e.g. each `<observation>` of `<observations>`).  The fred/release/tables endpoint is not supported since its XML response is not shaped like its JSON response.
+pub struct Xml;
+
+#[cfg(feature = "xml")]
+impl ResponseFormat for Xml {
+    fn file_type(&self) -> &'static str {
+        "xml"
+    }
+
+    fn parse<T: DeserializeOwned>(&self, bytes: &[u8]) -> Result<T, FredError> {
+        match serde_xml_rs::from_reader(bytes) {
+            Ok(val) => Ok(val),
+            Err(e) => Err(FredError::XmlDeserialize(e)),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+/// A file type chosen at runtime
+/// 
+/// Used with [FredClient::with_file_type](../client/struct.FredClient.html#method.with_file_type) when the format is only known once the program is running, e.g. from a configuration file.
+pub enum FileType {
+    /// See [Json](struct.Json.html)
+    Json,
+    /// See [Xml](struct.Xml.html)
+    #[cfg(feature = "xml")]
+    Xml,
+}
+
+impl ResponseFormat for FileType {
+    fn file_type(&self) -> &'static str {
+        match self {
+            FileType::Json => Json.file_type(),
+            #[cfg(feature = "xml")]
+            FileType::Xml => Xml.file_type(),
+        }
+    }
+
+    fn parse<T: DeserializeOwned>(&self, bytes: &[u8]) -> Result<T, FredError> {
+        match self {
+            FileType::Json => Json.parse(bytes),
+            #[cfg(feature = "xml")]
+            FileType::Xml => Xml.parse(bytes),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::series::Response;
+
+    #[test]
+    fn json_parse() {
+        let body = br#"{"realtime_start":"2020-01-01","realtime_end":"2020-01-01","seriess":[]}"#;
+        let resp: Response = match Json.parse(body) {
+            Ok(resp) => resp,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+        assert_eq!(resp.realtime_start, "2020-01-01");
+        assert!(resp.seriess.is_empty());
+
+        assert_eq!(Json.file_type(), "json");
+        assert!(Json.parse::<Response>(b"<html></html>").is_err());
+        assert_eq!(FileType::Json.file_type(), "json");
+    }
+
+    #[cfg(feature = "xml")]
+    #[test]
+    fn xml_parse() {
+        let body = br#"<?xml version="1.0" encoding="utf-8" ?>
+<observations realtime_start="2020-04-01" realtime_end="2020-04-01" observation_start="1600-01-01" observation_end="9999-12-31" units="lin" output_type="1" file_type="xml" order_by="observation_date" sort_order="asc" count="2" offset="0" limit="100000">
+  <observation realtime_start="2020-04-01" realtime_end="2020-04-01" date="1929-01-01" value="1120.076"/>
+  <observation realtime_start="2020-04-01" realtime_end="2020-04-01" date="1930-01-01" value="1025.091"/>
+</observations>"#;
+        let resp: crate::series::observation::Response = match FileType::Xml.parse(body) {
+            Ok(resp) => resp,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+        assert_eq!(resp.count, 2);
+        assert_eq!(resp.observations[1].date, "1930-01-01");
+        assert_eq!(Xml.file_type(), "xml");
+    }
+}
@@ -104,6 +104,8 @@
 //! license, shall be dual licensed as above, without any additional terms or 
 //! conditions.
 
+#[macro_use]
+pub mod common;
 pub mod client;
 pub mod category;
 pub mod releases;
@@ -113,5 +115,10 @@ pub mod tags;
 pub mod related_tags;
 pub mod sources;
 pub mod source;
+pub mod maps;
+pub mod format;
+pub mod error;
 
-mod error;
\ No newline at end of file
+mod encode;
+#[cfg(feature = "sqlite")]
+mod cache;
\ No newline at end of file
@@ -0,0 +1,227 @@
+//! Get regional data from the maps (GeoFRED) API
+//! 
+//! [https://research.stlouisfed.org/docs/api/geofred/](https://research.stlouisfed.org/docs/api/geofred/)
+//! 
+//! The maps API is served from a different path than the rest of FRED (geofred/ rather than fred/).  The client derives it from its base URL, so a client pointed at a mock server with [with_base_url](../client/struct.FredClient.html#method.with_base_url) requests the maps endpoints from the same server.
+//! 
+//! Maps responses are only available as JSON.
+//! 
+//! ```
+//! use fred_rs::client::FredClient;
+//! use fred_rs::maps::{series_data::Builder, Response};
+//! 
+//! let mut c = match FredClient::new() {
+//!     Ok(c) => c,
+//!     Err(msg) => {
+//!         println!("{}", msg);
+//!         assert_eq!(2, 1);
+//!         return
+//!     },
+//! };
+//! 
+//! let mut builder = Builder::new();
+//! builder.date("2012-01-01");
+//! 
+//! let resp: Response = match c.maps_series_data("WIPCPI", Some(builder)) {
+//!     Ok(resp) => resp,
+//!     Err(msg) => {
+//!         println!("{}", msg);
+//!         assert_eq!(2, 1);
+//!         return
+//!     },
+//! };
+//! 
+//! for (region, value) in resp.values("2012-01-01") {
+//!     println!("{}: {:?}", region, value);
+//! }
+//! ```
+
+pub mod regional_data;
+pub mod series_data;
+
+// -----------------------------------------------------------------------------
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::{self, Display, Formatter};
+
+use crate::series::observation::parse_value;
+
+#[derive(Deserialize, Serialize, Clone, Debug, Default)]
+/// Response data structure for the geofred/series/data and geofred/regional/data endpoints
+/// 
+/// [https://research.stlouisfed.org/docs/api/geofred/series_data.html](https://research.stlouisfed.org/docs/api/geofred/series_data.html)
+pub struct Response {
+    /// Description of the data and the values themselves
+    pub meta: Meta,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, Default)]
+/// Description of the regional data in a maps response
+pub struct Meta {
+    /// The title of the series or series group
+    pub title: String,
+    /// The type of region the values are for (e.g. "state")
+    pub region: String,
+    /// The seasonal adjustment of the values (e.g. "Not Seasonally Adjusted")
+    pub seasonality: String,
+    /// The units of the values (e.g. "Dollars")
+    pub units: String,
+    /// The frequency of the values (e.g. "Annual")
+    pub frequency: String,
+    /// The values of each region, keyed by observation date (YYYY-MM-DD)
+    #[serde(default)]
+    pub data: BTreeMap<String, Vec<RegionValue>>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, Default)]
+/// The value of a single region on an observation date
+pub struct RegionValue {
+    /// The name of the region (e.g. "Alabama")
+    pub region: String,
+    /// The region code (e.g. the FIPS code "01")
+    pub code: String,
+    /// The value of the region, or None if FRED has no value for it
+    #[serde(default, deserialize_with = "deserialize_value")]
+    pub value: Option<f64>,
+    /// The id of the FRED series the value comes from
+    pub series_id: String,
+}
+
+impl Response {
+    /// Returns the observation dates in the response in ascending order
+    pub fn dates(&self) -> Vec<&str> {
+        self.meta.data.keys().map(|d| d.as_str()).collect()
+    }
+
+    /// Returns the values on `date` keyed by region name
+    /// 
+    /// The map is empty if the response has no data for `date`.
+    /// 
+    /// # Arguments
+    /// * `date` - An observation date formatted as YYYY-MM-DD
+    pub fn values(&self, date: &str) -> BTreeMap<String, Option<f64>> {
+        match self.meta.data.get(date) {
+            Some(items) => items.iter()
+                .map(|item| (item.region.clone(), item.value))
+                .collect(),
+            None => BTreeMap::new(),
+        }
+    }
+
+    /// Returns the values on `date` keyed by region code, see [values](#method.values)
+    /// 
+    /// Codes are what map shapefiles are usually keyed by (e.g. FIPS codes for states and counties).
+    /// 
+    /// # Arguments
+    /// * `date` - An observation date formatted as YYYY-MM-DD
+    pub fn values_by_code(&self, date: &str) -> BTreeMap<String, Option<f64>> {
+        match self.meta.data.get(date) {
+            Some(items) => items.iter()
+                .map(|item| (item.code.clone(), item.value))
+                .collect(),
+            None => BTreeMap::new(),
+        }
+    }
+}
+
+impl Display for Response {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (date, items) in self.meta.data.iter() {
+            for item in items.iter() {
+                match writeln!(f, "{},{},{},{:?}", date, item.code, item.region, item.value) {
+                    Ok(_) => (),
+                    Err(e) => return Err(e),
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Region types available from the maps API
+/// 
+/// [https://research.stlouisfed.org/docs/api/geofred/regional_data.html#region_type](https://research.stlouisfed.org/docs/api/geofred/regional_data.html#region_type)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RegionType {
+    /// Bureau of Economic Analysis regions
+    BEA,
+    /// Metropolitan Statistical Areas
+    MSA,
+    /// Federal Reserve Bank districts
+    FRB,
+    /// New England City and Town Areas
+    NECTA,
+    State,
+    Country,
+    County,
+    CensusRegion,
+    CensusDivision,
+}
+
+impl RegionType {
+    /// Returns the value FRED expects for the region_type argument
+    pub fn as_param(&self) -> &'static str {
+        match self {
+            RegionType::BEA => "bea",
+            RegionType::MSA => "msa",
+            RegionType::FRB => "frb",
+            RegionType::NECTA => "necta",
+            RegionType::State => "state",
+            RegionType::Country => "country",
+            RegionType::County => "county",
+            RegionType::CensusRegion => "censusregion",
+            RegionType::CensusDivision => "censusdivision",
+        }
+    }
+}
+
+/// Deserializes a region value sent as a number, a string or null
+/// 
+/// FRED sends missing values as null or ".".
+fn deserialize_value<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<f64>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Value {
+        Number(f64),
+        Text(String),
+    }
+
+    match Option::<Value>::deserialize(deserializer) {
+        Ok(Some(Value::Number(n))) => Ok(Some(n)),
+        Ok(Some(Value::Text(s))) => Ok(parse_value(&s)),
+        Ok(None) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RESPONSE: &str = r#"{"meta":{"title":"Per Capita Personal Income by State (Dollars)","region":"state","seasonality":"Not Seasonally Adjusted","units":"Dollars","frequency":"Annual","data":{"2012-01-01":[{"region":"Alabama","code":"01","value":35926,"series_id":"ALPCPI"},{"region":"Alaska","code":"02","value":".","series_id":"AKPCPI"},{"region":"Arizona","code":"04","value":"36983","series_id":"AZPCPI"}]}}}"#;
+
+    #[test]
+    fn maps_response_values() {
+        let resp: Response = match serde_json::from_str(RESPONSE) {
+            Ok(resp) => resp,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+
+        assert_eq!(resp.dates(), vec!["2012-01-01"]);
+
+        let values = resp.values("2012-01-01");
+        assert_eq!(values.len(), 3);
+        assert_eq!(values["Alabama"], Some(35926.0));
+        assert_eq!(values["Alaska"], None);
+        assert_eq!(values["Arizona"], Some(36983.0));
+
+        let values = resp.values_by_code("2012-01-01");
+        assert_eq!(values["04"], Some(36983.0));
+
+        assert!(resp.values("2013-01-01").is_empty());
+    }
+}
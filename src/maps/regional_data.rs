@@ -0,0 +1,262 @@
+//! Get the data of every region in a regional series group
+//! 
+//! [https://research.stlouisfed.org/docs/api/geofred/regional_data.html](https://research.stlouisfed.org/docs/api/geofred/regional_data.html)
+//! 
+//! ```
+//! use fred_rs::client::FredClient;
+//! use fred_rs::maps::regional_data::{Builder, Frequency, RegionType, SeasonalAdjustment};
+//! use fred_rs::maps::Response;
+//! 
+//! let mut c = match FredClient::new() {
+//!     Ok(c) => c,
+//!     Err(msg) => {
+//!         println!("{}", msg);
+//!         assert_eq!(2, 1);
+//!         return
+//!     },
+//! };
+//! 
+//! let mut builder = Builder::new();
+//! builder
+//!     .series_group("882")
+//!     .region_type(RegionType::State)
+//!     .date("2013-01-01")
+//!     .units("Dollars")
+//!     .frequency(Frequency::A)
+//!     .season(SeasonalAdjustment::NSA);
+//! 
+//! let resp: Response = match c.maps_regional_data(builder) {
+//!     Ok(resp) => resp,
+//!     Err(msg) => {
+//!         println!("{}", msg);
+//!         assert_eq!(2, 1);
+//!         return
+//!     },
+//! };
+//! 
+//! for (region, value) in resp.values("2013-01-01") {
+//!     println!("{}: {:?}", region, value);
+//! }
+//! ```
+
+pub use crate::common::SeasonalAdjustment;
+pub use crate::series::observation::Frequency;
+pub use super::RegionType;
+use crate::encode::encode_query_value;
+use crate::error::REGIONAL_DATA_REQUIRED_ERROR_TEXT;
+
+#[derive(Clone)]
+pub struct Builder {
+    option_string: String,
+    series_group: Option<String>,
+    region_type: Option<RegionType>,
+    date: Option<String>,
+    units: Option<String>,
+    frequency: Option<&'static str>,
+    season: Option<&'static str>,
+}
+
+impl Builder {
+
+    /// Initializes a new maps::regional_data::Builder that can be used to add commands to an API request
+    /// 
+    /// The series_group, region_type, date, units, frequency and season arguments are required by FRED.  The builder only checks that they are set, not that they are valid.
+    /// 
+    /// ```
+    /// use fred_rs::maps::regional_data::{Builder, RegionType};
+    /// // Create a new builder
+    /// let mut builder = Builder::new();
+    /// // add arguments to the builder
+    /// builder
+    ///     .series_group("882")
+    ///     .region_type(RegionType::State);
+    /// ```
+    pub fn new() -> Builder {
+        Builder {
+            option_string: String::new(),
+            series_group: None,
+            region_type: None,
+            date: None,
+            units: None,
+            frequency: None,
+            season: None,
+        }
+    }
+
+    /// Removes all arguments from the builder so it can be reused
+    pub fn clear(&mut self) -> &mut Builder {
+        *self = Builder::new();
+        self
+    }
+
+    /// Returns the current arguments as a URL formatted string without consuming the builder
+    pub fn peek(&self) -> Result<String, String> {
+        let (series_group, region_type, date, units, frequency, season) = match (
+            &self.series_group,
+            &self.region_type,
+            &self.date,
+            &self.units,
+            &self.frequency,
+            &self.season,
+        ) {
+            (Some(g), Some(r), Some(d), Some(u), Some(f), Some(s)) => (g, r, d, u, f, s),
+            _ => return Err(String::from(REGIONAL_DATA_REQUIRED_ERROR_TEXT)),
+        };
+
+        Ok(format!(
+            "&series_group={}&region_type={}&date={}&units={}&frequency={}&season={}{}",
+            series_group,
+            region_type.as_param(),
+            date,
+            units,
+            frequency,
+            season,
+            self.option_string
+        ))
+    }
+
+    /// Returns the current arguments as a URL formatted string
+    pub(crate) fn build(self) -> Result<String, String> {
+        self.peek()
+    }
+
+    /// Consumes the builder and returns its arguments as a URL formatted string
+    /// 
+    /// This is the query string the client appends to the request URL, which can be logged or checked in tests without sending a request.
+    pub fn into_query(self) -> Result<String, String> {
+        self.build()
+    }
+
+    /// Sets the series_group argument (required)
+    /// 
+    /// # Arguments
+    /// * `group` - The id of a series group, as returned in the series group metadata of a series
+    /// 
+    /// [https://research.stlouisfed.org/docs/api/geofred/regional_data.html#series_group](https://research.stlouisfed.org/docs/api/geofred/regional_data.html#series_group)
+    pub fn series_group(&mut self, group: &str) -> &mut Builder {
+        self.series_group = Some(encode_query_value(group));
+        self
+    }
+
+    /// Sets the region_type argument (required)
+    /// 
+    /// # Arguments
+    /// * `region` - The type of region to return values for
+    /// 
+    /// [https://research.stlouisfed.org/docs/api/geofred/regional_data.html#region_type](https://research.stlouisfed.org/docs/api/geofred/regional_data.html#region_type)
+    pub fn region_type(&mut self, region: RegionType) -> &mut Builder {
+        self.region_type = Some(region);
+        self
+    }
+
+    /// Sets the date argument (required)
+    /// 
+    /// # Arguments
+    /// * `date` - date formatted as YYYY-MM-DD
+    /// 
+    /// [https://research.stlouisfed.org/docs/api/geofred/regional_data.html#date](https://research.stlouisfed.org/docs/api/geofred/regional_data.html#date)
+    pub fn date(&mut self, date: &str) -> &mut Builder {
+        self.date = Some(crate::common::date_arg(date));
+        self
+    }
+
+    /// Sets the units argument (required)
+    /// 
+    /// # Arguments
+    /// * `units` - The units of the series group as FRED names them (e.g. "Dollars")
+    /// 
+    /// [https://research.stlouisfed.org/docs/api/geofred/regional_data.html#units](https://research.stlouisfed.org/docs/api/geofred/regional_data.html#units)
+    pub fn units(&mut self, units: &str) -> &mut Builder {
+        self.units = Some(encode_query_value(units));
+        self
+    }
+
+    /// Sets the frequency argument (required)
+    /// 
+    /// # Arguments
+    /// * `freq` - The frequency of the series group
+    /// 
+    /// [https://research.stlouisfed.org/docs/api/geofred/regional_data.html#frequency](https://research.stlouisfed.org/docs/api/geofred/regional_data.html#frequency)
+    pub fn frequency(&mut self, freq: Frequency) -> &mut Builder {
+        self.frequency = Some(match freq {
+            Frequency::D => "d",
+            Frequency::W => "w",
+            Frequency::BW => "bw",
+            Frequency::M => "m",
+            Frequency::Q => "q",
+            Frequency::SA => "sa",
+            Frequency::A => "a",
+            Frequency::WEF => "wef",
+            Frequency::WETH => "weth",
+            Frequency::WEW => "wew",
+            Frequency::WETU => "wetu",
+            Frequency::WEM => "wem",
+            Frequency::WESU => "wesu",
+            Frequency::WESA => "wesa",
+            Frequency::BWEW => "bwew",
+            Frequency::BWEM => "bwem",
+        });
+        self
+    }
+
+    /// Sets the season argument (required)
+    /// 
+    /// # Arguments
+    /// * `season` - The seasonal adjustment of the series group
+    /// 
+    /// [https://research.stlouisfed.org/docs/api/geofred/regional_data.html#season](https://research.stlouisfed.org/docs/api/geofred/regional_data.html#season)
+    pub fn season(&mut self, season: SeasonalAdjustment) -> &mut Builder {
+        self.season = Some(match season {
+            SeasonalAdjustment::SA => "SA",
+            SeasonalAdjustment::NSA => "NSA",
+            SeasonalAdjustment::SAAR => "SAAR",
+            SeasonalAdjustment::SSA => "SSA",
+        });
+        self
+    }
+
+    /// Adds a start_date argument to the builder
+    /// 
+    /// Values from this date up to the date argument are returned.
+    /// 
+    /// # Arguments
+    /// * `start_date` - date formatted as YYYY-MM-DD
+    /// 
+    /// [https://research.stlouisfed.org/docs/api/geofred/regional_data.html#start_date](https://research.stlouisfed.org/docs/api/geofred/regional_data.html#start_date)
+    pub fn start_date(&mut self, start_date: &str) -> &mut Builder {
+        self.option_string += format!("&start_date={}", crate::common::date_arg(start_date)).as_str();
+        self
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_regional_data_builder() {
+        let mut builder = Builder::new();
+        builder
+            .series_group("882")
+            .region_type(RegionType::State)
+            .date("2013-01-01")
+            .units("Dollars")
+            .frequency(Frequency::A);
+        assert!(builder.peek().is_err());
+
+        builder
+            .season(SeasonalAdjustment::NSA)
+            .start_date("2010-01-01");
+        match builder.into_query() {
+            Ok(query) => assert_eq!(
+                query,
+                "&series_group=882&region_type=state&date=2013-01-01&units=Dollars&frequency=a&season=NSA&start_date=2010-01-01"
+            ),
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+            },
+        }
+    }
+}
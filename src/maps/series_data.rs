@@ -0,0 +1,126 @@
+//! Get the regional data for a series in a regional series group
+//! 
+//! [https://research.stlouisfed.org/docs/api/geofred/series_data.html](https://research.stlouisfed.org/docs/api/geofred/series_data.html)
+//! 
+//! ```
+//! use fred_rs::client::FredClient;
+//! use fred_rs::maps::{series_data::Builder, Response};
+//! 
+//! let mut c = match FredClient::new() {
+//!     Ok(c) => c,
+//!     Err(msg) => {
+//!         println!("{}", msg);
+//!         assert_eq!(2, 1);
+//!         return
+//!     },
+//! };
+//! 
+//! let mut builder = Builder::new();
+//! builder
+//!     .start_date("2010-01-01")
+//!     .date("2012-01-01");
+//! 
+//! let resp: Response = match c.maps_series_data("WIPCPI", Some(builder)) {
+//!     Ok(resp) => resp,
+//!     Err(msg) => {
+//!         println!("{}", msg);
+//!         assert_eq!(2, 1);
+//!         return
+//!     },
+//! };
+//! 
+//! for date in resp.dates() {
+//!     println!("{}: {} regions", date, resp.values(date).len());
+//! }
+//! ```
+
+#[derive(Clone)]
+pub struct Builder {
+    option_string: String,
+}
+
+impl Builder {
+
+    /// Initializes a new maps::series_data::Builder that can be used to add commands to an API request
+    /// 
+    /// The builder does not do validity checking of the arguments nor does it check for duplicates.
+    /// 
+    /// ```
+    /// use fred_rs::maps::series_data::Builder;
+    /// // Create a new builder
+    /// let mut builder = Builder::new();
+    /// // add arguments to the builder
+    /// builder
+    ///     .date("2012-01-01");
+    /// ```
+    pub fn new() -> Builder {
+        Builder {
+            option_string: String::new(),
+        }
+    }
+
+    /// Removes all arguments from the builder so it can be reused
+    pub fn clear(&mut self) -> &mut Builder {
+        *self = Builder::new();
+        self
+    }
+
+    /// Returns the current arguments as a URL formatted string without consuming the builder
+    pub fn peek(&self) -> String {
+        self.option_string.clone()
+    }
+
+    /// Returns the current arguments as a URL formatted string
+    pub(crate) fn build(self) -> String {
+        self.option_string
+    }
+
+    /// Consumes the builder and returns its arguments as a URL formatted string
+    /// 
+    /// This is the query string the client appends to the request URL, which can be logged or checked in tests without sending a request.
+    pub fn into_query(self) -> String {
+        self.build()
+    }
+
+    /// Adds a date argument to the builder
+    /// 
+    /// Only the values on this date are returned.  The most recent date is used by default.
+    /// 
+    /// # Arguments
+    /// * `date` - date formatted as YYYY-MM-DD
+    /// 
+    /// [https://research.stlouisfed.org/docs/api/geofred/series_data.html#date](https://research.stlouisfed.org/docs/api/geofred/series_data.html#date)
+    pub fn date(&mut self, date: &str) -> &mut Builder {
+        self.option_string += format!("&date={}", crate::common::date_arg(date)).as_str();
+        self
+    }
+
+    /// Adds a start_date argument to the builder
+    /// 
+    /// Values from this date up to the date argument are returned.
+    /// 
+    /// # Arguments
+    /// * `start_date` - date formatted as YYYY-MM-DD
+    /// 
+    /// [https://research.stlouisfed.org/docs/api/geofred/series_data.html#start_date](https://research.stlouisfed.org/docs/api/geofred/series_data.html#start_date)
+    pub fn start_date(&mut self, start_date: &str) -> &mut Builder {
+        self.option_string += format!("&start_date={}", crate::common::date_arg(start_date)).as_str();
+        self
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_series_data_builder() {
+        let mut builder = Builder::new();
+        builder
+            .start_date("2010/01/01")
+            .date("2012-01-01");
+
+        assert_eq!(builder.into_query(), "&start_date=2010-01-01&date=2012-01-01");
+    }
+}
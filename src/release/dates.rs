@@ -0,0 +1,298 @@
+//! Get release dates for a release of economic data
+//! 
+//! [https://research.stlouisfed.org/docs/api/fred/release_dates.html](https://research.stlouisfed.org/docs/api/fred/release_dates.html)
+//! 
+//! ```
+//! use fred_rs::client::FredClient;
+//! use fred_rs::release::dates::{Builder, SortOrder};
+//! use fred_rs::releases::dates::Response;
+//! 
+//! let mut c = match FredClient::new() {
+//!     Ok(c) => c,
+//!     Err(msg) => {
+//!         println!("{}", msg);
+//!         assert_eq!(2, 1);
+//!         return
+//!     },
+//! };
+//! 
+//! let mut builder = Builder::new();
+//! builder
+//!     .limit(5)
+//!     .sort_order(SortOrder::Descending);
+//! 
+//! let resp: Response = match c.release_dates(9, Some(builder)) {
+//!     Ok(resp) => resp,
+//!     Err(msg) => {
+//!         println!("{}", msg);
+//!         assert_eq!(2, 1);
+//!         return
+//!     },
+//! };
+//! 
+//! for item in resp.release_dates {
+//!     println!("{}: {}", item.release_id, item.date);
+//! }
+//! ```
+
+pub use crate::common::SortOrder;
+use crate::common::days_since_epoch;
+use crate::error::{FredError, LIMIT_EXCEEDED_ERROR_TEXT};
+
+/// Publication schedule of a release inferred from its past release dates
+/// 
+/// See [FredClient::release_frequency](../../client/struct.FredClient.html#method.release_frequency)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ReleaseCadence {
+    /// Published every business day
+    Daily,
+    Weekly,
+    Biweekly,
+    Monthly,
+    Quarterly,
+    Semiannual,
+    Annual,
+    /// Published on no regular schedule
+    Irregular,
+}
+
+impl ReleaseCadence {
+    /// Infers the cadence from a list of release dates formatted as YYYY-MM-DD
+    /// 
+    /// The dates may be in any order.  The typical spacing between consecutive dates decides the cadence, with some tolerance so releases moved for weekends or holidays still count as regular.  If fewer than three quarters of the gaps match that cadence the schedule is Irregular.
+    /// 
+    /// Returns None if there are fewer than three valid dates.
+    pub fn from_dates(dates: &[&str]) -> Option<ReleaseCadence> {
+        let mut days: Vec<i32> = dates.iter().filter_map(|d| days_since_epoch(d)).collect();
+        days.sort();
+        days.dedup();
+        if days.len() < 3 {
+            return None;
+        }
+
+        let mut gaps: Vec<i32> = days.windows(2).map(|w| w[1] - w[0]).collect();
+        gaps.sort();
+        let cadence = match cadence_of_gap(gaps[gaps.len() / 2]) {
+            Some(cadence) => cadence,
+            None => return Some(ReleaseCadence::Irregular),
+        };
+
+        let matching = gaps.iter().filter(|gap| cadence_of_gap(**gap) == Some(cadence)).count();
+        if matching * 4 >= gaps.len() * 3 {
+            Some(cadence)
+        } else {
+            Some(ReleaseCadence::Irregular)
+        }
+    }
+}
+
+/// Returns the cadence a gap between two release dates (in days) belongs to
+fn cadence_of_gap(gap: i32) -> Option<ReleaseCadence> {
+    match gap {
+        1..=4 => Some(ReleaseCadence::Daily), // weekends and holidays
+        5..=9 => Some(ReleaseCadence::Weekly),
+        12..=17 => Some(ReleaseCadence::Biweekly),
+        24..=38 => Some(ReleaseCadence::Monthly),
+        80..=102 => Some(ReleaseCadence::Quarterly),
+        170..=195 => Some(ReleaseCadence::Semiannual),
+        350..=380 => Some(ReleaseCadence::Annual),
+        _ => None,
+    }
+}
+
+#[derive(Clone)]
+pub struct Builder {
+    option_string: String
+}
+
+impl Builder {
+
+    /// Initializes a new release::dates::Builder that can be used to add commands to an API request
+    /// 
+    /// The builder does not do validity checking of the arguments nor does it check for duplicates.
+    /// 
+    /// ```
+    /// use fred_rs::release::dates::Builder;
+    /// // Create a new builder
+    /// let mut builder = Builder::new();
+    /// // add arguments to the builder
+    /// builder
+    ///     .realtime_start("1900-01-01")
+    ///     .realtime_end("2000-01-01");
+    /// ```
+    pub fn new() -> Builder {
+        Builder {
+            option_string: String::new(),
+        }
+    }
+
+    /// Removes all arguments from the builder so it can be reused
+    pub fn clear(&mut self) -> &mut Builder {
+        *self = Builder::new();
+        self
+    }
+
+    /// Returns the current arguments as a URL formatted string without consuming the builder
+    pub fn peek(&self) -> String {
+        self.option_string.clone()
+    }
+
+    /// Returns the current arguments as a URL formatted string
+    pub(crate) fn build(self) -> String {
+        self.option_string
+    }
+
+    /// Consumes the builder and returns its arguments as a URL formatted string
+    /// 
+    /// This is the query string the client appends to the request URL, which can be logged or checked in tests without sending a request.
+    pub fn into_query(self) -> String {
+        self.build()
+    }
+
+    /// Adds a realtime_start argument to the builder
+    /// 
+    /// # Arguments
+    /// * `start_date` - date formatted as YYYY-MM-DD
+    /// 
+    /// [https://research.stlouisfed.org/docs/api/fred/release_dates.html#realtime_start](https://research.stlouisfed.org/docs/api/fred/release_dates.html#realtime_start)
+    pub fn realtime_start(&mut self, start_date: &str) -> &mut Builder {
+        self.option_string += format!("&realtime_start={}", crate::common::date_arg(start_date)).as_str();
+        self
+    }
+
+    /// Adds a realtime_end argument to the builder
+    /// 
+    /// # Arguments
+    /// * `end_date` - date formatted as YYYY-MM-DD
+    /// 
+    /// [https://research.stlouisfed.org/docs/api/fred/release_dates.html#realtime_end](https://research.stlouisfed.org/docs/api/fred/release_dates.html#realtime_end)
+    pub fn realtime_end(&mut self, end_date: &str) -> &mut Builder {
+        self.option_string += format!("&realtime_end={}", crate::common::date_arg(end_date)).as_str();
+        self
+    }
+
+    realtime_period!();
+
+    /// Adds a limit argument to the builder
+    /// 
+    /// The limit argument specifies a maximum number of observations to return.
+    /// 
+    /// # Arguments
+    /// * `num_results` - Maximum number of results to return
+    /// 
+    /// [https://research.stlouisfed.org/docs/api/fred/release_dates.html#limit](https://research.stlouisfed.org/docs/api/fred/release_dates.html#limit)
+    pub fn limit(&mut self, num_results: usize) -> &mut Builder {
+        let num_results = if num_results > 10000 { // max value is 10000
+            10000
+        } else {
+            num_results
+        };
+        self.option_string += format!("&limit={}", num_results).as_str();
+        self
+    }
+
+    /// Adds a limit argument to the builder, returning Err rather than clamping
+    /// 
+    /// limit() silently clamps values above the endpoint maximum of 10,000, which returns fewer results than requested.  This function returns Err in that case so the condition can be detected.
+    /// 
+    /// # Arguments
+    /// * `num_results` - Maximum number of results to return
+    pub fn try_limit(&mut self, num_results: usize) -> Result<&mut Builder, FredError> {
+        if num_results > 10000 {
+            return Err(FredError::InvalidArgument(String::from(LIMIT_EXCEEDED_ERROR_TEXT)));
+        }
+        Ok(self.limit(num_results))
+    }
+
+    /// Adds an offset argument to the builder
+    /// 
+    /// Adding an offset shifts the starting result number.  For example, if limit is 5 and offset is 0 then results 1-5 will be returned, but if offset was 5 then results 6-10 would be returned.
+    /// 
+    /// # Arguments
+    /// * `ofs` - the offset amount
+    /// 
+    /// [https://research.stlouisfed.org/docs/api/fred/release_dates.html#offset](https://research.stlouisfed.org/docs/api/fred/release_dates.html#offset)
+    pub fn offset(&mut self, ofs: usize) -> &mut Builder {
+        self.option_string += format!("&offset={}", ofs).as_str();
+        self
+    }
+
+    /// Change the sort order of the data
+    /// 
+    /// # Arguments
+    /// * `order` - Data sort order enum
+    /// 
+    /// [https://research.stlouisfed.org/docs/api/fred/release_dates.html#sort_order](https://research.stlouisfed.org/docs/api/fred/release_dates.html#sort_order)
+    pub fn sort_order(&mut self, order: SortOrder) -> &mut Builder {
+        self.option_string += format!("&sort_order={}", order.as_param()).as_str();
+        self
+    }
+
+    /// Include release dates with no data available
+    /// 
+    /// The default is false.  Calling this will set the argument to true.
+    /// 
+    /// [https://research.stlouisfed.org/docs/api/fred/release_dates.html#include_release_dates_with_no_data](https://research.stlouisfed.org/docs/api/fred/release_dates.html#include_release_dates_with_no_data)
+    pub fn include_release_dates_with_no_data(&mut self) -> &mut Builder {
+        self.option_string += format!(
+            "&include_release_dates_with_no_data=true"
+        ).as_str();
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::releases::dates::Response;
+    use crate::client::FredClient;
+
+    #[test]
+    fn release_dates_with_options() {
+        let mut c = match FredClient::new() {
+            Ok(c) => c,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+
+        let mut builder = Builder::new();
+        builder
+            .limit(5)
+            .sort_order(SortOrder::Descending);
+
+        let resp: Response = match c.release_dates(9, Some(builder)) {
+            Ok(resp) => resp,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+
+        assert_eq!(resp.release_dates.len(), 5);
+        for item in resp.release_dates {
+            println!("{}: {}", item.release_id, item.date);
+        }
+    }
+
+    #[test]
+    fn release_dates_cadence() {
+        // monthly with one release pushed back by a holiday
+        let monthly = ["2019-01-11", "2019-02-13", "2019-03-12", "2019-04-10", "2019-05-10", "2019-06-12"];
+        assert_eq!(ReleaseCadence::from_dates(&monthly), Some(ReleaseCadence::Monthly));
+
+        let quarterly = ["2019-10-30", "2019-01-30", "2019-04-26", "2019-07-26", "2020-01-30"];
+        assert_eq!(ReleaseCadence::from_dates(&quarterly), Some(ReleaseCadence::Quarterly));
+
+        let daily = ["2020-01-02", "2020-01-03", "2020-01-06", "2020-01-07", "2020-01-08"];
+        assert_eq!(ReleaseCadence::from_dates(&daily), Some(ReleaseCadence::Daily));
+
+        let irregular = ["2019-01-01", "2019-01-08", "2019-03-20", "2019-09-01", "2019-09-30"];
+        assert_eq!(ReleaseCadence::from_dates(&irregular), Some(ReleaseCadence::Irregular));
+
+        assert_eq!(ReleaseCadence::from_dates(&["2019-01-01", "2019-02-01"]), None);
+    }
+}
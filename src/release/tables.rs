@@ -33,10 +33,10 @@
 //! }
 //! ```
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize)]
 /// Response data structure for the fred/release/tables endpoint
 /// 
 /// [https://research.stlouisfed.org/docs/api/fred/release_tables.html] (https://research.stlouisfed.org/docs/api/fred/release_tables.html)
@@ -48,10 +48,52 @@ pub struct Response {
     /// The release ID number that was queried
     pub release_id: String,
     /// List of releases related to the specified series_id
+    #[serde(default)]
     pub elements: HashMap<String, Element>,
+    /// Fields in the response that this crate does not parse yet
+    /// 
+    /// Only available with the `extra_fields` feature.
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Response {
+    /// Returns the element with the given element ID number
+    /// 
+    /// The elements map is keyed by the string form of the ID so this saves callers from formatting the key themselves.
+    pub fn element(&self, id: usize) -> Option<&Element> {
+        match self.elements.get(&id.to_string()) {
+            Some(e) => Some(e),
+            None => self.elements.values().find(|e| e.element_id == id),
+        }
+    }
+
+    /// Returns the top level elements of the table, ordered by element ID
+    /// 
+    /// An element is top level if it has no parent or if its parent was not returned with the response.
+    pub fn roots(&self) -> Vec<&Element> {
+        let mut roots: Vec<&Element> = self.elements.values()
+            .filter(|e| match e.parent_id {
+                Some(id) => self.element(id).is_none(),
+                None => true,
+            })
+            .collect();
+        roots.sort_by_key(|e| e.element_id);
+        roots
+    }
+
+    /// Returns the elements whose parent is the given element ID number, ordered by element ID
+    pub fn children_of(&self, id: usize) -> Vec<&Element> {
+        let mut children: Vec<&Element> = self.elements.values()
+            .filter(|e| e.parent_id == Some(id))
+            .collect();
+        children.sort_by_key(|e| e.element_id);
+        children
+    }
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Serialize)]
 /// Data structure containing infomation about a particular release table element
 /// 
 /// [https://research.stlouisfed.org/docs/api/fred/release_tables.html](https://research.stlouisfed.org/docs/api/fred/release_tables.html)
@@ -77,6 +119,7 @@ pub struct Element {
     pub children: Vec<Element>,
 }
 
+#[derive(Clone)]
 pub struct Builder {
     option_string: String
 }
@@ -101,11 +144,29 @@ impl Builder {
         }
     }
 
+    /// Removes all arguments from the builder so it can be reused
+    pub fn clear(&mut self) -> &mut Builder {
+        *self = Builder::new();
+        self
+    }
+
+    /// Returns the current arguments as a URL formatted string without consuming the builder
+    pub fn peek(&self) -> String {
+        self.option_string.clone()
+    }
+
     /// Returns the current arguments as a URL formatted string
     pub(crate) fn build(self) -> String {
         self.option_string
     }
 
+    /// Consumes the builder and returns its arguments as a URL formatted string
+    /// 
+    /// This is the query string the client appends to the request URL, which can be logged or checked in tests without sending a request.
+    pub fn into_query(self) -> String {
+        self.build()
+    }
+
     /// Add the element_id argument to the builder
     /// 
     /// # Arguments
@@ -132,7 +193,7 @@ impl Builder {
     /// 
     /// [https://research.stlouisfed.org/docs/api/fred/release_tables.html#observation_date](https://research.stlouisfed.org/docs/api/fred/release_tables.html#observation_date)
     pub fn observation_date(&mut self, date: &str) -> &mut Builder {
-        self.option_string += format!("&observation_date={}", date).as_str();
+        self.option_string += format!("&observation_date={}", crate::common::date_arg(date)).as_str();
         self
     }
 }
@@ -168,5 +229,53 @@ mod tests {
         for (key, value) in resp.elements {
             println!("{}: {}", key, value.name);
         }
-    } 
-}
\ No newline at end of file
+    }
+
+    #[test]
+    fn release_tables_element_hierarchy() {
+        let json = r#"{
+            "name": "Personal Income and Its Disposition, Monthly",
+            "element_id": 12886,
+            "release_id": "53",
+            "elements": {
+                "12887": {"element_id": 12887, "release_id": 53, "series_id": "DSPIC96", "parent_id": 12886, "line": "1", "type": "series", "name": "Real disposable personal income", "level": "1", "children": []},
+                "12888": {"element_id": 12888, "release_id": 53, "series_id": "PCE", "parent_id": 12886, "line": "2", "type": "series", "name": "Personal consumption expenditures", "level": "1", "children": []},
+                "12889": {"element_id": 12889, "release_id": 53, "series_id": "PCEDG", "parent_id": 12888, "line": "3", "type": "series", "name": "Durable goods", "level": "2", "children": []}
+            }
+        }"#;
+        let resp: Response = serde_json::from_str(json).unwrap();
+
+        assert_eq!(resp.element(12889).unwrap().name, "Durable goods");
+        assert!(resp.element(1).is_none());
+
+        let roots: Vec<usize> = resp.roots().iter().map(|e| e.element_id).collect();
+        assert_eq!(roots, vec![12887, 12888]);
+
+        let children: Vec<usize> = resp.children_of(12888).iter().map(|e| e.element_id).collect();
+        assert_eq!(children, vec![12889]);
+    }
+
+    #[test]
+    fn release_tables_empty() {
+        // FRED leaves the list out of some responses with no results
+        let resp: Response = match serde_json::from_str(r#"{"release_id":"53"}"#) {
+            Ok(resp) => resp,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+        assert!(resp.elements.is_empty());
+
+        let resp: Response = match serde_json::from_str(r#"{"release_id":"53","elements":{}}"#) {
+            Ok(resp) => resp,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+        assert!(resp.elements.is_empty());
+    }
+}
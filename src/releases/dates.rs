@@ -32,10 +32,17 @@
 //! };
 //! ```
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::fmt::{self, Display, Formatter};
 
-#[derive(Deserialize, Clone, Debug, Default)]
+use crate::common::{Paged, Pagination};
+pub use crate::common::SortOrder;
+use crate::error::{FredError, LIMIT_EXCEEDED_ERROR_TEXT};
+
+#[cfg(feature = "chrono")]
+use chrono::NaiveDate;
+
+#[derive(Deserialize, Serialize, Clone, Debug, Default)]
 /// Response data structure for the fred/releases/dates endpoint
 /// 
 /// [https://research.stlouisfed.org/docs/api/fred/releases_dates.html] (https://research.stlouisfed.org/docs/api/fred/releases_dates.html)
@@ -55,7 +62,45 @@ pub struct Response {
     /// Maximum number of results to return
     pub limit: usize,
     /// List of release dates for a release
+    #[serde(default, alias = "release_date")]
     pub release_dates: Vec<ReleaseDate>,
+    /// Fields in the response that this crate does not parse yet
+    /// 
+    /// Only available with the `extra_fields` feature.
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Response {
+    /// Returns the realtime period of the response as dates, see [series::Response::realtime_range](../../series/struct.Response.html#method.realtime_range)
+    #[cfg(feature = "chrono")]
+    pub fn realtime_range(&self) -> Result<(NaiveDate, NaiveDate), String> {
+        crate::common::realtime_range(&self.realtime_start, &self.realtime_end)
+    }
+
+    /// Returns the request arguments FRED echoed back in the response as (name, value) pairs
+    pub fn echoed_params(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("realtime_start", self.realtime_start.clone()),
+            ("realtime_end", self.realtime_end.clone()),
+            ("order_by", self.order_by.clone()),
+            ("sort_order", self.sort_order.clone()),
+            ("limit", self.limit.to_string()),
+            ("offset", self.offset.to_string()),
+        ]
+    }
+}
+
+impl Paged for Response {
+    fn pagination(&self) -> Pagination {
+        Pagination {
+            count: self.count,
+            offset: self.offset,
+            limit: self.limit,
+            returned: self.release_dates.len(),
+        }
+    }
 }
 
 impl Display for Response {
@@ -74,7 +119,7 @@ impl Display for Response {
     }
 }
 
-#[derive(Deserialize, Clone, Debug, Default)]
+#[derive(Deserialize, Serialize, Clone, Debug, Default)]
 /// Data structure containing infomation about a particular release
 /// 
 /// [https://research.stlouisfed.org/docs/api/fred/releases_dates.html](https://research.stlouisfed.org/docs/api/fred/releases_dates.html)
@@ -103,16 +148,7 @@ pub enum OrderBy {
     ReleaseName,
 }
 
-/// Sort order options for the fred/releases/dates endpoint
-/// 
-/// [https://research.stlouisfed.org/docs/api/fred/releases_dates.html#sort_order](https://research.stlouisfed.org/docs/api/fred/releases_dates.html#sort_order)
-pub enum SortOrder {
-    /// Dates returned in ascending order
-    Ascending,    
-    /// Dates returned in descending order (default)
-    Descending,   
-}
-
+#[derive(Clone)]
 pub struct Builder {
     option_string: String
 }
@@ -138,11 +174,29 @@ impl Builder {
         }
     }
 
+    /// Removes all arguments from the builder so it can be reused
+    pub fn clear(&mut self) -> &mut Builder {
+        *self = Builder::new();
+        self
+    }
+
+    /// Returns the current arguments as a URL formatted string without consuming the builder
+    pub fn peek(&self) -> String {
+        self.option_string.clone()
+    }
+
     /// Returns the current arguments as a URL formatted string
     pub(crate) fn build(self) -> String {
         self.option_string
     }
 
+    /// Consumes the builder and returns its arguments as a URL formatted string
+    /// 
+    /// This is the query string the client appends to the request URL, which can be logged or checked in tests without sending a request.
+    pub fn into_query(self) -> String {
+        self.build()
+    }
+
     /// Adds a realtime_start argument to the builder
     /// 
     /// # Arguments
@@ -150,7 +204,7 @@ impl Builder {
     /// 
     /// [https://research.stlouisfed.org/docs/api/fred/releases_dates.html#realtime_start](https://research.stlouisfed.org/docs/api/fred/releases_dates.html#realtime_start)
     pub fn realtime_start(&mut self, start_date: &str) -> &mut Builder {
-        self.option_string += format!("&realtime_start={}", start_date).as_str();
+        self.option_string += format!("&realtime_start={}", crate::common::date_arg(start_date)).as_str();
         self
     }
 
@@ -161,10 +215,12 @@ impl Builder {
     /// 
     /// [https://research.stlouisfed.org/docs/api/fred/releases_dates.html#realtime_end](https://research.stlouisfed.org/docs/api/fred/releases_dates.html#realtime_end)
     pub fn realtime_end(&mut self, end_date: &str) -> &mut Builder {
-        self.option_string += format!("&realtime_end={}", end_date).as_str();
+        self.option_string += format!("&realtime_end={}", crate::common::date_arg(end_date)).as_str();
         self
     }
 
+    realtime_period!();
+
     /// Adds a limit argument to the builder
     /// 
     /// The limit argument specifies a maximum number of observations to return.
@@ -183,6 +239,19 @@ impl Builder {
         self
     }
 
+    /// Adds a limit argument to the builder, returning Err rather than clamping
+    /// 
+    /// limit() silently clamps values above the endpoint maximum of 1,000, which returns fewer results than requested.  This function returns Err in that case so the condition can be detected.
+    /// 
+    /// # Arguments
+    /// * `num_results` - Maximum number of results to return
+    pub fn try_limit(&mut self, num_results: usize) -> Result<&mut Builder, FredError> {
+        if num_results > 1000 {
+            return Err(FredError::InvalidArgument(String::from(LIMIT_EXCEEDED_ERROR_TEXT)));
+        }
+        Ok(self.limit(num_results))
+    }
+
     /// Adds an offset argument to the builder
     /// 
     /// Adding an offset shifts the starting result number.  For example, if limit is 5 and offset is 0 then results 1-5 will be returned, but if offset was 5 then results 6-10 would be returned.
@@ -224,12 +293,7 @@ impl Builder {
     /// 
     /// [https://research.stlouisfed.org/docs/api/fred/releases_dates.html#sort_order](https://research.stlouisfed.org/docs/api/fred/releases_dates.html#sort_order)
     pub fn sort_order(&mut self, order: SortOrder) -> &mut Builder {
-        match order {
-            SortOrder::Ascending => {
-                self.option_string += format!("&sort_order=asc").as_str()
-            },
-            _ => () // DESC is the default so do nothing
-        }
+        self.option_string += format!("&sort_order={}", order.as_param()).as_str();
         self
     }
 
@@ -280,5 +344,29 @@ mod tests {
         for item in resp.release_dates {
             println!("{}: {} -> {}", item.date, item.release_id, item.release_name.unwrap());
         }
-    } 
-}
\ No newline at end of file
+    }
+
+    #[test]
+    fn releases_dates_empty() {
+        // FRED leaves the list out of some responses with no results
+        let resp: Response = match serde_json::from_str(r#"{"realtime_start":"2020-01-01","realtime_end":"2020-01-01","order_by":"series_id","sort_order":"asc","count":0,"offset":0,"limit":1000}"#) {
+            Ok(resp) => resp,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+        assert!(resp.release_dates.is_empty());
+
+        let resp: Response = match serde_json::from_str(r#"{"realtime_start":"2020-01-01","realtime_end":"2020-01-01","order_by":"series_id","sort_order":"asc","count":0,"offset":0,"limit":1000,"release_dates":[]}"#) {
+            Ok(resp) => resp,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+        assert!(resp.release_dates.is_empty());
+    }
+}
@@ -36,6 +36,10 @@
 //! }
 //! ```
 
+pub use crate::common::SortOrder;
+pub use crate::release::Response;
+use crate::error::{FredError, LIMIT_EXCEEDED_ERROR_TEXT};
+
 pub mod dates;
 
 // -----------------------------------------------------------------------------
@@ -52,16 +56,7 @@ pub enum OrderBy {
     RealtimeEnd,
 }
 
-/// Sort order options for the fred/releases endpoint
-/// 
-/// [https://research.stlouisfed.org/docs/api/fred/releases.html#sort_order](https://research.stlouisfed.org/docs/api/fred/releases.html#sort_order)
-pub enum SortOrder {
-    /// Dates returned in ascending order (default)
-    Ascending,    
-    /// Dates returned in descending order
-    Descending,   
-}
-
+#[derive(Clone)]
 pub struct Builder {
     option_string: String
 }
@@ -87,11 +82,29 @@ impl Builder {
         }
     }
 
+    /// Removes all arguments from the builder so it can be reused
+    pub fn clear(&mut self) -> &mut Builder {
+        *self = Builder::new();
+        self
+    }
+
+    /// Returns the current arguments as a URL formatted string without consuming the builder
+    pub fn peek(&self) -> String {
+        self.option_string.clone()
+    }
+
     /// Returns the current arguments as a URL formatted string
     pub(crate) fn build(self) -> String {
         self.option_string
     }
 
+    /// Consumes the builder and returns its arguments as a URL formatted string
+    /// 
+    /// This is the query string the client appends to the request URL, which can be logged or checked in tests without sending a request.
+    pub fn into_query(self) -> String {
+        self.build()
+    }
+
     /// Adds a realtime_start argument to the builder
     /// 
     /// # Arguments
@@ -99,7 +112,7 @@ impl Builder {
     /// 
     /// [https://research.stlouisfed.org/docs/api/fred/releases.html#realtime_start](https://research.stlouisfed.org/docs/api/fred/releases.html#realtime_start)
     pub fn realtime_start(&mut self, start_date: &str) -> &mut Builder {
-        self.option_string += format!("&realtime_start={}", start_date).as_str();
+        self.option_string += format!("&realtime_start={}", crate::common::date_arg(start_date)).as_str();
         self
     }
 
@@ -110,10 +123,12 @@ impl Builder {
     /// 
     /// [https://research.stlouisfed.org/docs/api/fred/releases.html#realtime_end](https://research.stlouisfed.org/docs/api/fred/releases.html#realtime_end)
     pub fn realtime_end(&mut self, end_date: &str) -> &mut Builder {
-        self.option_string += format!("&realtime_end={}", end_date).as_str();
+        self.option_string += format!("&realtime_end={}", crate::common::date_arg(end_date)).as_str();
         self
     }
 
+    realtime_period!();
+
     /// Adds a limit argument to the builder
     /// 
     /// The limit argument specifies a maximum number of observations to return.
@@ -132,6 +147,19 @@ impl Builder {
         self
     }
 
+    /// Adds a limit argument to the builder, returning Err rather than clamping
+    /// 
+    /// limit() silently clamps values above the endpoint maximum of 1,000, which returns fewer results than requested.  This function returns Err in that case so the condition can be detected.
+    /// 
+    /// # Arguments
+    /// * `num_results` - Maximum number of results to return
+    pub fn try_limit(&mut self, num_results: usize) -> Result<&mut Builder, FredError> {
+        if num_results > 1000 {
+            return Err(FredError::InvalidArgument(String::from(LIMIT_EXCEEDED_ERROR_TEXT)));
+        }
+        Ok(self.limit(num_results))
+    }
+
     /// Adds an offset argument to the builder
     /// 
     /// Adding an offset shifts the starting result number.  For example, if limit is 5 and offset is 0 then results 1-5 will be returned, but if offset was 5 then results 6-10 would be returned.
@@ -179,12 +207,7 @@ impl Builder {
     /// 
     /// [https://research.stlouisfed.org/docs/api/fred/releases.html#sort_order](https://research.stlouisfed.org/docs/api/fred/releases.html#sort_order)
     pub fn sort_order(&mut self, order: SortOrder) -> &mut Builder {
-        match order {
-            SortOrder::Descending => {
-                self.option_string += format!("&sort_order=desc").as_str()
-            },
-            _ => () // ASC is the default so do nothing
-        }
+        self.option_string += format!("&sort_order={}", order.as_param()).as_str();
         self
     }
 }
@@ -34,6 +34,7 @@
 //! };
 //! ```
 
+#[derive(Clone)]
 pub struct Builder {
     option_string: String
 }
@@ -59,11 +60,29 @@ impl Builder {
         }
     }
 
+    /// Removes all arguments from the builder so it can be reused
+    pub fn clear(&mut self) -> &mut Builder {
+        *self = Builder::new();
+        self
+    }
+
+    /// Returns the current arguments as a URL formatted string without consuming the builder
+    pub fn peek(&self) -> String {
+        self.option_string.clone()
+    }
+
     /// Returns the current arguments as a URL formatted string
     pub(crate) fn build(self) -> String {
         self.option_string
     }
 
+    /// Consumes the builder and returns its arguments as a URL formatted string
+    /// 
+    /// This is the query string the client appends to the request URL, which can be logged or checked in tests without sending a request.
+    pub fn into_query(self) -> String {
+        self.build()
+    }
+
     /// Adds a realtime_start argument to the builder
     /// 
     /// # Arguments
@@ -71,7 +90,7 @@ impl Builder {
     /// 
     /// [https://research.stlouisfed.org/docs/api/fred/series_categories.html#realtime_start](https://research.stlouisfed.org/docs/api/fred/series_categories.html#realtime_start)
     pub fn realtime_start(&mut self, start_date: &str) -> &mut Builder {
-        self.option_string += format!("&realtime_start={}", start_date).as_str();
+        self.option_string += format!("&realtime_start={}", crate::common::date_arg(start_date)).as_str();
         self
     }
 
@@ -82,9 +101,11 @@ impl Builder {
     /// 
     /// [https://research.stlouisfed.org/docs/api/fred/series_categories.html#realtime_start](https://research.stlouisfed.org/docs/api/fred/series_categories.html#realtime_start)
     pub fn realtime_end(&mut self, end_date: &str) -> &mut Builder {
-        self.option_string += format!("&realtime_end={}", end_date).as_str();
+        self.option_string += format!("&realtime_end={}", crate::common::date_arg(end_date)).as_str();
         self
     }
+
+    realtime_period!();
 }
 
 #[cfg(test)]
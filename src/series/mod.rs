@@ -47,10 +47,15 @@ pub mod updates;
 pub mod vintagedates;
 
 // ----------------------------------------------------------------------------
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::fmt::{self, Display, Formatter};
 
-#[derive(Deserialize, Clone, Debug, Default)]
+use crate::common::{days_since_epoch, Paged, Pagination};
+
+#[cfg(feature = "chrono")]
+use chrono::NaiveDate;
+
+#[derive(Deserialize, Serialize, Clone, Debug, Default)]
 /// Response data structure for the fred/series endpoint
 /// 
 /// Order_by, sort_order, count, offset and limit are used by endpoints which return a list of series.  They can be ignored for the fred/series endpoint.
@@ -74,7 +79,104 @@ pub struct Response {
     /// Series matching the requested series_id
     /// 
     /// The fred/series endpoint will return a series for each time a series changed.  For example Real GNP has been calculated several different ways over time so this endpoint will return a different series for each time period becasue they all fit under the same symbol: GNPCA.
+    #[serde(default, alias = "series")]
     pub seriess: Vec<Series>,
+    /// Fields in the response that this crate does not parse yet
+    /// 
+    /// Only available with the `extra_fields` feature.
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Response {
+    /// Returns the realtime period of the response as dates
+    /// 
+    /// A realtime_end of 9999-12-31 means the data is still current and is returned as that date.  Returns Err if either date cannot be parsed.
+    #[cfg(feature = "chrono")]
+    pub fn realtime_range(&self) -> Result<(NaiveDate, NaiveDate), String> {
+        crate::common::realtime_range(&self.realtime_start, &self.realtime_end)
+    }
+
+    /// Returns the request arguments FRED echoed back in the response
+    /// 
+    /// Each pair is an argument name and the value FRED used for it, including defaults for arguments the builder did not set.  Comparing these against the builder shows how FRED interpreted the request.
+    pub fn echoed_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = vec![
+            ("realtime_start", self.realtime_start.clone()),
+            ("realtime_end", self.realtime_end.clone()),
+        ];
+        if let Some(v) = &self.order_by {
+            params.push(("order_by", v.clone()));
+        }
+        if let Some(v) = &self.sort_order {
+            params.push(("sort_order", v.clone()));
+        }
+        if let Some(v) = &self.limit {
+            params.push(("limit", v.to_string()));
+        }
+        if let Some(v) = &self.offset {
+            params.push(("offset", v.to_string()));
+        }
+        params
+    }
+
+    /// Returns the series paired with their rank in the results
+    /// 
+    /// FRED does not return a relevance score with search results, but results ordered by search_rank (the default for full text searches) are returned most relevant first.  The rank is the 1-based position of the series across all pages, so the response offset is taken into account.
+    pub fn ranked(&self) -> Vec<(usize, &Series)> {
+        let offset = self.offset.unwrap_or(0);
+        self.seriess.iter()
+            .enumerate()
+            .map(|(i, s)| (offset + i + 1, s))
+            .collect()
+    }
+
+    /// Returns one series for each distinct title in the results
+    /// 
+    /// Searches often return near duplicates such as the seasonally adjusted and not seasonally adjusted versions of a series, which share a title.  Titles are compared ignoring case, punctuation and spacing, and the most popular series of each group is kept (the first one on ties).  The groups are returned in the order their first series appeared.
+    pub fn dedup_by_title(&self) -> Vec<&Series> {
+        let mut keys: Vec<String> = Vec::new();
+        let mut kept: Vec<&Series> = Vec::new();
+
+        for item in self.seriess.iter() {
+            let key = normalize_title(&item.title);
+            match keys.iter().position(|k| *k == key) {
+                Some(i) => {
+                    if item.popularity > kept[i].popularity {
+                        kept[i] = item;
+                    }
+                },
+                None => {
+                    keys.push(key);
+                    kept.push(item);
+                },
+            }
+        }
+
+        kept
+    }
+}
+
+/// Lowercases a title and reduces punctuation and whitespace to single spaces
+fn normalize_title(title: &str) -> String {
+    title
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+impl Paged for Response {
+    fn pagination(&self) -> Pagination {
+        Pagination {
+            count: self.count.unwrap_or(0),
+            offset: self.offset.unwrap_or(0),
+            limit: self.limit.unwrap_or(0),
+            returned: self.seriess.len(),
+        }
+    }
 }
 
 impl Display for Response {
@@ -93,7 +195,7 @@ impl Display for Response {
     }
 }
 
-#[derive(Deserialize, Clone, Debug, Default)]
+#[derive(Deserialize, Serialize, Clone, Debug, Default)]
 /// Data structure containing infomation about a particular data series
 /// 
 /// [https://research.stlouisfed.org/docs/api/fred/series.html](https://research.stlouisfed.org/docs/api/fred/series.html)
@@ -127,17 +229,243 @@ pub struct Series {
     /// Popularity score
     pub popularity: isize,
     /// Group popularity score
+    /// 
+    /// Only some endpoints (e.g. fred/series/search) return this value, see [group_popularity_or_zero](#method.group_popularity_or_zero).
     pub group_popularity: Option<isize>,
     /// Additional Notes
     pub notes: Option<String>,
 }
 
+impl Series {
+    /// Returns the group popularity score, or 0 if the endpoint did not return one
+    pub fn group_popularity_or_zero(&self) -> isize {
+        self.group_popularity.unwrap_or(0)
+    }
+
+    /// Parses the first day of the real-time period
+    #[cfg(feature = "chrono")]
+    pub fn realtime_start_parsed(&self) -> Result<NaiveDate, chrono::ParseError> {
+        NaiveDate::parse_from_str(&self.realtime_start, "%Y-%m-%d")
+    }
+
+    /// Parses the last day of the real-time period
+    /// 
+    /// FRED uses 9999-12-31 for series that are still current.
+    #[cfg(feature = "chrono")]
+    pub fn realtime_end_parsed(&self) -> Result<NaiveDate, chrono::ParseError> {
+        NaiveDate::parse_from_str(&self.realtime_end, "%Y-%m-%d")
+    }
+
+    /// Parses the date of the first observation
+    #[cfg(feature = "chrono")]
+    pub fn observation_start_parsed(&self) -> Result<NaiveDate, chrono::ParseError> {
+        NaiveDate::parse_from_str(&self.observation_start, "%Y-%m-%d")
+    }
+
+    /// Parses the date of the last observation
+    #[cfg(feature = "chrono")]
+    pub fn observation_end_parsed(&self) -> Result<NaiveDate, chrono::ParseError> {
+        NaiveDate::parse_from_str(&self.observation_end, "%Y-%m-%d")
+    }
+
+    /// Interprets the frequency FRED reported for the series
+    /// 
+    /// The full frequency (e.g. "Weekly, Ending Friday") is matched first so weekly and biweekly series keep the day they end on, then the short form (e.g. "M").  Returns None if neither is recognized.
+    pub fn frequency_enum(&self) -> Option<observation::Frequency> {
+        use observation::Frequency::*;
+
+        let all = [D, W, BW, M, Q, SA, A, WEF, WETH, WEW, WETU, WEM, WESU, WESA, BWEW, BWEM];
+        match all.iter().find(|f| f.name().eq_ignore_ascii_case(self.frequency.trim())) {
+            Some(f) => return Some(*f),
+            None => (),
+        }
+
+        match self.frequency_short.trim().to_ascii_uppercase().as_str() {
+            "D" => Some(D),
+            "W" => Some(W),
+            "BW" => Some(BW),
+            "M" => Some(M),
+            "Q" => Some(Q),
+            "SA" => Some(SA),
+            "A" => Some(A),
+            _ => None,
+        }
+    }
+
+    /// Returns true if FRED reports the series as seasonally adjusted
+    /// 
+    /// Seasonally adjusted annual rates (SAAR) and smoothed seasonally adjusted series (SSA) count as adjusted.  The short form is checked first, then the full description.
+    pub fn is_seasonally_adjusted(&self) -> bool {
+        match self.seasonal_adjustment_short.trim().to_ascii_uppercase().as_str() {
+            "SA" | "SAAR" | "SSA" => return true,
+            "NSA" | "NSAAR" => return false,
+            _ => (),
+        }
+
+        let adjustment = self.seasonal_adjustment.trim().to_ascii_lowercase();
+        !adjustment.starts_with("not") && adjustment.contains("seasonally adjusted")
+    }
+
+    /// Classifies the series units into a general measurement system
+    /// 
+    /// The classification is a best effort parse of the units string (e.g. "Index 2012=100" or "Billions of Dollars") and returns Measurement::Unknown when the units are not recognized.
+    pub fn measurement(&self) -> Measurement {
+        Measurement::from_units(self.units.as_str())
+    }
+
+    /// Checks whether a units transform will return any values for this series
+    /// 
+    /// Year over year transforms (CH1, PC1) need observations at least a year apart and the other change transforms need at least two observations.  The check uses the observation_start and observation_end of the series together with its frequency.  Returns true when the span cannot be determined so the request is not blocked.
+    /// 
+    /// # Arguments
+    /// * `units` - the transform that would be requested
+    pub fn supports_units(&self, units: &observation::Units) -> bool {
+        let span = match (
+            days_since_epoch(&self.observation_start),
+            days_since_epoch(&self.observation_end),
+        ) {
+            (Some(start), Some(end)) => end - start,
+            _ => return true,
+        };
+
+        // shortest length of one period of the native frequency in days
+        let period = match self.frequency_short.as_str() {
+            "D" => 1,
+            "W" => 7,
+            "BW" => 14,
+            "M" => 28,
+            "Q" => 89,
+            "SA" => 181,
+            "A" => 365,
+            _ => 1,
+        };
+
+        match units {
+            observation::Units::LIN | observation::Units::LOG => true,
+            observation::Units::CH1 | observation::Units::PC1 => span >= 365,
+            _ => span >= period,
+        }
+    }
+}
+
 impl Display for Series {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(f, "Series {}: {}", self.id, self.title)
     }
 }
 
+#[derive(Clone, Debug, PartialEq)]
+/// General measurement system of a series' units
+/// 
+/// See [Series::measurement](struct.Series.html#method.measurement)
+pub enum Measurement {
+    /// A currency amount, holding the currency as written in the units (e.g. Dollars)
+    Currency(String),
+    /// A percentage or percent change
+    Percent,
+    /// An index, holding the base period if one is given (e.g. 2012=100)
+    Index {
+        base: Option<String>,
+    },
+    /// A count of people or things
+    Count,
+    /// A ratio between two quantities (e.g. an exchange rate)
+    Ratio,
+    /// Units that could not be classified
+    Unknown,
+}
+
+const CURRENCY_KEYWORDS: [&str; 8] = [
+    "u.s. dollars",
+    "dollars",
+    "euros",
+    "euro",
+    "yen",
+    "yuan",
+    "national currency",
+    "domestic currency",
+];
+
+const COUNT_KEYWORDS: [&str; 5] = [
+    "persons",
+    "people",
+    "number",
+    "units",
+    "households",
+];
+
+impl Measurement {
+    fn from_units(units: &str) -> Measurement {
+        let lower = units.to_lowercase();
+
+        if lower.starts_with("index") {
+            let base = units["index".len()..].trim();
+            return Measurement::Index {
+                base: if base.contains('=') { Some(String::from(base)) } else { None },
+            };
+        }
+        if lower.contains("percent") {
+            return Measurement::Percent;
+        }
+        if lower.contains("ratio") || lower.contains(" to one ") {
+            return Measurement::Ratio;
+        }
+        for keyword in CURRENCY_KEYWORDS.iter() {
+            if let Some(i) = lower.find(keyword) {
+                let currency = units.get(i..i + keyword.len()).unwrap_or(keyword);
+                return Measurement::Currency(String::from(currency));
+            }
+        }
+        if COUNT_KEYWORDS.iter().any(|keyword| lower.contains(keyword)) {
+            return Measurement::Count;
+        }
+
+        Measurement::Unknown
+    }
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+/// A series' metadata together with its observations
+/// 
+/// Returned by [FredClient::timeseries](../client/struct.FredClient.html#method.timeseries).
+pub struct TimeSeries {
+    /// Metadata for the series, including its title, units and frequency
+    pub meta: Series,
+    /// The observations of the series
+    pub observations: observation::Response,
+}
+
+impl TimeSeries {
+    /// Returns the observation values in order, with missing values (".") as None
+    pub fn values(&self) -> Vec<Option<f64>> {
+        self.observations.observations.iter()
+            .map(|item| observation::parse_value(&item.value))
+            .collect()
+    }
+
+    /// Returns the most recent observation which is not missing
+    pub fn latest(&self) -> Option<&observation::DataPoint> {
+        self.observations.observations.iter()
+            .filter(|item| observation::parse_value(&item.value).is_some())
+            .max_by(|a, b| a.date.cmp(&b.date))
+    }
+
+    /// Returns the observations as CSV text with a `date` column and a column named after the series id
+    /// 
+    /// Missing values are written as empty cells.
+    pub fn to_csv(&self) -> String {
+        let mut csv = format!("date,{}\n", self.meta.id);
+        for item in self.observations.observations.iter() {
+            match observation::parse_value(&item.value) {
+                Some(_) => csv += format!("{},{}\n", item.date, item.value).as_str(),
+                None => csv += format!("{},\n", item.date).as_str(),
+            }
+        }
+        csv
+    }
+}
+
+#[derive(Clone)]
 pub struct Builder {
     option_string: String
 }
@@ -163,11 +491,29 @@ impl Builder {
         }
     }
 
+    /// Removes all arguments from the builder so it can be reused
+    pub fn clear(&mut self) -> &mut Builder {
+        *self = Builder::new();
+        self
+    }
+
+    /// Returns the current arguments as a URL formatted string without consuming the builder
+    pub fn peek(&self) -> String {
+        self.option_string.clone()
+    }
+
     /// Returns the current arguments as a URL formatted string
     pub(crate) fn build(self) -> String {
         self.option_string
     }
 
+    /// Consumes the builder and returns its arguments as a URL formatted string
+    /// 
+    /// This is the query string the client appends to the request URL, which can be logged or checked in tests without sending a request.
+    pub fn into_query(self) -> String {
+        self.build()
+    }
+
     /// Adds a realtime_start argument to the builder
     /// 
     /// # Arguments
@@ -175,7 +521,7 @@ impl Builder {
     /// 
     /// [https://research.stlouisfed.org/docs/api/fred/series.html#realtime_start](https://research.stlouisfed.org/docs/api/fred/series.html#realtime_start)
     pub fn realtime_start(&mut self, start_date: &str) -> &mut Builder {
-        self.option_string += format!("&realtime_start={}", start_date).as_str();
+        self.option_string += format!("&realtime_start={}", crate::common::date_arg(start_date)).as_str();
         self
     }
 
@@ -186,9 +532,33 @@ impl Builder {
     /// 
     /// [https://research.stlouisfed.org/docs/api/fred/series.html#realtime_end](https://research.stlouisfed.org/docs/api/fred/series.html#realtime_end)
     pub fn realtime_end(&mut self, end_date: &str) -> &mut Builder {
-        self.option_string += format!("&realtime_end={}", end_date).as_str();
+        self.option_string += format!("&realtime_end={}", crate::common::date_arg(end_date)).as_str();
         self
     }
+
+    realtime_period!();
+
+    /// Adds a realtime_start argument to the builder from a date
+    /// 
+    /// # Arguments
+    /// * `start_date` - the first day of the real-time period
+    /// 
+    /// [https://research.stlouisfed.org/docs/api/fred/series.html#realtime_start](https://research.stlouisfed.org/docs/api/fred/series.html#realtime_start)
+    #[cfg(feature = "chrono")]
+    pub fn realtime_start_date(&mut self, start_date: NaiveDate) -> &mut Builder {
+        self.realtime_start(start_date.format("%Y-%m-%d").to_string().as_str())
+    }
+
+    /// Adds a realtime_end argument to the builder from a date
+    /// 
+    /// # Arguments
+    /// * `end_date` - the last day of the real-time period
+    /// 
+    /// [https://research.stlouisfed.org/docs/api/fred/series.html#realtime_end](https://research.stlouisfed.org/docs/api/fred/series.html#realtime_end)
+    #[cfg(feature = "chrono")]
+    pub fn realtime_end_date(&mut self, end_date: NaiveDate) -> &mut Builder {
+        self.realtime_end(end_date.format("%Y-%m-%d").to_string().as_str())
+    }
 }
 
 #[cfg(test)]
@@ -223,5 +593,328 @@ mod tests {
         for item in resp.seriess {
             println!("{}: {} {} {}", item.id, item.title, item.realtime_start, item.realtime_end);
         }
-    } 
-}
\ No newline at end of file
+    }
+
+    #[test]
+    fn series_measurement() {
+        let cases = [
+            ("Index 2012=100", Measurement::Index { base: Some(String::from("2012=100")) }),
+            ("Index Jan 1995=100", Measurement::Index { base: Some(String::from("Jan 1995=100")) }),
+            ("Index", Measurement::Index { base: None }),
+            ("Percent", Measurement::Percent),
+            ("Percent of GDP", Measurement::Percent),
+            ("Billions of Chained 2012 Dollars", Measurement::Currency(String::from("Dollars"))),
+            ("Millions of U.S. Dollars", Measurement::Currency(String::from("U.S. Dollars"))),
+            ("Thousands of Persons", Measurement::Count),
+            ("Ratio", Measurement::Ratio),
+            ("U.S. Dollars to One Euro", Measurement::Ratio),
+            ("Degrees Fahrenheit", Measurement::Unknown),
+        ];
+
+        for (units, expected) in cases.iter() {
+            let series = Series {
+                units: String::from(*units),
+                ..Series::default()
+            };
+            assert_eq!(&series.measurement(), expected, "units: {}", units);
+        }
+    }
+
+    #[test]
+    fn series_ranked() {
+        let mut resp = Response::default();
+        resp.offset = Some(10);
+        for id in ["GDP", "GDPC1"].iter() {
+            resp.seriess.push(Series {
+                id: String::from(*id),
+                ..Series::default()
+            });
+        }
+
+        let ranks: Vec<(usize, &str)> = resp.ranked()
+            .iter()
+            .map(|(rank, s)| (*rank, s.id.as_str()))
+            .collect();
+        assert_eq!(ranks, vec![(11, "GDP"), (12, "GDPC1")]);
+    }
+
+    #[test]
+    fn series_echoed_params() {
+        let mut resp = Response::default();
+        resp.realtime_start = String::from("2013-08-14");
+        resp.realtime_end = String::from("2013-08-14");
+        resp.order_by = Some(String::from("search_rank"));
+        resp.limit = Some(1000);
+
+        assert_eq!(
+            resp.echoed_params(),
+            vec![
+                ("realtime_start", String::from("2013-08-14")),
+                ("realtime_end", String::from("2013-08-14")),
+                ("order_by", String::from("search_rank")),
+                ("limit", String::from("1000")),
+            ]
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn series_realtime_dates() {
+        let mut builder = Builder::new();
+        builder
+            .realtime_start_date(NaiveDate::from_ymd_opt(1999, 1, 5).unwrap())
+            .realtime_end_date(NaiveDate::from_ymd_opt(2000, 12, 31).unwrap());
+
+        assert_eq!(builder.build(), "&realtime_start=1999-01-05&realtime_end=2000-12-31");
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn series_realtime_range() {
+        let mut resp = Response::default();
+        resp.realtime_start = String::from("2013-08-14");
+        resp.realtime_end = String::from("9999-12-31");
+
+        match resp.realtime_range() {
+            Ok((start, end)) => {
+                assert_eq!(start, NaiveDate::from_ymd_opt(2013, 8, 14).unwrap());
+                assert_eq!(end, NaiveDate::from_ymd_opt(9999, 12, 31).unwrap());
+            },
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1)
+            },
+        }
+    }
+
+    #[test]
+    fn series_timeseries_helpers() {
+        let point = |date: &str, value: &str| observation::DataPoint {
+            date: String::from(date),
+            value: String::from(value),
+            ..observation::DataPoint::default()
+        };
+        let mut ts = TimeSeries::default();
+        ts.meta.id = String::from("UNRATE");
+        ts.observations.observations = vec![
+            point("2020-01-01", "3.5"),
+            point("2020-02-01", "3.6"),
+            point("2020-03-01", "."),
+        ];
+
+        assert_eq!(ts.values(), vec![Some(3.5), Some(3.6), None]);
+        assert_eq!(ts.latest().unwrap().date, "2020-02-01");
+        assert_eq!(ts.to_csv(), "date,UNRATE\n2020-01-01,3.5\n2020-02-01,3.6\n2020-03-01,\n");
+    }
+
+    #[test]
+    fn series_frequency_enum() {
+        let cases = vec![
+            ("Daily", "D", Some(observation::Frequency::D)),
+            ("Daily, Close", "D", Some(observation::Frequency::D)),
+            ("Weekly", "W", Some(observation::Frequency::W)),
+            ("Weekly, Ending Friday", "W", Some(observation::Frequency::WEF)),
+            ("Weekly, Ending Saturday", "W", Some(observation::Frequency::WESA)),
+            ("Biweekly, Ending Wednesday", "BW", Some(observation::Frequency::BWEW)),
+            ("Monthly", "M", Some(observation::Frequency::M)),
+            ("Quarterly", "Q", Some(observation::Frequency::Q)),
+            ("Semiannual", "SA", Some(observation::Frequency::SA)),
+            ("Annual", "A", Some(observation::Frequency::A)),
+            ("Monthly", "", Some(observation::Frequency::M)),
+            ("", "Q", Some(observation::Frequency::Q)),
+            ("Not Applicable", "NA", None),
+            ("", "", None),
+        ];
+
+        for (frequency, short, expected) in cases {
+            let series = Series {
+                frequency: String::from(frequency),
+                frequency_short: String::from(short),
+                ..Series::default()
+            };
+            assert_eq!(series.frequency_enum(), expected, "{} ({})", frequency, short);
+        }
+    }
+
+    #[test]
+    fn series_is_seasonally_adjusted() {
+        let cases = vec![
+            ("Seasonally Adjusted", "SA", true),
+            ("Seasonally Adjusted Annual Rate", "SAAR", true),
+            ("Smoothed Seasonally Adjusted", "SSA", true),
+            ("Not Seasonally Adjusted", "NSA", false),
+            ("Not Seasonally Adjusted Annual Rate", "NSAAR", false),
+            ("Seasonally Adjusted", "", true),
+            ("Not Seasonally Adjusted", "", false),
+            ("", "", false),
+        ];
+
+        for (adjustment, short, expected) in cases {
+            let series = Series {
+                seasonal_adjustment: String::from(adjustment),
+                seasonal_adjustment_short: String::from(short),
+                ..Series::default()
+            };
+            assert_eq!(series.is_seasonally_adjusted(), expected, "{} ({})", adjustment, short);
+        }
+    }
+
+    #[test]
+    fn series_supports_units() {
+        let mut series = Series {
+            observation_start: String::from("2020-01-01"),
+            observation_end: String::from("2020-10-01"),
+            frequency_short: String::from("Q"),
+            ..Series::default()
+        };
+        assert!(series.supports_units(&observation::Units::LIN));
+        assert!(series.supports_units(&observation::Units::PCH));
+        assert!(!series.supports_units(&observation::Units::PC1));
+
+        series.observation_end = String::from("2021-01-01");
+        assert!(series.supports_units(&observation::Units::PC1));
+
+        series.observation_end = String::from("2020-01-01");
+        assert!(!series.supports_units(&observation::Units::CHG));
+
+        series.observation_end = String::new();
+        assert!(series.supports_units(&observation::Units::CH1));
+    }
+
+    #[cfg(feature = "extra_fields")]
+    #[test]
+    fn series_extra_fields() {
+        let body = r#"{"realtime_start":"2020-01-01","realtime_end":"2020-01-01","seriess":[],"new_field":"abc"}"#;
+        let resp: Response = match serde_json::from_str(body) {
+            Ok(resp) => resp,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+
+        assert_eq!(resp.extra.get("new_field"), Some(&serde_json::Value::from("abc")));
+        assert!(resp.extra.get("realtime_start").is_none());
+    }
+
+    #[test]
+    fn series_empty() {
+        // FRED leaves the list out of some responses with no results
+        let resp: Response = match serde_json::from_str(r#"{"realtime_start":"2020-01-01","realtime_end":"2020-01-01","order_by":"series_id","sort_order":"asc","count":0,"offset":0,"limit":1000}"#) {
+            Ok(resp) => resp,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+        assert!(resp.seriess.is_empty());
+
+        let resp: Response = match serde_json::from_str(r#"{"realtime_start":"2020-01-01","realtime_end":"2020-01-01","order_by":"series_id","sort_order":"asc","count":0,"offset":0,"limit":1000,"seriess":[]}"#) {
+            Ok(resp) => resp,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+        assert!(resp.seriess.is_empty());
+    }
+
+    #[test]
+    fn series_pagination() {
+        let mut resp = Response::default();
+        resp.count = Some(3);
+        resp.offset = Some(1);
+        resp.limit = Some(1);
+        resp.seriess.push(Series::default());
+
+        let page = resp.pagination();
+        assert_eq!(page, Pagination { count: 3, offset: 1, limit: 1, returned: 1 });
+        assert!(resp.has_more());
+
+        resp.seriess.push(Series::default());
+        assert!(!resp.has_more());
+    }
+
+    #[test]
+    fn series_serialize_round_trip() {
+        let mut resp = Response::default();
+        resp.count = Some(1);
+        resp.seriess.push(Series {
+            id: String::from("GNPCA"),
+            title: String::from("Real Gross National Product"),
+            popularity: 12,
+            ..Series::default()
+        });
+
+        let json = match serde_json::to_string(&resp) {
+            Ok(json) => json,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+        let parsed: Response = match serde_json::from_str(&json) {
+            Ok(parsed) => parsed,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+        assert_eq!(parsed.count, Some(1));
+        assert_eq!(parsed.seriess[0].id, "GNPCA");
+        assert_eq!(parsed.seriess[0].title, "Real Gross National Product");
+        assert_eq!(parsed.seriess[0].popularity, 12);
+    }
+
+    #[test]
+    fn series_group_popularity_or_zero() {
+        let mut series = Series::default();
+        assert_eq!(series.group_popularity_or_zero(), 0);
+
+        series.group_popularity = Some(81);
+        assert_eq!(series.group_popularity_or_zero(), 81);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn series_parsed_dates() {
+        let series = Series {
+            realtime_start: String::from("2020-04-01"),
+            realtime_end: String::from("9999-12-31"),
+            observation_start: String::from("1929-01-01"),
+            observation_end: String::from("not a date"),
+            ..Series::default()
+        };
+        assert_eq!(series.realtime_start_parsed().ok(), NaiveDate::from_ymd_opt(2020, 4, 1));
+        assert_eq!(series.realtime_end_parsed().ok(), NaiveDate::from_ymd_opt(9999, 12, 31));
+        assert_eq!(series.observation_start_parsed().ok(), NaiveDate::from_ymd_opt(1929, 1, 1));
+        assert!(series.observation_end_parsed().is_err());
+    }
+
+    #[test]
+    fn series_dedup_by_title() {
+        let mut resp = Response::default();
+        for (id, title, popularity) in [
+            ("UNRATENSA", "Unemployment Rate", 45),
+            ("GDP", "Gross Domestic Product", 90),
+            ("UNRATE", "Unemployment  rate", 94),
+            ("GDPA", "Gross Domestic Product", 50),
+            ("CPIAUCSL", "Consumer Price Index for All Urban Consumers: All Items", 93),
+        ].iter() {
+            resp.seriess.push(Series {
+                id: String::from(*id),
+                title: String::from(*title),
+                popularity: *popularity,
+                ..Series::default()
+            });
+        }
+
+        let ids: Vec<&str> = resp.dedup_by_title().iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(ids, vec!["UNRATE", "GDP", "CPIAUCSL"]);
+    }
+}
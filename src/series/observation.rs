@@ -34,10 +34,42 @@
 //! };
 //! ```
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::fmt::{self, Display, Formatter};
+use std::collections::HashMap;
+use std::io::Write;
 
-#[derive(Deserialize, Clone, Debug, Default)]
+use crate::common::{Paged, Pagination};
+pub use crate::common::SortOrder;
+use crate::series::Series;
+use crate::error::{FredError, AGGREGATION_WITHOUT_FREQUENCY_ERROR_TEXT, LIMIT_EXCEEDED_ERROR_TEXT, OBSERVATION_PERIOD_ORDER_ERROR_TEXT, VINTAGE_REALTIME_CONFLICT_ERROR_TEXT};
+
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Utc};
+#[cfg(feature = "chrono")]
+use std::collections::BTreeMap;
+#[cfg(feature = "chrono")]
+use std::convert::TryFrom;
+
+#[cfg(feature = "rust_decimal")]
+use rust_decimal::Decimal;
+#[cfg(feature = "rust_decimal")]
+use std::str::FromStr;
+
+#[cfg(feature = "arrow")]
+use std::sync::Arc;
+#[cfg(feature = "arrow")]
+use crate::common::days_since_epoch;
+#[cfg(feature = "arrow")]
+use arrow::array::{ArrayRef, Date32Array, Float64Array};
+#[cfg(feature = "arrow")]
+use arrow::datatypes::{DataType, Field, Schema};
+#[cfg(feature = "arrow")]
+use arrow::ipc::writer::StreamWriter;
+#[cfg(feature = "arrow")]
+use arrow::record_batch::RecordBatch;
+
+#[derive(Deserialize, Serialize, Clone, Debug, Default)]
 /// Response data structure for the fred/series/observation endpoint
 /// 
 /// [https://research.stlouisfed.org/docs/api/fred/series_observations.html](https://research.stlouisfed.org/docs/api/fred/series_observations.html)
@@ -67,458 +99,2703 @@ pub struct Response {
     /// The maximum number of results requested
     pub limit: usize,
     /// The data values returned
+    #[serde(default, alias = "observation")]
     pub observations: Vec<DataPoint>,
+    /// Fields in the response that this crate does not parse yet
+    /// 
+    /// Only available with the `extra_fields` feature.
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-impl Display for Response {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+impl Response {
+    /// Returns the realtime period of the response as dates, see [series::Response::realtime_range](../struct.Response.html#method.realtime_range)
+    #[cfg(feature = "chrono")]
+    pub fn realtime_range(&self) -> Result<(NaiveDate, NaiveDate), String> {
+        crate::common::realtime_range(&self.realtime_start, &self.realtime_end)
+    }
+
+    /// Returns the request arguments FRED echoed back in the response
+    /// 
+    /// Each pair is an argument name and the value FRED used for it, including defaults for arguments the builder did not set.  Comparing these against the builder shows how FRED interpreted the request.
+    pub fn echoed_params(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("realtime_start", self.realtime_start.clone()),
+            ("realtime_end", self.realtime_end.clone()),
+            ("observation_start", self.observation_start.clone()),
+            ("observation_end", self.observation_end.clone()),
+            ("units", self.units.clone()),
+            ("output_type", self.output_type.to_string()),
+            ("order_by", self.order_by.clone()),
+            ("sort_order", self.sort_order.clone()),
+            ("limit", self.limit.to_string()),
+            ("offset", self.offset.to_string()),
+        ]
+    }
+
+    /// Writes the observations to `writer` as CSV with `date` and `value` columns
+    /// 
+    /// Missing values (".") are written as empty cells.
+    pub fn to_csv<W: Write>(&self, writer: &mut W) -> Result<(), String> {
+        match writeln!(writer, "date,value") {
+            Ok(_) => (),
+            Err(e) => return Err(e.to_string()),
+        }
         for item in self.observations.iter() {
-            match item.fmt(f) {
-                Ok(_) => (),
-                Err(e) => return Err(e),
-            }
-            match writeln!(f, "") {
+            let value = match parse_value(&item.value) {
+                Some(_) => item.value.as_str(),
+                None => "",
+            };
+            match writeln!(writer, "{},{}", item.date, value) {
                 Ok(_) => (),
-                Err(e) => return Err(e),
+                Err(e) => return Err(e.to_string()),
             }
         }
         Ok(())
     }
-}
-
-#[derive(Deserialize, Clone, Debug, Default)]
-/// A single observation datapoint
-/// 
-/// [https://research.stlouisfed.org/docs/api/fred/series_observations.html](https://research.stlouisfed.org/docs/api/fred/series_observations.html)
-pub struct DataPoint {
-    pub realtime_start: String,
-    pub realtime_end: String,
-    /// Date of the data point
-    pub date: String,
-    /// String encoded data point
-    pub value: String,
-}
 
-impl Display for DataPoint {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "({}: {})", self.date, self.value)
+    /// Writes the observations to `writer` as CSV preceded by comment lines describing the series
+    /// 
+    /// The comment lines start with `#` and give the series id, title, units, frequency and seasonal adjustment so the file can be understood without the FRED website.  Most CSV readers can skip them (e.g. `comment='#'` in pandas).  The data follows in the same format as [to_csv](#method.to_csv).
+    /// 
+    /// # Arguments
+    /// * `series` - metadata for the series, from [FredClient::series](../../client/struct.FredClient.html#method.series)
+    /// * `writer` - destination for the CSV text
+    pub fn to_csv_with_meta<W: Write>(&self, series: &Series, writer: &mut W) -> Result<(), String> {
+        let meta = [
+            ("id", &series.id),
+            ("title", &series.title),
+            ("units", &series.units),
+            ("frequency", &series.frequency),
+            ("seasonal_adjustment", &series.seasonal_adjustment),
+        ];
+        for (name, value) in meta.iter() {
+            match writeln!(writer, "# {}: {}", name, value) {
+                Ok(_) => (),
+                Err(e) => return Err(e.to_string()),
+            }
+        }
+        self.to_csv(writer)
     }
-}
 
-/// Sort order options for the fred/series/observation endpoint
-/// 
-/// [https://research.stlouisfed.org/docs/api/fred/series_observations.html#sort_order](https://research.stlouisfed.org/docs/api/fred/series_observations.html#sort_order)
-pub enum SortOrder {
-    /// Dates returned in ascending order (default)
-    Ascending,    
-    /// Dates returned in descending order
-    Descending,   
-}
+    /// Writes the observations to `writer` in the Arrow IPC streaming format
+    /// 
+    /// The record batch has a non-null `date` column (Date32) and a nullable `value` column (Float64) where missing values (".") are null.  The output can be read with pyarrow using `pyarrow.ipc.open_stream`.
+    /// 
+    /// Returns Err if an observation date is not formatted as YYYY-MM-DD or the data cannot be written.
+    #[cfg(feature = "arrow")]
+    pub fn to_arrow_ipc<W: Write>(&self, writer: W) -> Result<(), String> {
+        let mut dates = Vec::with_capacity(self.observations.len());
+        let mut values = Vec::with_capacity(self.observations.len());
+        for item in self.observations.iter() {
+            match days_since_epoch(&item.date) {
+                Some(days) => dates.push(days),
+                None => return Err(format!("Invalid observation date {}", item.date)),
+            }
+            values.push(parse_value(&item.value));
+        }
 
-/// Data transformation options for the fred/series/observation endpoint
-/// 
-/// [https://research.stlouisfed.org/docs/api/fred/series_observations.html#units](https://research.stlouisfed.org/docs/api/fred/series_observations.html#units)
-pub enum Units {
-    /// Linear: no transform applied (default)
-    LIN,
-    /// Change: returns the period over period change of the observation
-    CHG,
-    /// 1 Year Change: Returns the YoY change of the observation
-    CH1,
-    /// Percent Change: Returns the period over period percent change of the observation
-    PCH,
-    /// 1 Year Percent Change: Returns the YoY percent change of the observation
-    PC1,
-    /// Compounded Annual Rate of Change
-    PCA,
-    /// Continuously Compounded Rate of Change
-    CCH,
-    /// Continuously Compounded Annual Rate of Change
-    CCA,
-    /// Natual Log: Returns the natural logarithm of the observation
-    LOG,
-}
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("date", DataType::Date32, false),
+            Field::new("value", DataType::Float64, true),
+        ]));
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(Date32Array::from(dates)),
+            Arc::new(Float64Array::from(values)),
+        ];
+        let batch = match RecordBatch::try_new(schema.clone(), columns) {
+            Ok(batch) => batch,
+            Err(e) => return Err(e.to_string()),
+        };
 
-/// Options for data series frequency
-/// 
-/// The frequency cannot exceed the native frequency of the data series.
-/// 
-/// [https://research.stlouisfed.org/docs/api/fred/series_observations.html#frequency](https://research.stlouisfed.org/docs/api/fred/series_observations.html#frequency)
-pub enum Frequency {
-    /// Daily (fastest)
-    D,
-    /// Weekly
-    W,
-    /// Bi-Weekly
-    BW,
-    /// Monthly
-    M,
-    /// Quarterly
-    Q,
-    /// Semi-Annualy
-    SA,
-    /// Annual (slowest)
-    A,
-    /// Weekly, Ending Friday
-    WEF,    
-    /// Weekly, Ending Thursday
-    WETH,   
-    /// Weekly, Ending Wednesday
-    WEW,    
-    /// Weekly, Ending Tuesday
-    WETU,  
-    /// Weekly, Ending Monday 
-    WEM,   
-    /// Weekly, Ending Sunday 
-    WESU,  
-    /// Weekly, Ending Saturday 
-    WESA,  
-    /// Bi-Weekly, Ending Wednesday 
-    BWEW,   
-    /// Bi-Weekly, Ending Monday
-    BWEM,   
-}
+        let mut stream = match StreamWriter::try_new(writer, &schema) {
+            Ok(stream) => stream,
+            Err(e) => return Err(e.to_string()),
+        };
+        match stream.write(&batch) {
+            Ok(_) => (),
+            Err(e) => return Err(e.to_string()),
+        }
+        match stream.finish() {
+            Ok(_) => Ok(()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
 
-/// Provides an aggregation method for frequency aggregation
-/// 
-/// This argument should be used in conjunction with the frequency argument if the default aggregation method (AVG) is not preferred.
-/// 
-/// [https://research.stlouisfed.org/docs/api/fred/series_observations.html#aggregation_method](https://research.stlouisfed.org/docs/api/fred/series_observations.html#aggregation_method)
-pub enum AggregationMethod {
-    /// Average (default): intermediate datapoints are averaged to produce the aggregate
-    AVG,
-    /// Sum: intermediate datapoints are summed to produce the aggregate
-    SUM,
-    /// End of Period: The final result in the period is returned
-    EOP
-}
+    /// Returns an iterator over the observations
+    /// 
+    /// Equivalent to iterating `&response` or `response.observations.iter()`.
+    pub fn iter(&self) -> std::slice::Iter<'_, DataPoint> {
+        self.observations.iter()
+    }
 
-/// Specifies the data output type
-/// 
-/// [https://research.stlouisfed.org/docs/api/fred/series_observations.html#output_type](https://research.stlouisfed.org/docs/api/fred/series_observations.html#output_type)
-pub enum OutputType {
-    /// Observations by Real Time Period
-    RT,
-    /// Observations by Vintage Date, All Observations
-    VDALL,
-    /// Observations by Vintage Date, New and Revised Observations Only
-    VDNEW,
-    /// Observations, Initial Release Only
-    INITIAL
-}
+    /// Returns an iterator over the observation dates and their parsed values
+    /// 
+    /// Dates are left as the YYYY-MM-DD strings FRED returned and missing values (".") are None, so this works without the chrono feature.
+    pub fn iter_raw(&self) -> impl Iterator<Item = (&str, Option<f64>)> {
+        self.observations.iter().map(|item| (item.date.as_str(), parse_value(&item.value)))
+    }
 
-/// Argument builder for the fred/series/observation endpoint.
-/// 
-/// Each method adds an argument to the builder which can then be passed to the client used to fetch the data to apply the arguments.
-pub struct Builder {
-    option_string: String,
-    vintage_dates: String,
-}
+    /// Returns an iterator over the observations parsed into dates and values
+    /// 
+    /// Missing values (".") are None.  Observations whose date cannot be parsed are skipped.
+    #[cfg(feature = "chrono")]
+    pub fn iter_values(&self) -> impl Iterator<Item = (NaiveDate, Option<f64>)> + '_ {
+        self.observations
+            .iter()
+            .filter_map(|item| parse_date(&item.date).map(|date| (date, parse_value(&item.value))))
+    }
 
+    /// Returns the parsed value of each observation, in order
+    /// 
+    /// Missing values (".") are None.  See [DataPoint::value_f64](struct.DataPoint.html#method.value_f64).
+    pub fn values(&self) -> Vec<Option<f64>> {
+        self.observations.iter().map(|item| item.value_f64()).collect()
+    }
 
-impl Builder {
-    /// Initializes a new observation::Builder that can be used to add commands to an API request
+    /// Checks the observations against the metadata of their series
     /// 
-    /// The builder does not do validity checking of the arguments nor does it check for duplicates.
+    /// Returns a warning for each inconsistency found, or an empty Vec if there are none:
+    /// * the observations were requested with a units transform (e.g. pc1), so the values are not in the units of the series
+    /// * an observation date is before the observation_start or after the observation_end of the series
     /// 
-    /// ```
-    /// use fred_rs::series::observation::{Builder, Units, SortOrder};
-    /// // Create a new builder
-    /// let mut builder = Builder::new();
-    /// // add arguments to the builder
-    /// builder
-    ///     .limit(100)
-    ///     .units(Units::LOG)
-    ///     .sort_order(SortOrder::Descending);
-    /// ```
-    pub fn new() -> Builder {
-        Builder {
-            option_string: String::new(),
-            vintage_dates: String::new(),
+    /// # Arguments
+    /// * `series` - the series metadata, e.g. from [FredClient::series](../../client/struct.FredClient.html#method.series)
+    pub fn validate_against(&self, series: &Series) -> Vec<String> {
+        let mut warnings = Vec::new();
+
+        if !self.units.is_empty() && self.units != "lin" {
+            warnings.push(format!(
+                "Observations use the {} units transform so values are not in the series units ({})",
+                self.units,
+                series.units
+            ));
         }
-    }
 
-    /// Returns the current arguments as a URL formatted string
-    pub(crate) fn build(mut self) -> String {
-        if self.vintage_dates.len() > 0 {
-            self.option_string += format!("&vintage_dates={}", self.vintage_dates).as_str()
+        for item in self.observations.iter() {
+            if !series.observation_start.is_empty() && item.date < series.observation_start {
+                warnings.push(format!(
+                    "Observation {} is before the series observation_start {}",
+                    item.date,
+                    series.observation_start
+                ));
+            } else if !series.observation_end.is_empty() && item.date > series.observation_end {
+                warnings.push(format!(
+                    "Observation {} is after the series observation_end {}",
+                    item.date,
+                    series.observation_end
+                ));
+            }
         }
 
-        self.option_string
+        warnings
     }
 
-    /// Adds a realtime_start argument to the builder
-    /// 
-    /// # Arguments
-    /// * `start_date` - date formatted as YYYY-MM-DD
+    /// Returns the fraction of observations which are missing data
     /// 
-    /// [https://research.stlouisfed.org/docs/api/fred/series_observations.html#realtime_start](https://research.stlouisfed.org/docs/api/fred/series_observations.html#realtime_start)
-    pub fn realtime_start(&mut self, start_date: &str) -> &mut Builder {
-        self.option_string += format!("&realtime_start={}", start_date).as_str();
-        self
+    /// FRED marks missing observations with a value of ".".  Returns 0.0 if there are no observations.
+    pub fn missing_ratio(&self) -> f64 {
+        if self.observations.is_empty() {
+            return 0.0;
+        }
+        let missing = self.observations.iter().filter(|item| item.value == ".").count();
+        missing as f64 / self.observations.len() as f64
     }
 
-    /// Adds a realtime_end argument to the builder
+    /// Returns the observations whose value moved by more than `min_jump` from the previous observation
     /// 
-    /// # Arguments
-    /// * `end_date` - date formatted as YYYY-MM-DD
+    /// Each entry holds the observation date and the signed change from the previous value.  Missing values (".") are skipped so a change is always measured against the last observation that has data.  Observations with a date that cannot be parsed are not reported.
     /// 
-    /// [https://research.stlouisfed.org/docs/api/fred/series_observations.html#realtime_end](https://research.stlouisfed.org/docs/api/fred/series_observations.html#realtime_end)
-    pub fn realtime_end(&mut self, end_date: &str) -> &mut Builder {
-        self.option_string += format!("&realtime_end={}", end_date).as_str();
-        self
+    /// # Arguments
+    /// * `min_jump` - the absolute period over period change that must be exceeded
+    #[cfg(feature = "chrono")]
+    pub fn change_points(&self, min_jump: f64) -> Vec<(NaiveDate, f64)> {
+        let mut points = Vec::new();
+        let mut prev: Option<f64> = None;
+
+        for item in self.observations.iter() {
+            let value = match parse_value(&item.value) {
+                Some(v) => v,
+                None => continue,
+            };
+            if let Some(p) = prev {
+                let change = value - p;
+                if change.abs() > min_jump {
+                    if let Some(date) = parse_date(&item.date) {
+                        points.push((date, change));
+                    }
+                }
+            }
+            prev = Some(value);
+        }
+
+        points
     }
 
-    /// Adds a limit argument to the builder
-    /// 
-    /// The limit argument specifies a maximum number of observations to return.
+    /// Returns the simple return of each observation relative to the previous one
     /// 
-    /// # Arguments
-    /// * `num_points` - Maximum number of data points to return
+    /// Each value is `(v_t / v_{t-1}) - 1`.  The value is None for the first observation, when either observation is missing, or when the previous value is zero.  Observations with an unparseable date are left out.
+    #[cfg(feature = "chrono")]
+    pub fn simple_returns(&self) -> Vec<(NaiveDate, Option<f64>)> {
+        self.period_over_period(|prev, cur| {
+            if prev == 0.0 {
+                None
+            } else {
+                Some(cur / prev - 1.0)
+            }
+        })
+    }
+
+    /// Returns the log return of each observation relative to the previous one
     /// 
-    /// [https://research.stlouisfed.org/docs/api/fred/series_observations.html#limit](https://research.stlouisfed.org/docs/api/fred/series_observations.html#limit)
-    pub fn limit(&mut self, num_points: usize) -> &mut Builder {
-        let num_points = if num_points > 1000000 { // max value is 1000
-            1000000
-        } else {
-            num_points
-        };
-        self.option_string += format!("&limit={}", num_points).as_str();
-        self
+    /// Each value is `ln(v_t / v_{t-1})`.  The value is None for the first observation, when either observation is missing, or when the logarithm is undefined because one of the values is zero or negative.  Observations with an unparseable date are left out.
+    #[cfg(feature = "chrono")]
+    pub fn log_returns(&self) -> Vec<(NaiveDate, Option<f64>)> {
+        self.period_over_period(|prev, cur| {
+            if prev <= 0.0 || cur <= 0.0 {
+                None
+            } else {
+                Some((cur / prev).ln())
+            }
+        })
     }
 
-    /// Adds an offset argument to the builder
+    /// Returns the difference between each observation and the one `periods` observations before it
     /// 
-    /// Adding an offset shifts the starting result number.  For example, if limit is 5 and offset is 0 then results 1-5 will be returned, but if offset was 5 then results 6-10 would be returned.
+    /// Each value is `v_t - v_{t-periods}`, so `periods` of 1 gives the period over period change and 12 gives the year over year change of monthly data (like Units::CHG and Units::CH1 computed locally).  The value is None for the first `periods` observations or when either observation is missing (".").  Observations with an unparseable date are left out.
     /// 
     /// # Arguments
-    /// * `ofs` - the offset amount
-    /// 
-    /// [https://research.stlouisfed.org/docs/api/fred/series_observations.html#offset](https://research.stlouisfed.org/docs/api/fred/series_observations.html#offset)
-    pub fn offset(&mut self, ofs: usize) -> &mut Builder {
-        self.option_string += format!("&offset={}", ofs).as_str();
-        self
+    /// * `periods` - the number of observations to lag by
+    #[cfg(feature = "chrono")]
+    pub fn lag_diff(&self, periods: usize) -> Vec<(NaiveDate, Option<f64>)> {
+        let values: Vec<Option<f64>> = self.observations.iter().map(|item| parse_value(&item.value)).collect();
+        let mut result = Vec::new();
+
+        for (i, item) in self.observations.iter().enumerate() {
+            let date = match parse_date(&item.date) {
+                Some(date) => date,
+                None => continue,
+            };
+            let diff = if i < periods {
+                None
+            } else {
+                match (values[i - periods], values[i]) {
+                    (Some(prev), Some(cur)) => Some(cur - prev),
+                    _ => None,
+                }
+            };
+            result.push((date, diff));
+        }
+
+        result
     }
 
-    /// Change the sort order of the data
+    /// Returns the exponentially weighted moving average of the observations
     /// 
-    /// # Arguments
-    /// * `order` - Data sort order enum
+    /// Each average is `alpha * v_t + (1 - alpha) * ewma_{t-1}`, starting from the first value.  A larger `alpha` follows the data more closely and `alpha` of 1.0 returns the values unchanged.  Missing values (".") have no average (None) and the next value is smoothed against the last average, so gaps do not reset the series.  Observations with an unparseable date are left out.
     /// 
-    /// [https://research.stlouisfed.org/docs/api/fred/series_observations.html#sort_order](https://research.stlouisfed.org/docs/api/fred/series_observations.html#sort_order)
-    pub fn sort_order(&mut self, order: SortOrder) -> &mut Builder {
-        match order {
-            SortOrder::Descending => {
-                self.option_string += format!("&sort_order=desc").as_str()
-            },
-            _ => () // Ascending is the default so do nothing
+    /// Returns an empty Vec if `alpha` is not in the range (0, 1].
+    /// 
+    /// # Arguments
+    /// * `alpha` - the smoothing factor
+    #[cfg(feature = "chrono")]
+    pub fn ewma(&self, alpha: f64) -> Vec<(NaiveDate, Option<f64>)> {
+        if !(alpha > 0.0 && alpha <= 1.0) {
+            return Vec::new();
         }
-        self
+
+        let mut result = Vec::new();
+        let mut prev: Option<f64> = None;
+
+        for item in self.observations.iter() {
+            let average = match (prev, parse_value(&item.value)) {
+                (Some(p), Some(v)) => Some(alpha * v + (1.0 - alpha) * p),
+                (None, Some(v)) => Some(v),
+                (_, None) => None,
+            };
+            if average.is_some() {
+                prev = average;
+            }
+            if let Some(date) = parse_date(&item.date) {
+                result.push((date, average));
+            }
+        }
+
+        result
     }
 
-    /// Set the start year for data points
+    /// Returns the trailing simple moving average of the observations
     /// 
-    /// # Arguments
-    /// * `start_date` - date formatted as YYYY-MM-DD
+    /// Each average is the mean of the observation and the `window - 1` observations before it.  The average is None when the window extends past the first observation or contains a missing value (".").  Observations with an unparseable date are left out.
     /// 
-    /// [https://research.stlouisfed.org/docs/api/fred/series_observations.html#observation_start](https://research.stlouisfed.org/docs/api/fred/series_observations.html#observation_start)
-    pub fn observation_start(&mut self, start_date: &str) -> &mut Builder {
-        self.option_string += format!("&observation_start={}", start_date).as_str();
-        self
+    /// Returns an empty Vec if `window` is 0.
+    /// 
+    /// # Arguments
+    /// * `window` - the number of observations in each average
+    #[cfg(feature = "chrono")]
+    pub fn moving_average(&self, window: usize) -> Vec<(NaiveDate, Option<f64>)> {
+        self.windowed_average(window, window.saturating_sub(1))
     }
 
-    /// Set the end year for data points
+    /// Returns the centered simple moving average of the observations
+    /// 
+    /// Each average is the mean of `window` observations centered on the observation.  For an even window the extra observation is taken from after it.  The average is None when the window extends past either end of the response or contains a missing value (".").  Observations with an unparseable date are left out.
+    /// 
+    /// Returns an empty Vec if `window` is 0.
+    /// 
+    /// # Arguments
+    /// * `window` - the number of observations in each average
+    #[cfg(feature = "chrono")]
+    pub fn centered_moving_average(&self, window: usize) -> Vec<(NaiveDate, Option<f64>)> {
+        self.windowed_average(window, window.saturating_sub(1) / 2)
+    }
+
+    /// Averages `window` observations starting `before` observations ahead of each one
+    #[cfg(feature = "chrono")]
+    fn windowed_average(&self, window: usize, before: usize) -> Vec<(NaiveDate, Option<f64>)> {
+        if window == 0 {
+            return Vec::new();
+        }
+
+        let values: Vec<Option<f64>> = self.observations.iter().map(|item| parse_value(&item.value)).collect();
+        let mut result = Vec::new();
+
+        for (i, item) in self.observations.iter().enumerate() {
+            let date = match parse_date(&item.date) {
+                Some(date) => date,
+                None => continue,
+            };
+            let average = if i < before || i - before + window > values.len() {
+                None
+            } else {
+                let slice = &values[i - before..i - before + window];
+                match slice.iter().copied().collect::<Option<Vec<f64>>>() {
+                    Some(v) => Some(v.iter().sum::<f64>() / window as f64),
+                    None => None,
+                }
+            };
+            result.push((date, average));
+        }
+
+        result
+    }
+
+    /// Returns the compound annual growth rate between the first and last values
+    /// 
+    /// The rate is `(last / first)^(1 / years) - 1` where years is the time between the two observation dates, counted as days / 365.25 so partial years are included.  Missing values (".") and observations with unparseable dates are skipped when finding the first and last values.  The observations should be in ascending date order (the default).
+    /// 
+    /// Returns None if there are fewer than two values, if the dates do not span any time, or if either endpoint is zero or negative since growth between such values is undefined.
+    #[cfg(feature = "chrono")]
+    pub fn cagr(&self) -> Option<f64> {
+        let mut values = self.observations.iter().filter_map(|item| {
+            match (parse_date(&item.date), parse_value(&item.value)) {
+                (Some(date), Some(value)) => Some((date, value)),
+                _ => None,
+            }
+        });
+        let (start_date, start) = values.next()?;
+        let (end_date, end) = values.last()?;
+
+        let years = end_date.signed_duration_since(start_date).num_days() as f64 / 365.25;
+        if years <= 0.0 || start <= 0.0 || end <= 0.0 {
+            return None;
+        }
+        Some((end / start).powf(1.0 / years) - 1.0)
+    }
+
+    /// Applies `f(previous, current)` to each pair of consecutive observations
+    #[cfg(feature = "chrono")]
+    fn period_over_period<F>(&self, f: F) -> Vec<(NaiveDate, Option<f64>)>
+    where
+        F: Fn(f64, f64) -> Option<f64>,
+    {
+        let mut result = Vec::new();
+        let mut prev: Option<f64> = None;
+
+        for item in self.observations.iter() {
+            let value = parse_value(&item.value);
+            if let Some(date) = parse_date(&item.date) {
+                let change = match (prev, value) {
+                    (Some(p), Some(v)) => f(p, v),
+                    _ => None,
+                };
+                result.push((date, change));
+            }
+            prev = value;
+        }
+
+        result
+    }
+
+    /// Returns the earliest and latest observation dates present in the response
+    /// 
+    /// This is the coverage actually returned which can be narrower than the requested observation_start and observation_end when data is sparse.  Returns None if there are no observations with a parseable date.
+    #[cfg(feature = "chrono")]
+    pub fn date_range(&self) -> Option<(NaiveDate, NaiveDate)> {
+        let mut range: Option<(NaiveDate, NaiveDate)> = None;
+
+        for date in self.observations.iter().filter_map(|item| parse_date(&item.date)) {
+            range = match range {
+                Some((first, last)) => Some((first.min(date), last.max(date))),
+                None => Some((date, date)),
+            };
+        }
+
+        range
+    }
+
+    /// Places the observations on an evenly spaced date grid at the given frequency
+    /// 
+    /// The grid runs from the first to the last observation and has an entry for every period in between, holding None where the value is missing or there is no observation for that period.  Monthly, quarterly, semiannual and annual grids use the first day of each calendar period (the dates FRED uses for those frequencies).  Daily and weekly grids step from the first observation date.
+    /// 
+    /// The response is expected to already be at the given frequency.  If several observations fall in the same period the latest one is used.
+    /// 
+    /// # Arguments
+    /// * `freq` - the frequency of the grid
+    #[cfg(feature = "chrono")]
+    pub fn to_regular_grid(&self, freq: Frequency) -> Vec<(NaiveDate, Option<f64>)> {
+        let mut values: BTreeMap<NaiveDate, Option<f64>> = BTreeMap::new();
+        for item in self.observations.iter() {
+            if let Some(date) = parse_date(&item.date) {
+                values.insert(period_start(date, &freq), parse_value(&item.value));
+            }
+        }
+
+        let (first, last) = match (values.keys().next(), values.keys().next_back()) {
+            (Some(first), Some(last)) => (*first, *last),
+            _ => return Vec::new(),
+        };
+
+        let mut grid = Vec::new();
+        let mut date = first;
+        while date <= last {
+            grid.push((date, values.get(&date).and_then(|v| *v)));
+            date = next_period(date, &freq);
+        }
+
+        grid
+    }
+
+    /// Groups the observations into periods of the target frequency and combines each group with the aggregation method
+    /// 
+    /// Observations finer than the target are aggregated while coarser observations are left as they are, each landing in the period containing its date.  Missing values are ignored and a period with no values is None.
+    /// 
+    /// Only periods containing at least one observation are returned, use [to_regular_grid](#method.to_regular_grid) to fill the gaps.  Monthly and longer periods follow calendar boundaries (e.g. a quarter runs from January through March) and are dated on their first day, daily and weekly periods step from the first observation date.
+    /// 
+    /// # Arguments
+    /// * `target` - the frequency to convert to
+    /// * `method` - how to combine the observations in each period
+    #[cfg(feature = "chrono")]
+    pub fn resample(&self, target: Frequency, method: AggregationMethod) -> Vec<(NaiveDate, Option<f64>)> {
+        let points = self.dated_values();
+
+        let anchor = match points.first() {
+            Some((date, _)) => *date,
+            None => return Vec::new(),
+        };
+
+        let mut periods: BTreeMap<NaiveDate, Vec<f64>> = BTreeMap::new();
+        for (date, value) in points {
+            let values = periods.entry(period_containing(date, anchor, &target)).or_default();
+            if let Some(v) = value {
+                values.push(v);
+            }
+        }
+
+        periods.into_iter()
+            .map(|(date, values)| (date, aggregate(&values, &method)))
+            .collect()
+    }
+
+    /// Converts the observations to calendar months, see [resample](#method.resample)
+    #[cfg(feature = "chrono")]
+    pub fn to_monthly(&self, method: AggregationMethod) -> Vec<(NaiveDate, Option<f64>)> {
+        self.resample(Frequency::M, method)
+    }
+
+    /// Converts the observations to calendar quarters (Jan-Mar, Apr-Jun, Jul-Sep, Oct-Dec), see [resample](#method.resample)
+    #[cfg(feature = "chrono")]
+    pub fn to_quarterly(&self, method: AggregationMethod) -> Vec<(NaiveDate, Option<f64>)> {
+        self.resample(Frequency::Q, method)
+    }
+
+    /// Converts the observations to calendar years, see [resample](#method.resample)
+    #[cfg(feature = "chrono")]
+    pub fn to_annual(&self, method: AggregationMethod) -> Vec<(NaiveDate, Option<f64>)> {
+        self.resample(Frequency::A, method)
+    }
+
+    /// Converts a series whose native frequency changed over its history to a single frequency
+    /// 
+    /// Segments are found from the spacing of the observations: an observation closer than one target period to either of its neighbours is part of a finer segment, anything else is at the target frequency or coarser.  Finer segments are aggregated into target periods as in [resample](#method.resample), with daily and weekly periods counted from the first finer observation.  Observations of coarser segments are returned as they are, on their own dates, rather than spread across target periods.
+    /// 
+    /// This is a best-effort conversion.  Gaps in a finer segment longer than a target period split it, and the observations around such a gap are passed through unaggregated.
+    /// 
+    /// # Arguments
+    /// * `target` - the frequency to convert to
+    /// * `method` - how to combine the observations in each period
+    #[cfg(feature = "chrono")]
+    pub fn unify_frequency(&self, target: Frequency, method: AggregationMethod) -> Vec<(NaiveDate, Option<f64>)> {
+        let points = self.dated_values();
+        let min_days = min_period_days(&target);
+
+        // the spacing of an observation is the smaller gap to its neighbours so the first and last observations of a finer segment are not taken as coarser
+        let gaps: Vec<i64> = points.windows(2)
+            .map(|pair| pair[1].0.signed_duration_since(pair[0].0).num_days())
+            .collect();
+        let spacing = |i: usize| match (i.checked_sub(1).and_then(|j| gaps.get(j)), gaps.get(i)) {
+            (Some(before), Some(after)) => *before.min(after),
+            (Some(gap), None) | (None, Some(gap)) => *gap,
+            (None, None) => min_days,
+        };
+
+        let anchor = match (0..points.len()).find(|i| spacing(*i) < min_days) {
+            Some(i) => points[i].0,
+            None => return points,
+        };
+
+        let mut unified: BTreeMap<NaiveDate, Option<f64>> = BTreeMap::new();
+        let mut periods: BTreeMap<NaiveDate, Vec<f64>> = BTreeMap::new();
+        for (i, (date, value)) in points.iter().enumerate() {
+            if spacing(i) >= min_days {
+                unified.insert(*date, *value);
+                continue;
+            }
+            let values = periods.entry(period_containing(*date, anchor, &target)).or_default();
+            if let Some(v) = value {
+                values.push(*v);
+            }
+        }
+        for (date, values) in periods {
+            unified.insert(date, aggregate(&values, &method));
+        }
+
+        unified.into_iter().collect()
+    }
+
+    /// Returns the observations with parseable dates in date order, paired with their values
+    #[cfg(feature = "chrono")]
+    fn dated_values(&self) -> Vec<(NaiveDate, Option<f64>)> {
+        let mut points: Vec<(NaiveDate, Option<f64>)> = self.observations.iter()
+            .filter_map(|item| match parse_date(&item.date) {
+                Some(date) => Some((date, parse_value(&item.value))),
+                None => None,
+            })
+            .collect();
+        points.sort_by_key(|(date, _)| *date);
+        points
+    }
+
+    /// Returns the revision history of a single observation date
+    /// 
+    /// Each entry is the realtime_start of a vintage and the value the observation held in it, in chronological order.  This is most useful with responses requested using vintage dates or an OutputType other than RT (see [revision_table](#method.revision_table) for every date at once).  Missing values are left out.
+    /// 
+    /// # Arguments
+    /// * `date` - the observation date formatted as YYYY-MM-DD
+    pub fn revisions_for(&self, date: &str) -> Vec<(String, f64)> {
+        let date = crate::common::date_arg(date);
+        let mut revisions: Vec<(String, f64)> = self.observations.iter()
+            .filter(|item| item.date == date)
+            .filter_map(|item| match parse_value(&item.value) {
+                Some(value) => Some((item.realtime_start.clone(), value)),
+                None => None,
+            })
+            .collect();
+        revisions.sort_by(|a, b| a.0.cmp(&b.0));
+        revisions
+    }
+
+    /// Groups the observations of a vintage response by observation date
+    /// 
+    /// Maps each observation date to the values it held in each vintage, keyed by the realtime_start of the vintage.  This is most useful with responses requested using vintage dates or an OutputType other than RT, where an observation date appears once for every revision.  Missing values and observations with unparseable dates are left out.
+    #[cfg(feature = "chrono")]
+    pub fn revision_table(&self) -> BTreeMap<NaiveDate, BTreeMap<NaiveDate, f64>> {
+        let mut table: BTreeMap<NaiveDate, BTreeMap<NaiveDate, f64>> = BTreeMap::new();
+
+        for item in self.observations.iter() {
+            let (date, realtime_start, value) = match (
+                parse_date(&item.date),
+                parse_date(&item.realtime_start),
+                parse_value(&item.value),
+            ) {
+                (Some(date), Some(realtime_start), Some(value)) => (date, realtime_start, value),
+                _ => continue,
+            };
+            table.entry(date).or_default().insert(realtime_start, value);
+        }
+
+        table
+    }
+}
+
+impl Paged for Response {
+    fn pagination(&self) -> Pagination {
+        Pagination {
+            count: self.count,
+            offset: self.offset,
+            limit: self.limit,
+            returned: self.observations.len(),
+        }
+    }
+}
+
+impl Display for Response {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for item in self.observations.iter() {
+            match item.fmt(f) {
+                Ok(_) => (),
+                Err(e) => return Err(e),
+            }
+            match writeln!(f, "") {
+                Ok(_) => (),
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug, Default)]
+/// A single observation datapoint
+/// 
+/// [https://research.stlouisfed.org/docs/api/fred/series_observations.html](https://research.stlouisfed.org/docs/api/fred/series_observations.html)
+pub struct DataPoint {
+    pub realtime_start: String,
+    pub realtime_end: String,
+    /// Date of the data point
+    pub date: String,
+    /// String encoded data point
+    pub value: String,
+}
+
+impl DataPoint {
+    /// Parses the value as a floating point number
+    /// 
+    /// Returns None for missing values (".") or values that cannot be parsed.
+    pub fn value_f64(&self) -> Option<f64> {
+        parse_value(&self.value)
+    }
+
+    /// Parses the date of the data point
+    /// 
+    /// Returns Err if the date is not formatted as YYYY-MM-DD.
+    #[cfg(feature = "chrono")]
+    pub fn date_parsed(&self) -> Result<NaiveDate, chrono::ParseError> {
+        NaiveDate::parse_from_str(&self.date, "%Y-%m-%d")
+    }
+
+    /// Parses the value exactly as a decimal number
+    /// 
+    /// Unlike an f64, the decimal keeps every digit FRED returned so rates and currency amounts can be used in exact arithmetic.  Returns None for missing values (".") or values that cannot be parsed.
+    #[cfg(feature = "rust_decimal")]
+    pub fn value_decimal(&self) -> Option<Decimal> {
+        Decimal::from_str(&self.value).ok()
+    }
+}
+
+impl IntoIterator for Response {
+    type Item = DataPoint;
+    type IntoIter = std::vec::IntoIter<DataPoint>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.observations.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Response {
+    type Item = &'a DataPoint;
+    type IntoIter = std::slice::Iter<'a, DataPoint>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.observations.iter()
+    }
+}
+
+impl Display for DataPoint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "({}: {})", self.date, self.value)
+    }
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+/// Observations returned by FredClient::series_observation_with_fallback
+/// 
+/// Some units transforms (e.g. PC1) need more history than a series has, in which case FRED answers with a missing value (".") for every observation.  The client then repeats the request with Units::LIN and sets `fell_back` so the substitution is not silent.
+pub struct FallbackResponse {
+    /// The observations that were ultimately returned
+    pub response: Response,
+    /// The units used for the returned observations (e.g. lin, pc1)
+    pub units: String,
+    /// True if the requested units returned only missing values and the request was repeated with Units::LIN
+    pub fell_back: bool,
+}
+
+#[cfg(feature = "chrono")]
+#[derive(Clone, Debug, Default, PartialEq)]
+/// Several series aligned on a common set of dates
+/// 
+/// Returned by [FredClient::build_panel](../../client/struct.FredClient.html#method.build_panel).  Each column has one value per entry in `dates`, with None where the series has no value for that date.
+pub struct Panel {
+    /// The dates of the rows in ascending order
+    pub dates: Vec<NaiveDate>,
+    /// The series id and values of each column
+    pub columns: Vec<(String, Vec<Option<f64>>)>,
+}
+
+#[cfg(feature = "chrono")]
+impl Panel {
+    /// Aligns dated values from several series into a panel
+    /// 
+    /// The rows are every date appearing in any column.  The series should already share a frequency (e.g. using [Response::resample](struct.Response.html#method.resample)) or few dates will line up.
+    /// 
+    /// # Arguments
+    /// * `columns` - the series id and dated values of each column
+    pub fn align(columns: Vec<(String, Vec<(NaiveDate, Option<f64>)>)>) -> Panel {
+        let mut dates: Vec<NaiveDate> = columns.iter()
+            .flat_map(|(_, values)| values.iter().map(|(date, _)| *date))
+            .collect();
+        dates.sort();
+        dates.dedup();
+
+        let columns = columns.into_iter()
+            .map(|(id, values)| {
+                let by_date: BTreeMap<NaiveDate, Option<f64>> = values.into_iter().collect();
+                let aligned = dates.iter()
+                    .map(|date| match by_date.get(date) {
+                        Some(value) => *value,
+                        None => None,
+                    })
+                    .collect();
+                (id, aligned)
+            })
+            .collect();
+
+        Panel { dates, columns }
+    }
+}
+
+/// Returns the Pearson correlation of two series of observations
+/// 
+/// The observations are aligned by date and only dates with a value in both responses are used; missing values (".") and dates present in only one response are dropped.  Both responses should use the same frequency, otherwise few or no dates will line up.
+/// 
+/// Returns None if fewer than two dates overlap or if either series is constant over the overlapping dates.
+/// 
+/// ```
+/// use fred_rs::series::observation::{correlation, Response};
+/// 
+/// let a = Response::default();
+/// let b = Response::default();
+/// assert_eq!(correlation(&a, &b), None);
+/// ```
+pub fn correlation(a: &Response, b: &Response) -> Option<f64> {
+    let a_values: HashMap<&str, f64> = a.iter_raw()
+        .filter_map(|(date, value)| value.map(|v| (date, v)))
+        .collect();
+    let pairs: Vec<(f64, f64)> = b.iter_raw()
+        .filter_map(|(date, value)| match (a_values.get(date), value) {
+            (Some(x), Some(y)) => Some((*x, y)),
+            _ => None,
+        })
+        .collect();
+    if pairs.len() < 2 {
+        return None;
+    }
+
+    let n = pairs.len() as f64;
+    let mean_x = pairs.iter().map(|(x, _)| x).sum::<f64>() / n;
+    let mean_y = pairs.iter().map(|(_, y)| y).sum::<f64>() / n;
+    let mut cov = 0.0;
+    let mut var_x = 0.0;
+    let mut var_y = 0.0;
+    for (x, y) in pairs.iter() {
+        cov += (x - mean_x) * (y - mean_y);
+        var_x += (x - mean_x) * (x - mean_x);
+        var_y += (y - mean_y) * (y - mean_y);
+    }
+    if var_x == 0.0 || var_y == 0.0 {
+        return None;
+    }
+    Some(cov / (var_x * var_y).sqrt())
+}
+
+/// Parses an observation value, returning None for missing data (".")
+pub(crate) fn parse_value(value: &str) -> Option<f64> {
+    value.parse::<f64>().ok()
+}
+
+/// Checks that a date is formatted as YYYY-MM-DD
+fn is_date(date: &str) -> bool {
+    let bytes = date.as_bytes();
+    bytes.len() == 10
+        && bytes.iter().enumerate().all(|(i, b)| match i {
+            4 | 7 => *b == b'-',
+            _ => b.is_ascii_digit(),
+        })
+}
+
+/// Parses a date formatted as YYYY-MM-DD
+#[cfg(feature = "chrono")]
+fn parse_date(date: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()
+}
+
+/// Returns the date `months` calendar months before `date`
+/// 
+/// The day of the month is clamped to the length of the resulting month (e.g. one month before March 31st is February 28th or 29th).  A result earlier than chrono can represent is clamped to NaiveDate::MIN.
+#[cfg(feature = "chrono")]
+fn months_before(date: NaiveDate, months: i64) -> NaiveDate {
+    let total = i64::from(date.year()) * 12 + i64::from(date.month0()) - months;
+    let year = match i32::try_from(total.div_euclid(12)) {
+        Ok(year) if year >= NaiveDate::MIN.year() => year,
+        _ => return NaiveDate::MIN,
+    };
+    let month = total.rem_euclid(12) as u32 + 1;
+    let mut day = date.day();
+    loop {
+        match NaiveDate::from_ymd_opt(year, month, day) {
+            Some(start) => return start,
+            None if day > 1 => day -= 1,
+            None => return NaiveDate::MIN,
+        }
+    }
+}
+
+/// Length of a single period of a frequency
+#[cfg(feature = "chrono")]
+enum PeriodLength {
+    Days(i64),
+    Months(u32),
+}
+
+#[cfg(feature = "chrono")]
+fn period_length(freq: &Frequency) -> PeriodLength {
+    match freq {
+        Frequency::D => PeriodLength::Days(1),
+        Frequency::W
+        | Frequency::WEF
+        | Frequency::WETH
+        | Frequency::WEW
+        | Frequency::WETU
+        | Frequency::WEM
+        | Frequency::WESU
+        | Frequency::WESA => PeriodLength::Days(7),
+        Frequency::BW
+        | Frequency::BWEW
+        | Frequency::BWEM => PeriodLength::Days(14),
+        Frequency::M => PeriodLength::Months(1),
+        Frequency::Q => PeriodLength::Months(3),
+        Frequency::SA => PeriodLength::Months(6),
+        Frequency::A => PeriodLength::Months(12),
+    }
+}
+
+/// Returns the fewest days a period of `freq` can span
+/// 
+/// Observations spaced at least this far apart are at `freq` or a coarser frequency.
+#[cfg(feature = "chrono")]
+fn min_period_days(freq: &Frequency) -> i64 {
+    match period_length(freq) {
+        PeriodLength::Days(n) => n,
+        PeriodLength::Months(n) => 28 * i64::from(n),
+    }
+}
+
+/// Returns the first day of the calendar period containing `date`
+/// 
+/// Daily and weekly periods are not tied to the calendar so the date is returned unchanged.
+#[cfg(feature = "chrono")]
+fn period_start(date: NaiveDate, freq: &Frequency) -> NaiveDate {
+    match period_length(freq) {
+        PeriodLength::Days(_) => date,
+        PeriodLength::Months(n) => {
+            let month0 = (date.month0() / n) * n;
+            NaiveDate::from_ymd_opt(date.year(), month0 + 1, 1).unwrap()
+        },
+    }
+}
+
+/// Returns the start of the period containing `date`
+/// 
+/// Calendar periods start on the first day of the period and daily or weekly periods are counted from `anchor`.
+#[cfg(feature = "chrono")]
+fn period_containing(date: NaiveDate, anchor: NaiveDate, freq: &Frequency) -> NaiveDate {
+    match period_length(freq) {
+        PeriodLength::Days(n) => {
+            let days = date.signed_duration_since(anchor).num_days();
+            anchor + Duration::days(days.div_euclid(n) * n)
+        },
+        PeriodLength::Months(_) => period_start(date, freq),
+    }
+}
+
+/// Combines the values of a period, returning None if there are no values
+#[cfg(feature = "chrono")]
+fn aggregate(values: &[f64], method: &AggregationMethod) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    match method {
+        AggregationMethod::AVG => Some(values.iter().sum::<f64>() / values.len() as f64),
+        AggregationMethod::SUM => Some(values.iter().sum()),
+        AggregationMethod::EOP => values.last().copied(),
+    }
+}
+
+/// Returns the start of the period following the one starting on `date`
+#[cfg(feature = "chrono")]
+fn next_period(date: NaiveDate, freq: &Frequency) -> NaiveDate {
+    match period_length(freq) {
+        PeriodLength::Days(n) => date + Duration::days(n),
+        PeriodLength::Months(n) => {
+            let months = date.year() * 12 + date.month0() as i32 + n as i32;
+            NaiveDate::from_ymd_opt(months.div_euclid(12), months.rem_euclid(12) as u32 + 1, 1).unwrap()
+        },
+    }
+}
+
+/// Data transformation options for the fred/series/observation endpoint
+/// 
+/// [https://research.stlouisfed.org/docs/api/fred/series_observations.html#units](https://research.stlouisfed.org/docs/api/fred/series_observations.html#units)
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Units {
+    /// Linear: no transform applied (default)
+    LIN,
+    /// Change: returns the period over period change of the observation
+    CHG,
+    /// 1 Year Change: Returns the YoY change of the observation
+    CH1,
+    /// Percent Change: Returns the period over period percent change of the observation
+    PCH,
+    /// 1 Year Percent Change: Returns the YoY percent change of the observation
+    PC1,
+    /// Compounded Annual Rate of Change
+    PCA,
+    /// Continuously Compounded Rate of Change
+    CCH,
+    /// Continuously Compounded Annual Rate of Change
+    CCA,
+    /// Natual Log: Returns the natural logarithm of the observation
+    LOG,
+}
+
+/// Options for data series frequency
+/// 
+/// The frequency cannot exceed the native frequency of the data series.
+/// 
+/// [https://research.stlouisfed.org/docs/api/fred/series_observations.html#frequency](https://research.stlouisfed.org/docs/api/fred/series_observations.html#frequency)
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Frequency {
+    /// Daily (fastest)
+    D,
+    /// Weekly
+    W,
+    /// Bi-Weekly
+    BW,
+    /// Monthly
+    M,
+    /// Quarterly
+    Q,
+    /// Semi-Annualy
+    SA,
+    /// Annual (slowest)
+    A,
+    /// Weekly, Ending Friday
+    WEF,    
+    /// Weekly, Ending Thursday
+    WETH,   
+    /// Weekly, Ending Wednesday
+    WEW,    
+    /// Weekly, Ending Tuesday
+    WETU,  
+    /// Weekly, Ending Monday 
+    WEM,   
+    /// Weekly, Ending Sunday 
+    WESU,  
+    /// Weekly, Ending Saturday 
+    WESA,  
+    /// Bi-Weekly, Ending Wednesday 
+    BWEW,   
+    /// Bi-Weekly, Ending Monday
+    BWEM,   
+}
+
+impl Frequency {
+    /// Returns the frequency as FRED names it in series metadata (e.g. "Monthly" or "Weekly, Ending Friday")
+    /// 
+    /// This is the value used to filter series by frequency, for example with [category::series::Builder::frequency_filter](../../category/series/struct.Builder.html#method.frequency_filter).
+    pub fn name(&self) -> &'static str {
+        match self {
+            Frequency::D => "Daily",
+            Frequency::W => "Weekly",
+            Frequency::BW => "Biweekly",
+            Frequency::M => "Monthly",
+            Frequency::Q => "Quarterly",
+            Frequency::SA => "Semiannual",
+            Frequency::A => "Annual",
+            Frequency::WEF => "Weekly, Ending Friday",
+            Frequency::WETH => "Weekly, Ending Thursday",
+            Frequency::WEW => "Weekly, Ending Wednesday",
+            Frequency::WETU => "Weekly, Ending Tuesday",
+            Frequency::WEM => "Weekly, Ending Monday",
+            Frequency::WESU => "Weekly, Ending Sunday",
+            Frequency::WESA => "Weekly, Ending Saturday",
+            Frequency::BWEW => "Biweekly, Ending Wednesday",
+            Frequency::BWEM => "Biweekly, Ending Monday",
+        }
+    }
+}
+
+/// Provides an aggregation method for frequency aggregation
+/// 
+/// This argument should be used in conjunction with the frequency argument if the default aggregation method (AVG) is not preferred.
+/// 
+/// [https://research.stlouisfed.org/docs/api/fred/series_observations.html#aggregation_method](https://research.stlouisfed.org/docs/api/fred/series_observations.html#aggregation_method)
+#[derive(Deserialize, Serialize, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum AggregationMethod {
+    /// Average (default): intermediate datapoints are averaged to produce the aggregate
+    AVG,
+    /// Sum: intermediate datapoints are summed to produce the aggregate
+    SUM,
+    /// End of Period: The final result in the period is returned
+    EOP
+}
+
+/// Specifies the data output type
+/// 
+/// [https://research.stlouisfed.org/docs/api/fred/series_observations.html#output_type](https://research.stlouisfed.org/docs/api/fred/series_observations.html#output_type)
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputType {
+    /// Observations by Real Time Period
+    RT,
+    /// Observations by Vintage Date, All Observations
+    VDALL,
+    /// Observations by Vintage Date, New and Revised Observations Only
+    VDNEW,
+    /// Observations, Initial Release Only
+    INITIAL
+}
+
+#[derive(Deserialize, Serialize, Default)]
+/// Declarative description of a fred/series/observation request
+/// 
+/// Query definitions can be loaded from a configuration file (JSON or any other format supported by serde) and converted into a [Builder](struct.Builder.html).  Every field is optional and the enum values use the same names as the FRED API (e.g. `"units": "pch"`, `"frequency": "q"`, `"sort_order": "desc"`).
+/// 
+/// ```
+/// use fred_rs::series::observation::{Builder, ObservationQuery};
+/// 
+/// let query: ObservationQuery = serde_json::from_str(
+///     r#"{"observation_start": "2000-01-01", "units": "pch", "frequency": "q"}"#
+/// ).unwrap();
+/// let builder = Builder::from(query);
+/// ```
+pub struct ObservationQuery {
+    pub realtime_start: Option<String>,
+    pub realtime_end: Option<String>,
+    pub limit: Option<usize>,
+    pub offset: Option<usize>,
+    pub sort_order: Option<SortOrder>,
+    pub observation_start: Option<String>,
+    pub observation_end: Option<String>,
+    pub units: Option<Units>,
+    pub frequency: Option<Frequency>,
+    pub aggregation_method: Option<AggregationMethod>,
+    pub output_type: Option<OutputType>,
+    pub vintage_dates: Option<Vec<String>>,
+}
+
+impl From<ObservationQuery> for Builder {
+    fn from(query: ObservationQuery) -> Builder {
+        let mut builder = Builder::new();
+        if let Some(date) = query.realtime_start {
+            builder.realtime_start(date.as_str());
+        }
+        if let Some(date) = query.realtime_end {
+            builder.realtime_end(date.as_str());
+        }
+        if let Some(num_points) = query.limit {
+            builder.limit(num_points);
+        }
+        if let Some(ofs) = query.offset {
+            builder.offset(ofs);
+        }
+        if let Some(order) = query.sort_order {
+            builder.sort_order(order);
+        }
+        if let Some(date) = query.observation_start {
+            builder.observation_start(date.as_str());
+        }
+        if let Some(date) = query.observation_end {
+            builder.observation_end(date.as_str());
+        }
+        if let Some(units) = query.units {
+            builder.units(units);
+        }
+        if let Some(freq) = query.frequency {
+            builder.frequency(freq);
+        }
+        if let Some(method) = query.aggregation_method {
+            builder.aggregation_method(method);
+        }
+        if let Some(otype) = query.output_type {
+            builder.output_type(otype);
+        }
+        if let Some(dates) = query.vintage_dates {
+            for date in dates.iter() {
+                builder.vintage_date(date.as_str());
+            }
+        }
+        builder
+    }
+}
+
+#[derive(Clone)]
+/// Argument builder for the fred/series/observation endpoint.
+/// 
+/// Each method adds an argument to the builder which can then be passed to the client used to fetch the data to apply the arguments.
+pub struct Builder {
+    option_string: String,
+    vintage_dates: String,
+    realtime_set: bool,
+    frequency_set: bool,
+    aggregation_set: bool,
+    observation_start: Option<String>,
+    observation_end: Option<String>,
+    all: bool,
+}
+
+
+impl Builder {
+    /// Initializes a new observation::Builder that can be used to add commands to an API request
+    /// 
+    /// The builder does not do validity checking of the arguments nor does it check for duplicates.
+    /// 
+    /// ```
+    /// use fred_rs::series::observation::{Builder, Units, SortOrder};
+    /// // Create a new builder
+    /// let mut builder = Builder::new();
+    /// // add arguments to the builder
+    /// builder
+    ///     .limit(100)
+    ///     .units(Units::LOG)
+    ///     .sort_order(SortOrder::Descending);
+    /// ```
+    pub fn new() -> Builder {
+        Builder {
+            option_string: String::new(),
+            vintage_dates: String::new(),
+            realtime_set: false,
+            frequency_set: false,
+            aggregation_set: false,
+            observation_start: None,
+            observation_end: None,
+            all: false,
+        }
+    }
+
+    /// Removes all arguments from the builder so it can be reused
+    pub fn clear(&mut self) -> &mut Builder {
+        *self = Builder::new();
+        self
+    }
+
+    /// Returns the current arguments as a URL formatted string without consuming the builder
+    /// 
+    /// Returns Err if vintage dates are combined with realtime_start or realtime_end.  FRED ignores the realtime period when vintage dates are given so the combination is rejected rather than silently returning unexpected data.
+    /// 
+    /// Also returns Err if observation_start is after observation_end, which FRED would otherwise answer with an empty response, if an aggregation_method is set without a frequency, which FRED ignores, or if a date argument is not a valid date.
+    pub fn peek(&self) -> Result<String, String> {
+        let mut options = self.option_string.clone();
+        match crate::common::check_date_args(&options) {
+            Ok(()) => (),
+            Err(e) => return Err(e.to_string()),
+        }
+        if self.aggregation_set && !self.frequency_set {
+            return Err(String::from(AGGREGATION_WITHOUT_FREQUENCY_ERROR_TEXT));
+        }
+        if let (Some(start), Some(end)) = (&self.observation_start, &self.observation_end) {
+            // YYYY-MM-DD dates order correctly as strings
+            if is_date(start) && is_date(end) && start > end {
+                return Err(String::from(OBSERVATION_PERIOD_ORDER_ERROR_TEXT));
+            }
+        }
+        if self.vintage_dates.len() > 0 {
+            if self.realtime_set {
+                return Err(String::from(VINTAGE_REALTIME_CONFLICT_ERROR_TEXT));
+            }
+            options += format!("&vintage_dates={}", self.vintage_dates).as_str();
+            match crate::common::check_date_args(&options) {
+                Ok(()) => (),
+                Err(e) => return Err(e.to_string()),
+            }
+        }
+
+        Ok(options)
+    }
+
+    /// Returns the current arguments as a URL formatted string
+    pub(crate) fn build(self) -> Result<String, String> {
+        self.peek()
+    }
+
+    /// Consumes the builder and returns its arguments as a URL formatted string
+    /// 
+    /// This is the query string the client appends to the request URL, which can be logged or checked in tests without sending a request.  Returns Err if the arguments are invalid, as the client would.
+    pub fn into_query(self) -> Result<String, String> {
+        self.build()
+    }
+
+    /// Returns true if the client should request every page of observations
+    pub(crate) fn is_all(&self) -> bool {
+        self.all
+    }
+
+    /// Returns a copy of the builder without its units transform, or None if no transform was requested
+    pub(crate) fn without_units(&self) -> Option<Builder> {
+        if !self.option_string.contains("&units=") {
+            return None;
+        }
+        Some(self.copy_without_units())
+    }
+
+    /// Returns a copy of the builder with its units transform replaced by `units`
+    pub(crate) fn with_units(&self, units: Units) -> Builder {
+        let mut builder = self.copy_without_units();
+        builder.units(units);
+        builder
+    }
+
+    /// Returns a copy of the builder with any units argument removed
+    fn copy_without_units(&self) -> Builder {
+        Builder {
+            option_string: self.option_string
+                .split('&')
+                .filter(|arg| !arg.is_empty() && !arg.starts_with("units="))
+                .map(|arg| format!("&{}", arg))
+                .collect(),
+            vintage_dates: self.vintage_dates.clone(),
+            realtime_set: self.realtime_set,
+            frequency_set: self.frequency_set,
+            aggregation_set: self.aggregation_set,
+            observation_start: self.observation_start.clone(),
+            observation_end: self.observation_end.clone(),
+            all: self.all,
+        }
+    }
+
+    /// Adds a realtime_start argument to the builder
+    /// 
+    /// # Arguments
+    /// * `start_date` - date formatted as YYYY-MM-DD
+    /// 
+    /// [https://research.stlouisfed.org/docs/api/fred/series_observations.html#realtime_start](https://research.stlouisfed.org/docs/api/fred/series_observations.html#realtime_start)
+    pub fn realtime_start(&mut self, start_date: &str) -> &mut Builder {
+        self.option_string += format!("&realtime_start={}", crate::common::date_arg(start_date)).as_str();
+        self.realtime_set = true;
+        self
+    }
+
+    /// Adds a realtime_end argument to the builder
+    /// 
+    /// # Arguments
+    /// * `end_date` - date formatted as YYYY-MM-DD
+    /// 
+    /// [https://research.stlouisfed.org/docs/api/fred/series_observations.html#realtime_end](https://research.stlouisfed.org/docs/api/fred/series_observations.html#realtime_end)
+    pub fn realtime_end(&mut self, end_date: &str) -> &mut Builder {
+        self.option_string += format!("&realtime_end={}", crate::common::date_arg(end_date)).as_str();
+        self.realtime_set = true;
+        self
+    }
+
+    realtime_period!();
+
+    /// Adds a realtime_start argument to the builder from a date
+    /// 
+    /// # Arguments
+    /// * `start_date` - the first day of the real-time period
+    /// 
+    /// [https://research.stlouisfed.org/docs/api/fred/series_observations.html#realtime_start](https://research.stlouisfed.org/docs/api/fred/series_observations.html#realtime_start)
+    #[cfg(feature = "chrono")]
+    pub fn realtime_start_date(&mut self, start_date: NaiveDate) -> &mut Builder {
+        self.realtime_start(start_date.format("%Y-%m-%d").to_string().as_str())
+    }
+
+    /// Adds a realtime_end argument to the builder from a date
+    /// 
+    /// # Arguments
+    /// * `end_date` - the last day of the real-time period
+    /// 
+    /// [https://research.stlouisfed.org/docs/api/fred/series_observations.html#realtime_end](https://research.stlouisfed.org/docs/api/fred/series_observations.html#realtime_end)
+    #[cfg(feature = "chrono")]
+    pub fn realtime_end_date(&mut self, end_date: NaiveDate) -> &mut Builder {
+        self.realtime_end(end_date.format("%Y-%m-%d").to_string().as_str())
+    }
+
+    /// Pins the realtime period to today so the current vintage is requested
+    /// 
+    /// FRED already returns the current vintage when no realtime period is given, but it resolves "today" when the request is made.  Setting realtime_start and realtime_end to an explicit date makes the request reproducible: the arguments can be saved (see [peek](#method.peek)) and re-running them later returns this same snapshot even after the series is revised.
+    /// 
+    /// Today is the current date from the system clock in UTC, so near midnight it can differ from the local date by one day.
+    /// 
+    /// [https://research.stlouisfed.org/docs/api/fred/realtime_period.html](https://research.stlouisfed.org/docs/api/fred/realtime_period.html)
+    #[cfg(feature = "chrono")]
+    pub fn current_vintage(&mut self) -> &mut Builder {
+        let today = Utc::now().date_naive().to_string();
+        self.realtime_start(today.as_str());
+        self.realtime_end(today.as_str())
+    }
+
+    /// Pins the realtime period to the vintage in effect at a point in time
+    /// 
+    /// FRED's realtime period only has a resolution of days, so the time of day is dropped and realtime_start and realtime_end are both set to the date of `dt` in its own time zone.  Data revised later on that same day is included, so this cannot separate two releases on one day (such as the timestamps from [series/updates](../updates/index.html)).  Convert `dt` to the desired time zone first if the date should be taken elsewhere.
+    /// 
+    /// # Arguments
+    /// * `dt` - the point in time of the vintage
+    /// 
+    /// [https://research.stlouisfed.org/docs/api/fred/realtime_period.html](https://research.stlouisfed.org/docs/api/fred/realtime_period.html)
+    #[cfg(feature = "chrono")]
+    pub fn as_of_datetime<Tz: TimeZone>(&mut self, dt: DateTime<Tz>) -> &mut Builder {
+        let date = dt.date_naive().to_string();
+        self.realtime_start(date.as_str());
+        self.realtime_end(date.as_str())
+    }
+
+    /// Adds a limit argument to the builder
+    /// 
+    /// The limit argument specifies a maximum number of observations to return.
+    /// 
+    /// # Arguments
+    /// * `num_points` - Maximum number of data points to return
+    /// 
+    /// [https://research.stlouisfed.org/docs/api/fred/series_observations.html#limit](https://research.stlouisfed.org/docs/api/fred/series_observations.html#limit)
+    pub fn limit(&mut self, num_points: usize) -> &mut Builder {
+        let num_points = if num_points > 1000000 { // max value is 1000000
+            1000000
+        } else {
+            num_points
+        };
+        self.option_string += format!("&limit={}", num_points).as_str();
+        self
+    }
+
+    /// Adds a limit argument to the builder, returning Err rather than clamping
+    /// 
+    /// limit() silently clamps values above the endpoint maximum of 1,000,000, which returns fewer data points than requested.  This function returns Err in that case so the condition can be detected.
+    /// 
+    /// # Arguments
+    /// * `num_points` - Maximum number of data points to return
+    pub fn try_limit(&mut self, num_points: usize) -> Result<&mut Builder, FredError> {
+        if num_points > 1000000 {
+            return Err(FredError::InvalidArgument(String::from(LIMIT_EXCEEDED_ERROR_TEXT)));
+        }
+        Ok(self.limit(num_points))
+    }
+
+    /// Request every observation regardless of the per request limit
+    /// 
+    /// FRED returns at most 1,000,000 observations per request.  With this option the client pages through the results by offset and merges them into a single response, so any limit or offset set on the builder is ignored.  Long daily series can make this a large download.
+    pub fn all(&mut self) -> &mut Builder {
+        self.all = true;
+        self
+    }
+
+    /// Adds an offset argument to the builder
+    /// 
+    /// Adding an offset shifts the starting result number.  For example, if limit is 5 and offset is 0 then results 1-5 will be returned, but if offset was 5 then results 6-10 would be returned.
+    /// 
+    /// # Arguments
+    /// * `ofs` - the offset amount
+    /// 
+    /// [https://research.stlouisfed.org/docs/api/fred/series_observations.html#offset](https://research.stlouisfed.org/docs/api/fred/series_observations.html#offset)
+    pub fn offset(&mut self, ofs: usize) -> &mut Builder {
+        self.option_string += format!("&offset={}", ofs).as_str();
+        self
+    }
+
+    /// Change the sort order of the data
+    /// 
+    /// # Arguments
+    /// * `order` - Data sort order enum
+    /// 
+    /// [https://research.stlouisfed.org/docs/api/fred/series_observations.html#sort_order](https://research.stlouisfed.org/docs/api/fred/series_observations.html#sort_order)
+    pub fn sort_order(&mut self, order: SortOrder) -> &mut Builder {
+        self.option_string += format!("&sort_order={}", order.as_param()).as_str();
+        self
+    }
+
+    /// Set the start year for data points
+    /// 
+    /// # Arguments
+    /// * `start_date` - date formatted as YYYY-MM-DD
+    /// 
+    /// [https://research.stlouisfed.org/docs/api/fred/series_observations.html#observation_start](https://research.stlouisfed.org/docs/api/fred/series_observations.html#observation_start)
+    pub fn observation_start(&mut self, start_date: &str) -> &mut Builder {
+        let start_date = crate::common::date_arg(start_date);
+        self.option_string += format!("&observation_start={}", start_date).as_str();
+        self.observation_start = Some(start_date);
+        self
+    }
+
+    /// Set the start date for data points to January 1st of the current year
+    /// 
+    /// The current year is taken from the system clock in UTC so requests made in the first hours of January 1st in time zones ahead of UTC will still use the previous year.
+    /// 
+    /// [https://research.stlouisfed.org/docs/api/fred/series_observations.html#observation_start](https://research.stlouisfed.org/docs/api/fred/series_observations.html#observation_start)
+    #[cfg(feature = "chrono")]
+    pub fn year_to_date(&mut self) -> &mut Builder {
+        let start = format!("{}-01-01", Utc::now().year());
+        self.observation_start(start.as_str())
+    }
+
+    /// Set the start date for data points to `n` years before today
+    /// 
+    /// Today is the current date from the system clock in UTC, so near midnight it can differ from the local date by one day.  A start date of February 29th is moved to February 28th when the earlier year is not a leap year.
+    /// 
+    /// # Arguments
+    /// * `n` - number of years of data to request
+    /// 
+    /// [https://research.stlouisfed.org/docs/api/fred/series_observations.html#observation_start](https://research.stlouisfed.org/docs/api/fred/series_observations.html#observation_start)
+    #[cfg(feature = "chrono")]
+    pub fn last_n_years(&mut self, n: u32) -> &mut Builder {
+        self.last_n_periods(i64::from(n) * 12)
+    }
+
+    /// Set the start date for data points to `n` months before today
+    /// 
+    /// Today is the current date from the system clock in UTC, so near midnight it can differ from the local date by one day.  If the earlier month is shorter than the current day of the month, the last day of that month is used.  A start date before 1776-07-04, the earliest date FRED has observations for, is clamped to it.
+    /// 
+    /// # Arguments
+    /// * `n` - number of months of data to request
+    /// 
+    /// [https://research.stlouisfed.org/docs/api/fred/series_observations.html#observation_start](https://research.stlouisfed.org/docs/api/fred/series_observations.html#observation_start)
+    #[cfg(feature = "chrono")]
+    pub fn last_n_months(&mut self, n: u32) -> &mut Builder {
+        self.last_n_periods(i64::from(n))
+    }
+
+    /// Sets the start date for data points to `months` months before today, no earlier than 1776-07-04
+    #[cfg(feature = "chrono")]
+    fn last_n_periods(&mut self, months: i64) -> &mut Builder {
+        let start = months_before(Utc::now().date_naive(), months);
+        let start = match NaiveDate::from_ymd_opt(1776, 7, 4) {
+            Some(earliest) if start < earliest => earliest,
+            _ => start,
+        };
+        self.observation_start(start.to_string().as_str())
+    }
+
+    /// Set the end year for data points
+    /// 
+    /// # Arguments
+    /// * `end_date` - date formatted as YYYY-MM-DD
+    /// 
+    /// [https://research.stlouisfed.org/docs/api/fred/series_observations.html#observation_end](https://research.stlouisfed.org/docs/api/fred/series_observations.html#observation_end)
+    pub fn observation_end(&mut self, end_date: &str) -> &mut Builder {
+        let end_date = crate::common::date_arg(end_date);
+        self.option_string += format!("&observation_end={}", end_date).as_str();
+        self.observation_end = Some(end_date);
+        self
+    }
+
+    /// Set the start date for data points from a date
+    /// 
+    /// # Arguments
+    /// * `start_date` - the first observation date to return
+    /// 
+    /// [https://research.stlouisfed.org/docs/api/fred/series_observations.html#observation_start](https://research.stlouisfed.org/docs/api/fred/series_observations.html#observation_start)
+    #[cfg(feature = "chrono")]
+    pub fn observation_start_date(&mut self, start_date: NaiveDate) -> &mut Builder {
+        self.observation_start(start_date.format("%Y-%m-%d").to_string().as_str())
+    }
+
+    /// Set the end date for data points from a date
+    /// 
+    /// # Arguments
+    /// * `end_date` - the last observation date to return
+    /// 
+    /// [https://research.stlouisfed.org/docs/api/fred/series_observations.html#observation_end](https://research.stlouisfed.org/docs/api/fred/series_observations.html#observation_end)
+    #[cfg(feature = "chrono")]
+    pub fn observation_end_date(&mut self, end_date: NaiveDate) -> &mut Builder {
+        self.observation_end(end_date.format("%Y-%m-%d").to_string().as_str())
+    }
+
+    /// Set the units of the data series
+    /// 
+    /// # Arguments
+    /// * `units` - Data units to apply to the data set (see ObservationUnits)
+    /// 
+    /// [https://research.stlouisfed.org/docs/api/fred/series_observations.html#units](https://research.stlouisfed.org/docs/api/fred/series_observations.html#units)
+    pub fn units(&mut self, units: Units) -> &mut Builder {
+        match units {
+            Units::CHG => {
+                self.option_string += format!("&units=chg").as_str()
+            },
+            Units::CH1 => {
+                self.option_string += format!("&units=ch1").as_str()
+            },
+            Units::PCH => {
+                self.option_string += format!("&units=pch").as_str()
+            },
+            Units::PC1 => {
+                self.option_string += format!("&units=pc1").as_str()
+            },
+            Units::PCA => {
+                self.option_string += format!("&units=pca").as_str()
+            },
+            Units::CCH => {
+                self.option_string += format!("&units=cch").as_str()
+            },
+            Units::CCA => {
+                self.option_string += format!("&units=cca").as_str()
+            },
+            Units::LOG => {
+                self.option_string += format!("&units=log").as_str()
+            },
+            _ => (), // lin is the default
+        }
+        self
+    }
+
+    /// Set the frequency of the data series
+    /// 
+    /// The requested frequency must be less than or equal to the native frequency for the data set.
+    /// 
+    /// # Arguments
+    /// * `freq` - Frequency of data observations to return
+    /// 
+    /// [https://research.stlouisfed.org/docs/api/fred/series_observations.html#frequency](https://research.stlouisfed.org/docs/api/fred/series_observations.html#frequency)
+    pub fn frequency(&mut self, freq: Frequency) -> &mut Builder {
+        self.frequency_set = true;
+        match freq {
+            Frequency::D => {
+                self.option_string += format!("&frequency=d").as_str()
+            },
+            Frequency::W => {
+                self.option_string += format!("&frequency=w").as_str()
+            },
+            Frequency::BW => {
+                self.option_string += format!("&frequency=bw").as_str()
+            },
+            Frequency::M => {
+                self.option_string += format!("&frequency=m").as_str()
+            },
+            Frequency::Q => {
+                self.option_string += format!("&frequency=q").as_str()
+            },
+            Frequency::SA => {
+                self.option_string += format!("&frequency=sa").as_str()
+            },
+            Frequency::A => {
+                self.option_string += format!("&frequency=a").as_str()
+            },
+            Frequency::WEF => {
+                self.option_string += format!("&frequency=wef").as_str()
+            },
+            Frequency::WETH => {
+                self.option_string += format!("&frequency=weth").as_str()
+            },
+            Frequency::WEW => {
+                self.option_string += format!("&frequency=wew").as_str()
+            },
+            Frequency::WETU => {
+                self.option_string += format!("&frequency=wetu").as_str()
+            },
+            Frequency::WEM => {
+                self.option_string += format!("&frequency=wem").as_str()
+            },
+            Frequency::WESU => {
+                self.option_string += format!("&frequency=wesu").as_str()
+            },
+            Frequency::WESA => {
+                self.option_string += format!("&frequency=wesa").as_str()
+            },
+            Frequency::BWEW => {
+                self.option_string += format!("&frequency=bwew").as_str()
+            },
+            Frequency::BWEM => {
+                self.option_string += format!("&frequency=bwem").as_str()
+            },
+        }
+        self
+    }
+
+    /// Set the aggregation method of the data series
+    /// 
+    /// The aggregation method is only used when a lower frequency is requested with frequency(), so building a request with an aggregation method but no frequency returns Err.
     /// 
     /// # Arguments
-    /// * `end_date` - date formatted as YYYY-MM-DD
+    /// * `method` - See `ObservationAggregationMethod`
     /// 
-    /// [https://research.stlouisfed.org/docs/api/fred/series_observations.html#observation_end](https://research.stlouisfed.org/docs/api/fred/series_observations.html#observation_end)
-    pub fn observation_end(&mut self, end_date: &str) -> &mut Builder {
-        self.option_string += format!("&observation_end={}", end_date).as_str();
+    /// [https://research.stlouisfed.org/docs/api/fred/series_observations.html#aggregation_method](https://research.stlouisfed.org/docs/api/fred/series_observations.html#aggregation_method)
+    pub fn aggregation_method(&mut self, method: AggregationMethod) -> &mut Builder {
+        self.aggregation_set = true;
+        match method {
+            AggregationMethod::SUM => {
+                self.option_string += format!("&aggregation_method=sum").as_str()
+            },
+            AggregationMethod::EOP => {
+                self.option_string += format!("&aggregation_method=eop").as_str()
+            },
+            _ => () // AVG is the default so do nothing
+        }
         self
     }
 
-    /// Set the units of the data series
+    /// Set the datapoint output type
     /// 
     /// # Arguments
-    /// * `units` - Data units to apply to the data set (see ObservationUnits)
+    /// * `otype` - [OutputType](enum.OutputType.hmtl)
     /// 
-    /// [https://research.stlouisfed.org/docs/api/fred/series_observations.html#units](https://research.stlouisfed.org/docs/api/fred/series_observations.html#units)
-    pub fn units(&mut self, units: Units) -> &mut Builder {
-        match units {
-            Units::CHG => {
-                self.option_string += format!("&units=chg").as_str()
-            },
-            Units::CH1 => {
-                self.option_string += format!("&units=ch1").as_str()
+    /// [https://research.stlouisfed.org/docs/api/fred/series_observations.html#output_type](https://research.stlouisfed.org/docs/api/fred/series_observations.html#output_type)
+    pub fn output_type(&mut self, otype: OutputType) -> &mut Builder {
+        match otype {
+            OutputType::VDALL => {
+                self.option_string += format!("&output_type=2").as_str()
             },
-            Units::PCH => {
-                self.option_string += format!("&units=pch").as_str()
+            OutputType::VDNEW => {
+                self.option_string += format!("&output_type=3").as_str()
             },
-            Units::PC1 => {
-                self.option_string += format!("&units=pc1").as_str()
+            OutputType::INITIAL => {
+                self.option_string += format!("&output_type=4").as_str()
             },
-            Units::PCA => {
-                self.option_string += format!("&units=pca").as_str()
+            _ => () // AVG is the default so do nothing
+        }
+        self
+    }
+
+    /// Add a vintage date argument
+    /// 
+    /// This is the only parameter that could be added mroe than once.
+    /// 
+    /// The API accepts a comma separated list of vintage dates for which to return data.  Vintage dates cannot be combined with realtime_start or realtime_end.
+    /// 
+    /// [https://research.stlouisfed.org/docs/api/fred/series_observations.html#vintage_dates](https://research.stlouisfed.org/docs/api/fred/series_observations.html#vintage_dates)
+    /// 
+    /// # Arguments
+    /// * `date` - date formatted as YYYY-MM-DD
+    pub fn vintage_date(&mut self, date: &str) -> &mut Builder {
+        if self.vintage_dates.len() != 0 {
+            self.vintage_dates.push(',');
+        } 
+        self.vintage_dates += crate::common::date_arg(date).as_str();
+        self
+    }
+
+    /// Add several vintage date arguments at once
+    /// 
+    /// Equivalent to calling `vintage_date()` for each date in order.  Vintage dates cannot be combined with realtime_start or realtime_end.
+    /// 
+    /// [https://research.stlouisfed.org/docs/api/fred/series_observations.html#vintage_dates](https://research.stlouisfed.org/docs/api/fred/series_observations.html#vintage_dates)
+    /// 
+    /// # Arguments
+    /// * `dates` - dates formatted as YYYY-MM-DD
+    pub fn vintage_dates(&mut self, dates: &[&str]) -> &mut Builder {
+        for date in dates {
+            self.vintage_date(date);
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::FredClient;
+
+    #[test]
+    fn series_observation_with_options() {
+        let mut c = match FredClient::new() {
+            Ok(c) => c,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
             },
-            Units::CCH => {
-                self.option_string += format!("&units=cch").as_str()
+        };
+
+        let mut builder = Builder::new();
+        builder
+            .limit(5)
+            .sort_order(SortOrder::Descending);
+
+        let resp: Response = match c.series_observation("GNPCA", Some(builder)) {
+            Ok(resp) => resp,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
             },
-            Units::CCA => {
-                self.option_string += format!("&units=cca").as_str()
+        };
+
+        for item in resp.observations {
+            println!("{}: {}", item.date, item.value.parse::<f64>().unwrap());
+        }
+        //assert_eq!(resp.observations[0].value, String::from("1120.076"));
+    }
+
+    #[test]
+    fn series_observation_vintage_realtime_conflict() {
+        let mut builder = Builder::new();
+        builder
+            .vintage_date("2020-01-01")
+            .realtime_start("2019-01-01");
+
+        match builder.build() {
+            Ok(_) => assert_eq!(1, 2), // vintage dates and realtime must not be combined
+            Err(msg) => assert_eq!(msg.as_str(), VINTAGE_REALTIME_CONFLICT_ERROR_TEXT),
+        }
+
+        let mut builder = Builder::new();
+        builder.vintage_date("2020-01-01");
+        assert_eq!(builder.build(), Ok(String::from("&vintage_dates=2020-01-01")));
+    }
+
+    #[test]
+    fn series_observation_realtime_period() {
+        let mut builder = Builder::new();
+        assert!(builder.realtime_period("2020-01-01", "2019-01-01").is_err());
+        assert!(builder.realtime_period("2019-01-01", "yesterday").is_err());
+        assert_eq!(builder.peek(), Ok(String::new()));
+
+        match builder.realtime_period("2019/01/01", "2020-01-01") {
+            Ok(b) => assert_eq!(b.peek(), Ok(String::from("&realtime_start=2019-01-01&realtime_end=2020-01-01"))),
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1)
             },
-            Units::LOG => {
-                self.option_string += format!("&units=log").as_str()
+        }
+
+        // the period counts as realtime arguments for the vintage date check
+        builder.vintage_date("2020-01-01");
+        assert!(builder.build().is_err());
+    }
+
+    #[test]
+    fn series_observation_invalid_date() {
+        let mut builder = Builder::new();
+        builder.observation_start("01-01-2020");
+        assert!(builder.peek().is_err());
+
+        let mut builder = Builder::new();
+        builder
+            .vintage_date("2020-01-01")
+            .vintage_date("2020-02-30");
+        assert!(builder.peek().is_err());
+    }
+
+    #[test]
+    fn series_observation_period_order() {
+        let mut builder = Builder::new();
+        builder
+            .observation_start("2020-01-01")
+            .observation_end("2019-01-01");
+
+        match builder.build() {
+            Ok(_) => assert_eq!(1, 2), // start after end must be rejected
+            Err(msg) => assert_eq!(msg.as_str(), OBSERVATION_PERIOD_ORDER_ERROR_TEXT),
+        }
+
+        let mut builder = Builder::new();
+        builder
+            .observation_start("2019-01-01")
+            .observation_end("2019-01-01");
+        assert!(builder.build().is_ok());
+
+        assert!(is_date("2019-01-01"));
+        assert!(!is_date("2019/01/01"));
+        assert!(!is_date("19-01-01"));
+    }
+
+    #[test]
+    fn series_observation_query_into_builder() {
+        let query: ObservationQuery = serde_json::from_str(r#"{
+            "observation_start": "2000-01-01",
+            "units": "pch",
+            "frequency": "q",
+            "aggregation_method": "eop",
+            "sort_order": "desc",
+            "limit": 10
+        }"#).unwrap();
+
+        assert_eq!(
+            Builder::from(query).build(),
+            Ok(String::from("&limit=10&sort_order=desc&observation_start=2000-01-01&units=pch&frequency=q&aggregation_method=eop"))
+        );
+        assert_eq!(Builder::from(ObservationQuery::default()).build(), Ok(String::new()));
+    }
+
+    #[test]
+    fn series_observation_vintage_dates() {
+        let mut builder = Builder::new();
+        builder
+            .vintage_date("2019-01-01")
+            .vintage_dates(&["2020-01-01", "2021-01-01"]);
+
+        assert_eq!(
+            builder.build(),
+            Ok(String::from("&vintage_dates=2019-01-01,2020-01-01,2021-01-01"))
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn series_observation_year_to_date() {
+        let mut builder = Builder::new();
+        builder.year_to_date();
+
+        let expected = format!("&observation_start={}-01-01", Utc::now().year());
+        assert_eq!(builder.build(), Ok(expected));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn series_observation_last_n() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        assert_eq!(months_before(date(2020, 6, 15), 60), date(2015, 6, 15));
+        assert_eq!(months_before(date(2020, 3, 31), 1), date(2020, 2, 29));
+        assert_eq!(months_before(date(2020, 2, 29), 12), date(2019, 2, 28));
+        assert_eq!(months_before(date(2020, 1, 10), 2), date(2019, 11, 10));
+        assert_eq!(months_before(date(2020, 1, 10), 0), date(2020, 1, 10));
+        assert_eq!(months_before(date(2020, 3, 31), 4_000_000), NaiveDate::MIN);
+        assert_eq!(months_before(date(2020, 3, 31), i64::from(u32::MAX) * 12), NaiveDate::MIN);
+
+        let today = Utc::now().date_naive();
+        let mut builder = Builder::new();
+        builder.last_n_years(5);
+        let expected = format!("&observation_start={}", months_before(today, 60));
+        assert_eq!(builder.build(), Ok(expected));
+
+        let mut builder = Builder::new();
+        builder.last_n_months(3);
+        let expected = format!("&observation_start={}", months_before(today, 3));
+        assert_eq!(builder.build(), Ok(expected));
+
+        // far past the first FRED observation
+        let mut builder = Builder::new();
+        builder.last_n_years(u32::MAX);
+        assert_eq!(builder.peek(), Ok(String::from("&observation_start=1776-07-04")));
+        builder.clear().last_n_years(400_000_000);
+        assert_eq!(builder.peek(), Ok(String::from("&observation_start=1776-07-04")));
+        builder.clear().last_n_months(4_000_000);
+        assert_eq!(builder.peek(), Ok(String::from("&observation_start=1776-07-04")));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn series_observation_current_vintage() {
+        let today = Utc::now().date_naive();
+        let mut builder = Builder::new();
+        builder.current_vintage();
+
+        let expected = format!("&realtime_start={}&realtime_end={}", today, today);
+        assert_eq!(builder.peek(), Ok(expected));
+
+        // a pinned realtime period cannot be combined with vintage dates
+        builder.vintage_date("2020-01-01");
+        assert!(builder.build().is_err());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn series_observation_as_of_datetime() {
+        let now = Utc::now();
+        let mut builder = Builder::new();
+        builder.as_of_datetime(now);
+
+        let date = now.date_naive();
+        let expected = format!("&realtime_start={}&realtime_end={}", date, date);
+        assert_eq!(builder.build(), Ok(expected));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn series_observation_naive_date_setters() {
+        let mut builder = Builder::new();
+        builder
+            .observation_start_date(NaiveDate::from_ymd_opt(2000, 1, 1).unwrap())
+            .observation_end_date(NaiveDate::from_ymd_opt(2009, 12, 31).unwrap())
+            .realtime_start_date(NaiveDate::from_ymd_opt(2010, 6, 1).unwrap())
+            .realtime_end_date(NaiveDate::from_ymd_opt(2010, 6, 30).unwrap());
+        assert_eq!(
+            builder.build(),
+            Ok(String::from("&observation_start=2000-01-01&observation_end=2009-12-31&realtime_start=2010-06-01&realtime_end=2010-06-30"))
+        );
+
+        let resp = response_from(&[("2020-02-29", "1.0"), ("2020-13-01", "2.0")]);
+        assert_eq!(resp.observations[0].date_parsed().ok(), NaiveDate::from_ymd_opt(2020, 2, 29));
+        assert!(resp.observations[1].date_parsed().is_err());
+    }
+
+    #[test]
+    fn series_observation_missing_ratio() {
+        let mut resp = Response::default();
+        assert_eq!(resp.missing_ratio(), 0.0);
+
+        for value in [".", "1.0", "2.0", "."].iter() {
+            resp.observations.push(DataPoint {
+                value: String::from(*value),
+                ..DataPoint::default()
+            });
+        }
+        assert_eq!(resp.missing_ratio(), 0.5);
+    }
+
+    fn response_from(points: &[(&str, &str)]) -> Response {
+        let mut resp = Response::default();
+        for (date, value) in points {
+            resp.observations.push(DataPoint {
+                date: String::from(*date),
+                value: String::from(*value),
+                ..DataPoint::default()
+            });
+        }
+        resp
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn series_observation_change_points() {
+        let resp = response_from(&[
+            ("2020-01-01", "1.0"),
+            ("2020-02-01", "1.5"),
+            ("2020-03-01", "."),
+            ("2020-04-01", "4.0"),
+            ("2020-05-01", "3.9"),
+            ("2020-06-01", "1.0"),
+        ]);
+
+        let points = resp.change_points(1.0);
+        assert_eq!(points.len(), 2);
+        // the gap in March is bridged by comparing April against February
+        assert_eq!(points[0].0, NaiveDate::from_ymd_opt(2020, 4, 1).unwrap());
+        assert!((points[0].1 - 2.5).abs() < 1e-9);
+        assert_eq!(points[1].0, NaiveDate::from_ymd_opt(2020, 6, 1).unwrap());
+        assert!((points[1].1 + 2.9).abs() < 1e-9);
+
+        assert!(response_from(&[]).change_points(0.0).is_empty());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn series_observation_date_range() {
+        let resp = response_from(&[
+            ("2020-03-01", "1.0"),
+            ("2020-01-01", "."),
+            ("2020-06-01", "2.0"),
+        ]);
+
+        assert_eq!(
+            resp.date_range(),
+            Some((
+                NaiveDate::from_ymd_opt(2020, 1, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2020, 6, 1).unwrap(),
+            ))
+        );
+        assert_eq!(response_from(&[]).date_range(), None);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn series_observation_to_regular_grid() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        let resp = response_from(&[
+            ("2019-11-01", "1.0"),
+            ("2020-01-01", "."),
+            ("2020-02-01", "3.0"),
+        ]);
+        assert_eq!(
+            resp.to_regular_grid(Frequency::M),
+            vec![
+                (date(2019, 11, 1), Some(1.0)),
+                (date(2019, 12, 1), None),
+                (date(2020, 1, 1), None),
+                (date(2020, 2, 1), Some(3.0)),
+            ]
+        );
+
+        let resp = response_from(&[
+            ("2019-07-01", "1.0"),
+            ("2020-04-01", "2.0"),
+        ]);
+        assert_eq!(
+            resp.to_regular_grid(Frequency::Q),
+            vec![
+                (date(2019, 7, 1), Some(1.0)),
+                (date(2019, 10, 1), None),
+                (date(2020, 1, 1), None),
+                (date(2020, 4, 1), Some(2.0)),
+            ]
+        );
+
+        let resp = response_from(&[
+            ("2020-02-28", "1.0"),
+            ("2020-03-02", "2.0"),
+        ]);
+        assert_eq!(
+            resp.to_regular_grid(Frequency::D),
+            vec![
+                (date(2020, 2, 28), Some(1.0)),
+                (date(2020, 2, 29), None),
+                (date(2020, 3, 1), None),
+                (date(2020, 3, 2), Some(2.0)),
+            ]
+        );
+
+        assert!(response_from(&[]).to_regular_grid(Frequency::A).is_empty());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn series_observation_revision_table() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let vintage = |realtime_start: &str, date: &str, value: &str| DataPoint {
+            realtime_start: String::from(realtime_start),
+            realtime_end: String::from("9999-12-31"),
+            date: String::from(date),
+            value: String::from(value),
+        };
+
+        let mut resp = Response::default();
+        resp.observations = vec![
+            vintage("2020-04-29", "2020-01-01", "-4.8"),
+            vintage("2020-05-28", "2020-01-01", "-5.0"),
+            vintage("2020-06-25", "2020-01-01", "-5.0"),
+            vintage("2020-07-30", "2020-04-01", "-32.9"),
+            vintage("2020-08-27", "2020-04-01", "."),
+        ];
+
+        let table = resp.revision_table();
+        assert_eq!(table.len(), 2);
+
+        let q1: Vec<(NaiveDate, f64)> = table[&date(2020, 1, 1)].iter().map(|(k, v)| (*k, *v)).collect();
+        assert_eq!(q1, vec![
+            (date(2020, 4, 29), -4.8),
+            (date(2020, 5, 28), -5.0),
+            (date(2020, 6, 25), -5.0),
+        ]);
+        assert_eq!(table[&date(2020, 4, 1)].len(), 1);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn series_observation_returns() {
+        let resp = response_from(&[
+            ("2020-01-01", "100.0"),
+            ("2020-02-01", "110.0"),
+            ("2020-03-01", "."),
+            ("2020-04-01", "99.0"),
+            ("2020-05-01", "0.0"),
+            ("2020-06-01", "5.0"),
+        ]);
+
+        let simple: Vec<Option<f64>> = resp.simple_returns().iter().map(|(_, v)| *v).collect();
+        assert_eq!(simple.len(), 6);
+        assert_eq!(simple[0], None);
+        assert!((simple[1].unwrap() - 0.1).abs() < 1e-9);
+        assert_eq!(simple[2], None);
+        assert_eq!(simple[3], None); // previous observation is missing
+        assert!((simple[4].unwrap() + 1.0).abs() < 1e-9);
+        assert_eq!(simple[5], None); // previous value is zero
+
+        let log: Vec<Option<f64>> = resp.log_returns().iter().map(|(_, v)| *v).collect();
+        assert!((log[1].unwrap() - (1.1f64).ln()).abs() < 1e-9);
+        assert_eq!(log[4], None); // ln(0) is undefined
+        assert_eq!(log[5], None);
+
+        assert_eq!(resp.log_returns()[1].0, NaiveDate::from_ymd_opt(2020, 2, 1).unwrap());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn series_observation_unify_frequency() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        // annual until 2018 then monthly
+        let resp = response_from(&[
+            ("2017-01-01", "10.0"),
+            ("2018-01-01", "12.0"),
+            ("2019-01-01", "1.0"),
+            ("2019-02-01", "2.0"),
+            ("2019-03-01", "6.0"),
+            ("2019-04-01", "4.0"),
+            ("2019-05-01", "."),
+        ]);
+
+        assert_eq!(
+            resp.unify_frequency(Frequency::Q, AggregationMethod::AVG),
+            vec![
+                (date(2017, 1, 1), Some(10.0)),
+                (date(2018, 1, 1), Some(12.0)),
+                (date(2019, 1, 1), Some(3.0)),
+                (date(2019, 4, 1), Some(4.0)),
+            ]
+        );
+        assert_eq!(
+            resp.unify_frequency(Frequency::A, AggregationMethod::SUM),
+            vec![
+                (date(2017, 1, 1), Some(10.0)),
+                (date(2018, 1, 1), Some(12.0)),
+                (date(2019, 1, 1), Some(13.0)),
+            ]
+        );
+        assert_eq!(
+            resp.unify_frequency(Frequency::A, AggregationMethod::EOP)[2],
+            (date(2019, 1, 1), Some(4.0))
+        );
+
+        let resp = response_from(&[
+            ("2020-01-01", "1.0"),
+            ("2020-01-05", "3.0"),
+            ("2020-01-08", "5.0"),
+        ]);
+        assert_eq!(
+            resp.unify_frequency(Frequency::W, AggregationMethod::AVG),
+            vec![
+                (date(2020, 1, 1), Some(2.0)),
+                (date(2020, 1, 8), Some(5.0)),
+            ]
+        );
+
+        // monthly until 2019 then daily, the monthly observations keep their dates
+        let resp = response_from(&[
+            ("2019-11-01", "10.0"),
+            ("2019-12-01", "."),
+            ("2020-01-01", "1.0"),
+            ("2020-01-02", "2.0"),
+            ("2020-01-03", "3.0"),
+            ("2020-01-06", "6.0"),
+            ("2020-01-08", "8.0"),
+        ]);
+        assert_eq!(
+            resp.unify_frequency(Frequency::W, AggregationMethod::AVG),
+            vec![
+                (date(2019, 11, 1), Some(10.0)),
+                (date(2019, 12, 1), None),
+                (date(2020, 1, 1), Some(3.0)),
+                (date(2020, 1, 8), Some(8.0)),
+            ]
+        );
+        // resample counts weeks from the first observation instead
+        assert_eq!(resp.resample(Frequency::W, AggregationMethod::AVG)[1].0, date(2019, 11, 29));
+
+        // nothing finer than the target
+        assert_eq!(resp.unify_frequency(Frequency::D, AggregationMethod::AVG).len(), 7);
+    }
+
+    #[test]
+    fn series_observation_peek_and_clear() {
+        let mut builder = Builder::new();
+        builder
+            .vintage_date("2019-01-01")
+            .realtime_start("2018-01-01");
+        match builder.peek() {
+            Ok(_) => assert_eq!(2, 1),
+            Err(msg) => assert_eq!(msg.as_str(), VINTAGE_REALTIME_CONFLICT_ERROR_TEXT),
+        }
+
+        builder.clear().vintage_date("2019-01-01");
+        match builder.peek() {
+            Ok(options) => assert_eq!(options, "&vintage_dates=2019-01-01"),
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1)
             },
-            _ => (), // lin is the default
         }
-        self
     }
 
-    /// Set the frequency of the data series
-    /// 
-    /// The requested frequency must be less than or equal to the native frequency for the data set.
-    /// 
-    /// # Arguments
-    /// * `freq` - Frequency of data observations to return
-    /// 
-    /// [https://research.stlouisfed.org/docs/api/fred/series_observations.html#frequency](https://research.stlouisfed.org/docs/api/fred/series_observations.html#frequency)
-    pub fn frequency(&mut self, freq: Frequency) -> &mut Builder {
-        match freq {
-            Frequency::D => {
-                self.option_string += format!("&frequency=d").as_str()
-            },
-            Frequency::W => {
-                self.option_string += format!("&frequency=w").as_str()
-            },
-            Frequency::BW => {
-                self.option_string += format!("&frequency=bw").as_str()
-            },
-            Frequency::M => {
-                self.option_string += format!("&frequency=m").as_str()
-            },
-            Frequency::Q => {
-                self.option_string += format!("&frequency=q").as_str()
-            },
-            Frequency::SA => {
-                self.option_string += format!("&frequency=sa").as_str()
-            },
-            Frequency::A => {
-                self.option_string += format!("&frequency=a").as_str()
-            },
-            Frequency::WEF => {
-                self.option_string += format!("&frequency=wef").as_str()
-            },
-            Frequency::WETH => {
-                self.option_string += format!("&frequency=weth").as_str()
-            },
-            Frequency::WEW => {
-                self.option_string += format!("&frequency=wew").as_str()
-            },
-            Frequency::WETU => {
-                self.option_string += format!("&frequency=d").as_str()
-            },
-            Frequency::WEM => {
-                self.option_string += format!("&frequency=wem").as_str()
-            },
-            Frequency::WESU => {
-                self.option_string += format!("&frequency=wesu").as_str()
-            },
-            Frequency::WESA => {
-                self.option_string += format!("&frequency=wesa").as_str()
-            },
-            Frequency::BWEW => {
-                self.option_string += format!("&frequency=bwew").as_str()
-            },
-            Frequency::BWEM => {
-                self.option_string += format!("&frequency=bwem").as_str()
+    #[test]
+    fn series_observation_try_limit() {
+        let mut builder = Builder::new();
+        match builder.try_limit(1000001) {
+            Err(FredError::InvalidArgument(msg)) => assert_eq!(msg.as_str(), LIMIT_EXCEEDED_ERROR_TEXT),
+            _ => assert_eq!(2, 1),
+        }
+
+        // the permissive version still clamps
+        builder.limit(1000001);
+        match builder.build() {
+            Ok(options) => assert_eq!(options, "&limit=1000000"),
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1)
             },
         }
-        self
     }
 
-    /// Set the aggregation method of the data series
-    /// 
-    /// # Arguments
-    /// * `method` - See `ObservationAggregationMethod`
-    /// 
-    /// [https://research.stlouisfed.org/docs/api/fred/series_observations.html#aggregation_method](https://research.stlouisfed.org/docs/api/fred/series_observations.html#aggregation_method)
-    pub fn aggregation_method(&mut self, method: AggregationMethod) -> &mut Builder {
-        match method {
-            AggregationMethod::SUM => {
-                self.option_string += format!("&aggregation_method=sum").as_str()
-            },
-            AggregationMethod::EOP => {
-                self.option_string += format!("&aggregation_method=eop").as_str()
+    #[test]
+    fn series_observation_without_units() {
+        let mut builder = Builder::new();
+        builder.limit(10);
+        assert!(builder.without_units().is_none());
+
+        builder
+            .units(Units::PC1)
+            .observation_start("2000-01-01");
+        match builder.without_units() {
+            Some(b) => match b.build() {
+                Ok(options) => assert_eq!(options, "&limit=10&observation_start=2000-01-01"),
+                Err(msg) => {
+                    println!("{}", msg);
+                    assert_eq!(2, 1)
+                },
             },
-            _ => () // AVG is the default so do nothing
+            None => assert_eq!(2, 1),
         }
-        self
     }
 
-    /// Set the datapoint output type
-    /// 
-    /// # Arguments
-    /// * `otype` - [OutputType](enum.OutputType.hmtl)
-    /// 
-    /// [https://research.stlouisfed.org/docs/api/fred/series_observations.html#output_type](https://research.stlouisfed.org/docs/api/fred/series_observations.html#output_type)
-    pub fn output_type(&mut self, otype: OutputType) -> &mut Builder {
-        match otype {
-            OutputType::VDALL => {
-                self.option_string += format!("&output_type=2").as_str()
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn series_observation_calendar_downsampling() {
+        let resp = response_from(&[
+            ("2019-12-30", "1.0"),
+            ("2019-12-31", "2.0"),
+            ("2020-01-01", "3.0"),
+            ("2020-01-31", "."),
+            ("2020-02-01", "5.0"),
+            ("2020-03-31", "6.0"),
+            ("2020-04-01", "7.0"),
+        ]);
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        assert_eq!(
+            resp.to_monthly(AggregationMethod::SUM),
+            vec![
+                (date(2019, 12, 1), Some(3.0)),
+                (date(2020, 1, 1), Some(3.0)),
+                (date(2020, 2, 1), Some(5.0)),
+                (date(2020, 3, 1), Some(6.0)),
+                (date(2020, 4, 1), Some(7.0)),
+            ]
+        );
+        assert_eq!(
+            resp.to_quarterly(AggregationMethod::EOP),
+            vec![
+                (date(2019, 10, 1), Some(2.0)),
+                (date(2020, 1, 1), Some(6.0)),
+                (date(2020, 4, 1), Some(7.0)),
+            ]
+        );
+        assert_eq!(
+            resp.to_annual(AggregationMethod::AVG),
+            vec![
+                (date(2019, 1, 1), Some(1.5)),
+                (date(2020, 1, 1), Some(5.25)),
+            ]
+        );
+    }
+
+    #[cfg(feature = "arrow")]
+    #[test]
+    fn series_observation_to_arrow_ipc() {
+        let resp = response_from(&[
+            ("2020-01-01", "1.5"),
+            ("2020-02-01", "."),
+        ]);
+        let mut out = Vec::new();
+        match resp.to_arrow_ipc(&mut out) {
+            Ok(_) => assert!(!out.is_empty()),
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1)
             },
-            OutputType::VDNEW => {
-                self.option_string += format!("&output_type=3").as_str()
+        }
+
+        let bad = response_from(&[("01/01/2020", "1.5")]);
+        assert!(bad.to_arrow_ipc(Vec::new()).is_err());
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn series_observation_value_decimal() {
+        let point = |value: &str| DataPoint {
+            value: String::from(value),
+            ..DataPoint::default()
+        };
+
+        assert_eq!(point("0.1").value_decimal(), Decimal::from_str("0.1").ok());
+        assert_eq!(point("19221.970").value_decimal().unwrap().to_string(), "19221.970");
+        assert_eq!(point(".").value_decimal(), None);
+    }
+
+    #[test]
+    fn series_observation_to_csv_with_meta() {
+        let mut resp = Response::default();
+        for (date, value) in [("2020-01-01", "3.5"), ("2020-02-01", ".")].iter() {
+            resp.observations.push(DataPoint {
+                date: String::from(*date),
+                value: String::from(*value),
+                ..DataPoint::default()
+            });
+        }
+        let series = Series {
+            id: String::from("UNRATE"),
+            title: String::from("Unemployment Rate"),
+            units: String::from("Percent"),
+            frequency: String::from("Monthly"),
+            seasonal_adjustment: String::from("Seasonally Adjusted"),
+            ..Series::default()
+        };
+
+        let mut out = Vec::new();
+        match resp.to_csv_with_meta(&series, &mut out) {
+            Ok(_) => assert_eq!(
+                String::from_utf8(out).unwrap(),
+                "# id: UNRATE\n# title: Unemployment Rate\n# units: Percent\n# frequency: Monthly\n# seasonal_adjustment: Seasonally Adjusted\ndate,value\n2020-01-01,3.5\n2020-02-01,\n"
+            ),
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1)
             },
-            OutputType::INITIAL => {
-                self.option_string += format!("&output_type=4").as_str()
+        }
+    }
+
+    #[test]
+    fn series_observation_aggregation_without_frequency() {
+        let mut builder = Builder::new();
+        builder.aggregation_method(AggregationMethod::SUM);
+        match builder.peek() {
+            Ok(_) => assert_eq!(2, 1),
+            Err(msg) => assert_eq!(msg.as_str(), AGGREGATION_WITHOUT_FREQUENCY_ERROR_TEXT),
+        }
+
+        builder.frequency(Frequency::A);
+        match builder.build() {
+            Ok(options) => assert_eq!(options, "&aggregation_method=sum&frequency=a"),
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1)
             },
-            _ => () // AVG is the default so do nothing
         }
-        self
     }
 
-    /// Add a vintage date argument
-    /// 
-    /// This is the only parameter that could be added mroe than once.
-    /// 
-    /// The API accepts a comma separated list of vintage dates for which to return data.
-    /// 
-    /// [https://research.stlouisfed.org/docs/api/fred/series_observations.html#vintage_dates](https://research.stlouisfed.org/docs/api/fred/series_observations.html#vintage_dates)
-    /// 
-    /// # Arguments
-    /// * `date` - date formatted as YYYY-MM-DD
-    pub fn vintage_date(&mut self, date: &str) -> &mut Builder {
-        if self.vintage_dates.len() != 0 {
-            self.vintage_dates.push(',');
-        } 
-        self.vintage_dates += date;
-        self
+    #[test]
+    fn series_observation_frequency_params() {
+        let cases = vec![
+            (Frequency::D, "d"),
+            (Frequency::W, "w"),
+            (Frequency::BW, "bw"),
+            (Frequency::M, "m"),
+            (Frequency::Q, "q"),
+            (Frequency::SA, "sa"),
+            (Frequency::A, "a"),
+            (Frequency::WEF, "wef"),
+            (Frequency::WETH, "weth"),
+            (Frequency::WEW, "wew"),
+            (Frequency::WETU, "wetu"),
+            (Frequency::WEM, "wem"),
+            (Frequency::WESU, "wesu"),
+            (Frequency::WESA, "wesa"),
+            (Frequency::BWEW, "bwew"),
+            (Frequency::BWEM, "bwem"),
+        ];
+
+        for (freq, param) in cases {
+            let mut builder = Builder::new();
+            builder.frequency(freq);
+            match builder.build() {
+                Ok(options) => assert_eq!(options, format!("&frequency={}", param)),
+                Err(msg) => {
+                    println!("{}", msg);
+                    assert_eq!(2, 1)
+                },
+            }
+        }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::client::FredClient;
+    #[test]
+    fn series_observation_iter_raw() {
+        let mut resp = Response::default();
+        for (date, value) in [("2020-01-01", "3.5"), ("2020-02-01", ".")].iter() {
+            resp.observations.push(DataPoint {
+                date: String::from(*date),
+                value: String::from(*value),
+                ..DataPoint::default()
+            });
+        }
+
+        let pairs: Vec<(&str, Option<f64>)> = resp.iter_raw().collect();
+        assert_eq!(pairs, vec![("2020-01-01", Some(3.5)), ("2020-02-01", None)]);
+    }
 
     #[test]
-    fn series_observation_with_options() {
-        let mut c = match FredClient::new() {
-            Ok(c) => c,
+    fn series_observation_into_iter() {
+        let resp = response_from(&[("2020-01-01", "3.5"), ("2020-02-01", ".")]);
+
+        let dates: Vec<&str> = (&resp).into_iter().map(|item| item.date.as_str()).collect();
+        assert_eq!(dates, vec!["2020-01-01", "2020-02-01"]);
+        let missing: Vec<&DataPoint> = resp.iter().filter(|item| item.value_f64().is_none()).collect();
+        assert_eq!(missing.len(), 1);
+        assert_eq!(missing[0].date, "2020-02-01");
+
+        let points: Vec<DataPoint> = resp.into_iter().filter(|item| item.value != ".").collect();
+        assert_eq!(points.len(), 1);
+        assert_eq!(points[0].value, "3.5");
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn series_observation_iter_values() {
+        let resp = response_from(&[("2020-01-01", "3.5"), ("bad", "1.0"), ("2020-03-01", ".")]);
+        let values: Vec<(NaiveDate, Option<f64>)> = resp.iter_values().collect();
+        assert_eq!(values, vec![
+            (NaiveDate::from_ymd_opt(2020, 1, 1).unwrap(), Some(3.5)),
+            (NaiveDate::from_ymd_opt(2020, 3, 1).unwrap(), None),
+        ]);
+    }
+
+    #[test]
+    fn series_observation_values() {
+        let resp = response_from(&[("2020-01-01", "3.5"), ("2020-02-01", "."), ("2020-03-01", "n/a"), ("2020-04-01", "-1")]);
+        assert_eq!(resp.observations[0].value_f64(), Some(3.5));
+        assert_eq!(resp.observations[1].value_f64(), None);
+        assert_eq!(resp.values(), vec![Some(3.5), None, None, Some(-1.0)]);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn series_observation_ewma() {
+        let resp = response_from(&[
+            ("2020-01-01", "10.0"),
+            ("2020-02-01", "20.0"),
+            ("2020-03-01", "."),
+            ("2020-04-01", "5.0"),
+        ]);
+
+        let values: Vec<Option<f64>> = resp.ewma(0.5).into_iter().map(|(_, v)| v).collect();
+        assert_eq!(values, vec![Some(10.0), Some(15.0), None, Some(10.0)]);
+
+        let unchanged: Vec<Option<f64>> = resp.ewma(1.0).into_iter().map(|(_, v)| v).collect();
+        assert_eq!(unchanged, vec![Some(10.0), Some(20.0), None, Some(5.0)]);
+
+        assert!(resp.ewma(0.0).is_empty());
+        assert!(resp.ewma(1.5).is_empty());
+        assert!(resp.ewma(f64::NAN).is_empty());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn series_observation_moving_average() {
+        let resp = response_from(&[
+            ("2020-01-01", "1.0"),
+            ("2020-02-01", "2.0"),
+            ("2020-03-01", "3.0"),
+            ("2020-04-01", "."),
+            ("2020-05-01", "5.0"),
+            ("2020-06-01", "6.0"),
+            ("2020-07-01", "7.0"),
+        ]);
+
+        let trailing: Vec<Option<f64>> = resp.moving_average(2).into_iter().map(|(_, v)| v).collect();
+        assert_eq!(trailing, vec![None, Some(1.5), Some(2.5), None, None, Some(5.5), Some(6.5)]);
+
+        let centered: Vec<Option<f64>> = resp.centered_moving_average(3).into_iter().map(|(_, v)| v).collect();
+        assert_eq!(centered, vec![None, Some(2.0), None, None, None, Some(6.0), None]);
+
+        let unchanged: Vec<Option<f64>> = resp.moving_average(1).into_iter().map(|(_, v)| v).collect();
+        assert_eq!(unchanged[4], Some(5.0));
+
+        assert_eq!(resp.moving_average(10).iter().filter(|(_, v)| v.is_some()).count(), 0);
+        assert!(resp.moving_average(0).is_empty());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn series_observation_cagr() {
+        // doubled over two years (731 days including a leap day)
+        let resp = response_from(&[
+            ("2019-01-01", "."),
+            ("2019-01-01", "100.0"),
+            ("2020-01-01", "130.0"),
+            ("2021-01-01", "200.0"),
+            ("2021-04-01", "."),
+        ]);
+        match resp.cagr() {
+            Some(rate) => assert!((rate - (2.0f64.powf(365.25 / 731.0) - 1.0)).abs() < 1e-12),
+            None => assert_eq!(2, 1),
+        }
+
+        // a decline gives a negative rate
+        let resp = response_from(&[("2000-01-01", "100.0"), ("2010-01-01", "50.0")]);
+        match resp.cagr() {
+            Some(rate) => assert!(rate < 0.0),
+            None => assert_eq!(2, 1),
+        }
+
+        assert_eq!(response_from(&[("2000-01-01", "100.0")]).cagr(), None);
+        assert_eq!(response_from(&[("2000-01-01", "-1.0"), ("2010-01-01", "5.0")]).cagr(), None);
+        assert_eq!(response_from(&[("2000-01-01", "1.0"), ("2000-01-01", "5.0")]).cagr(), None);
+    }
+
+    #[test]
+    fn series_observation_revisions_for() {
+        let mut resp = Response::default();
+        for (date, realtime_start, value) in [
+            ("2020-01-01", "2020-05-28", "-5.0"),
+            ("2020-01-01", "2020-04-29", "-4.8"),
+            ("2020-04-01", "2020-07-30", "-32.9"),
+            ("2020-01-01", "2020-06-25", "."),
+            ("2020-01-01", "2020-07-30", "-5.1"),
+        ].iter() {
+            resp.observations.push(DataPoint {
+                realtime_start: String::from(*realtime_start),
+                date: String::from(*date),
+                value: String::from(*value),
+                ..DataPoint::default()
+            });
+        }
+
+        assert_eq!(
+            resp.revisions_for("2020-01-01"),
+            vec![
+                (String::from("2020-04-29"), -4.8),
+                (String::from("2020-05-28"), -5.0),
+                (String::from("2020-07-30"), -5.1),
+            ]
+        );
+        assert_eq!(resp.revisions_for("2020/04/01").len(), 1);
+        assert!(resp.revisions_for("2019-01-01").is_empty());
+    }
+
+    #[test]
+    fn series_observation_validate_against() {
+        let mut series = Series::default();
+        series.units = String::from("Percent");
+        series.observation_start = String::from("1948-01-01");
+        series.observation_end = String::from("2020-03-01");
+
+        let mut resp = response_from(&[("1948-01-01", "3.4"), ("2020-03-01", "4.4")]);
+        resp.units = String::from("lin");
+        assert!(resp.validate_against(&series).is_empty());
+
+        resp.units = String::from("pc1");
+        resp.observations.push(DataPoint {
+            date: String::from("2020-04-01"),
+            value: String::from("14.7"),
+            ..DataPoint::default()
+        });
+        let warnings = resp.validate_against(&series);
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[0].contains("pc1"));
+        assert!(warnings[1].contains("2020-04-01"));
+    }
+
+    #[test]
+    fn series_observation_with_units() {
+        let mut builder = Builder::new();
+        builder
+            .observation_start("2000-01-01")
+            .units(Units::PCH);
+
+        assert_eq!(
+            builder.with_units(Units::LIN).build(),
+            Ok(String::from("&observation_start=2000-01-01"))
+        );
+        assert_eq!(
+            builder.with_units(Units::PC1).build(),
+            Ok(String::from("&observation_start=2000-01-01&units=pc1"))
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn series_observation_lag_diff() {
+        let resp = response_from(&[
+            ("2020-01-01", "1.0"),
+            ("2020-02-01", "3.0"),
+            ("2020-03-01", "."),
+            ("2020-04-01", "10.0"),
+            ("2020-05-01", "12.5"),
+        ]);
+
+        let first: Vec<Option<f64>> = resp.lag_diff(1).into_iter().map(|(_, v)| v).collect();
+        assert_eq!(first, vec![None, Some(2.0), None, None, Some(2.5)]);
+
+        let second: Vec<Option<f64>> = resp.lag_diff(2).into_iter().map(|(_, v)| v).collect();
+        assert_eq!(second, vec![None, None, None, Some(7.0), None]);
+
+        assert!(resp.lag_diff(10).iter().all(|(_, v)| v.is_none()));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn series_observation_panel_align() {
+        let monthly = response_from(&[
+            ("2020-01-01", "1.0"),
+            ("2020-02-01", "2.0"),
+            ("2020-03-01", "3.0"),
+            ("2020-04-01", "4.0"),
+        ]);
+        let quarterly = response_from(&[("2020-04-01", "10.0"), ("2020-07-01", ".")]);
+
+        let panel = Panel::align(vec![
+            (String::from("M"), monthly.to_quarterly(AggregationMethod::AVG)),
+            (String::from("Q"), quarterly.to_quarterly(AggregationMethod::AVG)),
+        ]);
+
+        let date = |y, m| NaiveDate::from_ymd_opt(y, m, 1).unwrap();
+        assert_eq!(panel.dates, vec![date(2020, 1), date(2020, 4), date(2020, 7)]);
+        assert_eq!(panel.columns[0], (String::from("M"), vec![Some(2.0), Some(4.0), None]));
+        assert_eq!(panel.columns[1], (String::from("Q"), vec![None, Some(10.0), None]));
+    }
+
+    #[test]
+    fn series_observation_correlation() {
+        let a = response_from(&[
+            ("2020-01-01", "1.0"),
+            ("2020-02-01", "2.0"),
+            ("2020-03-01", "3.0"),
+            ("2020-04-01", "."),
+            ("2020-05-01", "5.0"),
+        ]);
+        let b = response_from(&[
+            ("2020-02-01", "4.0"),
+            ("2020-03-01", "6.0"),
+            ("2020-04-01", "8.0"),
+            ("2020-05-01", "10.0"),
+            ("2020-06-01", "12.0"),
+        ]);
+        let inverse = response_from(&[
+            ("2020-01-01", "3.0"),
+            ("2020-02-01", "2.0"),
+            ("2020-03-01", "1.0"),
+        ]);
+
+        // overlapping dates are 2020-02, 2020-03 and 2020-05
+        match correlation(&a, &b) {
+            Some(r) => assert!((r - 1.0).abs() < 1e-9),
+            None => assert_eq!(2, 1),
+        }
+        match correlation(&a, &inverse) {
+            Some(r) => assert!((r + 1.0).abs() < 1e-9),
+            None => assert_eq!(2, 1),
+        }
+
+        let single = response_from(&[("2020-01-01", "1.0"), ("2021-01-01", "2.0")]);
+        assert_eq!(correlation(&a, &single), None);
+
+        let constant = response_from(&[("2020-01-01", "1.0"), ("2020-02-01", "1.0")]);
+        assert_eq!(correlation(&a, &constant), None);
+    }
+
+    #[test]
+    fn series_observation_empty() {
+        // FRED leaves the list out of some responses with no results
+        let resp: Response = match serde_json::from_str(r#"{"realtime_start":"2020-01-01","realtime_end":"2020-01-01","observation_start":"2030-01-01","observation_end":"9999-12-31","units":"lin","output_type":1,"file_type":"json","order_by":"observation_date","sort_order":"asc","count":0,"offset":0,"limit":100000}"#) {
+            Ok(resp) => resp,
             Err(msg) => {
                 println!("{}", msg);
                 assert_eq!(2, 1);
                 return
             },
         };
+        assert!(resp.observations.is_empty());
 
-        let mut builder = Builder::new();
-        builder
-            .limit(5)
-            .sort_order(SortOrder::Descending);
-
-        let resp: Response = match c.series_observation("GNPCA", Some(builder)) {
+        let resp: Response = match serde_json::from_str(r#"{"realtime_start":"2020-01-01","realtime_end":"2020-01-01","observation_start":"2030-01-01","observation_end":"9999-12-31","units":"lin","output_type":1,"file_type":"json","order_by":"observation_date","sort_order":"asc","count":0,"offset":0,"limit":100000,"observations":[]}"#) {
             Ok(resp) => resp,
             Err(msg) => {
                 println!("{}", msg);
@@ -526,10 +2803,33 @@ mod tests {
                 return
             },
         };
+        assert!(resp.observations.is_empty());
+    }
 
-        for item in resp.observations {
-            println!("{}: {}", item.date, item.value.parse::<f64>().unwrap());
-        }
-        //assert_eq!(resp.observations[0].value, String::from("1120.076"));
+    #[test]
+    fn series_observation_normalized_dates() {
+        let mut builder = Builder::new();
+        builder
+            .observation_start("2000/01/01")
+            .observation_end("12/31/2009")
+            .vintage_date("2010/1/1");
+
+        assert_eq!(
+            builder.build(),
+            Ok(String::from("&observation_start=2000-01-01&observation_end=2009-12-31&vintage_dates=2010-01-01"))
+        );
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn series_observation_into_query() {
+        let mut builder = Builder::new();
+        builder.observation_start("2000-01-01");
+        assert_eq!(builder.into_query(), Ok(String::from("&observation_start=2000-01-01")));
+
+        let mut builder = Builder::new();
+        builder
+            .observation_start("2010-01-01")
+            .observation_end("2000-01-01");
+        assert!(builder.into_query().is_err());
+    }
+}
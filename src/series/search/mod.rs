@@ -41,6 +41,10 @@
 //! }
 //! ```
 
+pub use crate::common::SortOrder;
+use crate::encode::encode_query_value;
+use crate::error::{FredError, LIMIT_EXCEEDED_ERROR_TEXT};
+
 pub mod tags;
 pub mod related_tags;
 
@@ -62,6 +66,8 @@ pub enum SearchType {
 /// [https://research.stlouisfed.org/docs/api/fred/series_search.html#order_by](https://research.stlouisfed.org/docs/api/fred/series_search.html#order_by)
 pub enum OrderBy {
     /// Default if search type is FULL_TEXT
+    /// 
+    /// Results are returned most relevant first.  FRED does not include the rank itself in the response, see [series::Response::ranked](../struct.Response.html#method.ranked).
     SearchRank,
     /// Default if search type is SERIES_ID
     SeriesId,
@@ -78,16 +84,6 @@ pub enum OrderBy {
     GroupPopularity,
 }
 
-/// Sort order options for the fred/series/search endpoint
-/// 
-/// [https://research.stlouisfed.org/docs/api/fred/series_search.html#sort_order](https://research.stlouisfed.org/docs/api/fred/series_search.html#sort_order)
-pub enum SortOrder {
-    /// Dates returned in ascending order (default)
-    Ascending,    
-    /// Dates returned in descending order
-    Descending,   
-}
-
 /// Apply result filter
 /// 
 /// This should be used in conjunction with the filter_value argument to filter results based on one (maybe more than one?) of the fields.
@@ -99,6 +95,7 @@ pub enum FilterVariable {
     SeasonalAdjustment,
 }
 
+#[derive(Clone)]
 pub struct Builder {
     option_string: String,
     include_tags: String,
@@ -128,15 +125,34 @@ impl Builder {
         }
     }
 
-    /// Returns the current arguments as a URL formatted string
-    pub(crate) fn build(mut self) -> String {
+    /// Removes all arguments from the builder so it can be reused
+    pub fn clear(&mut self) -> &mut Builder {
+        *self = Builder::new();
+        self
+    }
+
+    /// Returns the current arguments as a URL formatted string without consuming the builder
+    pub fn peek(&self) -> String {
+        let mut options = self.option_string.clone();
         if self.include_tags.len() > 0 {
-            self.option_string += format!("&tag_names={}", self.include_tags).as_str()
+            options += format!("&tag_names={}", self.include_tags).as_str()
         }
         if self.exclude_tags.len() > 0 {
-            self.option_string += format!("&exclude_tag_names={}", self.exclude_tags).as_str()
+            options += format!("&exclude_tag_names={}", self.exclude_tags).as_str()
         }
-        self.option_string
+        options
+    }
+
+    /// Returns the current arguments as a URL formatted string
+    pub(crate) fn build(self) -> String {
+        self.peek()
+    }
+
+    /// Consumes the builder and returns its arguments as a URL formatted string
+    /// 
+    /// This is the query string the client appends to the request URL, which can be logged or checked in tests without sending a request.
+    pub fn into_query(self) -> String {
+        self.build()
     }
 
     /// Adds the search_type argument to the request
@@ -161,7 +177,7 @@ impl Builder {
     /// 
     /// [https://research.stlouisfed.org/docs/api/fred/series_search.html#realtime_start](https://research.stlouisfed.org/docs/api/fred/series_search.html#realtime_start)
     pub fn realtime_start(&mut self, start_date: &str) -> &mut Builder {
-        self.option_string += format!("&realtime_start={}", start_date).as_str();
+        self.option_string += format!("&realtime_start={}", crate::common::date_arg(start_date)).as_str();
         self
     }
 
@@ -172,10 +188,12 @@ impl Builder {
     /// 
     /// [https://research.stlouisfed.org/docs/api/fred/series_search.html#realtime_end](https://research.stlouisfed.org/docs/api/fred/series_search.html#realtime_end)
     pub fn realtime_end(&mut self, end_date: &str) -> &mut Builder {
-        self.option_string += format!("&realtime_end={}", end_date).as_str();
+        self.option_string += format!("&realtime_end={}", crate::common::date_arg(end_date)).as_str();
         self
     }
 
+    realtime_period!();
+
     /// Adds a limit argument to the builder
     /// 
     /// The limit argument specifies a maximum number of observations to return.
@@ -194,6 +212,19 @@ impl Builder {
         self
     }
 
+    /// Adds a limit argument to the builder, returning Err rather than clamping
+    /// 
+    /// limit() silently clamps values above the endpoint maximum of 1,000, which returns fewer results than requested.  This function returns Err in that case so the condition can be detected.
+    /// 
+    /// # Arguments
+    /// * `num_results` - Maximum number of results to return
+    pub fn try_limit(&mut self, num_results: usize) -> Result<&mut Builder, FredError> {
+        if num_results > 1000 {
+            return Err(FredError::InvalidArgument(String::from(LIMIT_EXCEEDED_ERROR_TEXT)));
+        }
+        Ok(self.limit(num_results))
+    }
+
     /// Adds an offset argument to the builder
     /// 
     /// Adding an offset shifts the starting result number.  For example, if limit is 5 and offset is 0 then results 1-5 will be returned, but if offset was 5 then results 6-10 would be returned.
@@ -265,12 +296,7 @@ impl Builder {
     /// 
     /// [https://research.stlouisfed.org/docs/api/fred/series_search.html#sort_order](https://research.stlouisfed.org/docs/api/fred/series_search.html#sort_order)
     pub fn sort_order(&mut self, order: SortOrder) -> &mut Builder {
-        match order {
-            SortOrder::Descending => {
-                self.option_string += format!("&sort_order=desc").as_str()
-            },
-            _ => () // ASC is the default so do nothing
-        }
+        self.option_string += format!("&sort_order={}", order.as_param()).as_str();
         self
     }
 
@@ -304,7 +330,7 @@ impl Builder {
     /// 
     /// [https://research.stlouisfed.org/docs/api/fred/series_search.html#filter_value](https://research.stlouisfed.org/docs/api/fred/series_search.html#filter_value)
     pub fn filter_value(&mut self, val: &str) -> &mut Builder {
-        self.option_string += format!("&filter_value={}", val).as_str();
+        self.option_string += format!("&filter_value={}", encode_query_value(val)).as_str();
         self
     }
 
@@ -320,7 +346,7 @@ impl Builder {
         if self.include_tags.len() != 0 {
             self.include_tags.push(';');
         } 
-        self.include_tags += tag;
+        self.include_tags += encode_query_value(tag).as_str();
         self
     }
 
@@ -336,7 +362,7 @@ impl Builder {
         if self.exclude_tags.len() != 0 {
             self.exclude_tags.push(';');
         } 
-        self.exclude_tags += tag;
+        self.exclude_tags += encode_query_value(tag).as_str();
         self
     }
 
@@ -382,5 +408,52 @@ mod tests {
                 item.frequency,
             );
         }
-    } 
-}
\ No newline at end of file
+    }
+
+    #[test]
+    fn series_search_order_by_search_rank() {
+        let mut builder = Builder::new();
+        builder.order_by(OrderBy::SearchRank);
+        assert_eq!(builder.build(), "&order_by=search_rank");
+    }
+
+    #[test]
+    fn series_search_order_by_last_updated() {
+        let mut builder = Builder::new();
+        builder
+            .order_by(OrderBy::LastUpdated)
+            .sort_order(SortOrder::Descending);
+        assert_eq!(builder.build(), "&order_by=last_updated&sort_order=desc");
+    }
+
+    #[test]
+    fn series_search_filter_value_encoded() {
+        let mut builder = Builder::new();
+        builder
+            .filter_variable(FilterVariable::Units)
+            .filter_value("Index 2015=100")
+            .tag_name("état");
+
+        assert_eq!(
+            builder.build(),
+            "&filter_variable=units&filter_value=Index%202015%3D100&tag_names=%C3%A9tat"
+        );
+    }
+
+    #[test]
+    fn series_search_builder_clone() {
+        let mut template = Builder::new();
+        template
+            .limit(5)
+            .sort_order(SortOrder::Descending);
+
+        let mut gdp = template.clone();
+        gdp.tag_name("gdp");
+        let mut cpi = template.clone();
+        cpi.tag_name("cpi");
+
+        assert_eq!(gdp.build(), "&limit=5&sort_order=desc&tag_names=gdp");
+        assert_eq!(cpi.build(), "&limit=5&sort_order=desc&tag_names=cpi");
+        assert_eq!(template.build(), "&limit=5&sort_order=desc");
+    }
+}
@@ -39,6 +39,8 @@
 //! }
 //! ```
 
+pub use crate::common::SortOrder;
+
 /// Determines the order of search results
 /// 
 /// [https://research.stlouisfed.org/docs/api/fred/series_tags.html#order_by](https://research.stlouisfed.org/docs/api/fred/series_tags.html#order_by)
@@ -51,16 +53,7 @@ pub enum OrderBy {
     GroupId,
 }
 
-/// Sort order options for the fred/series/tags endpoint
-/// 
-/// [https://research.stlouisfed.org/docs/api/fred/series_tags.html#sort_order](https://research.stlouisfed.org/docs/api/fred/series_tags.html#sort_order)
-pub enum SortOrder {
-    /// Dates returned in ascending order (default)
-    Ascending,    
-    /// Dates returned in descending order
-    Descending,   
-}
-
+#[derive(Clone)]
 pub struct Builder {
     option_string: String,
 }
@@ -86,11 +79,29 @@ impl Builder {
         }
     }
 
+    /// Removes all arguments from the builder so it can be reused
+    pub fn clear(&mut self) -> &mut Builder {
+        *self = Builder::new();
+        self
+    }
+
+    /// Returns the current arguments as a URL formatted string without consuming the builder
+    pub fn peek(&self) -> String {
+        self.option_string.clone()
+    }
+
     /// Returns the current arguments as a URL formatted string
     pub(crate) fn build(self) -> String {
         self.option_string
     }
 
+    /// Consumes the builder and returns its arguments as a URL formatted string
+    /// 
+    /// This is the query string the client appends to the request URL, which can be logged or checked in tests without sending a request.
+    pub fn into_query(self) -> String {
+        self.build()
+    }
+
     /// Adds a realtime_start argument to the builder
     /// 
     /// # Arguments
@@ -98,7 +109,7 @@ impl Builder {
     /// 
     /// [https://research.stlouisfed.org/docs/api/fred/series_tags.html#realtime_start](https://research.stlouisfed.org/docs/api/fred/series_tags.html#realtime_start)
     pub fn realtime_start(&mut self, start_date: &str) -> &mut Builder {
-        self.option_string += format!("&realtime_start={}", start_date).as_str();
+        self.option_string += format!("&realtime_start={}", crate::common::date_arg(start_date)).as_str();
         self
     }
 
@@ -109,10 +120,12 @@ impl Builder {
     /// 
     /// [https://research.stlouisfed.org/docs/api/fred/series_tags.html#realtime_end](https://research.stlouisfed.org/docs/api/fred/series_tags.html#realtime_end)
     pub fn realtime_end(&mut self, end_date: &str) -> &mut Builder {
-        self.option_string += format!("&realtime_end={}", end_date).as_str();
+        self.option_string += format!("&realtime_end={}", crate::common::date_arg(end_date)).as_str();
         self
     }
 
+    realtime_period!();
+
     /// Adds the search_type argument to the request
     /// 
     /// # Arguments
@@ -147,12 +160,7 @@ impl Builder {
     /// 
     /// [https://research.stlouisfed.org/docs/api/fred/series_tags.html#sort_order]9https://research.stlouisfed.org/docs/api/fred/series_tags.html#sort_order
     pub fn sort_order(&mut self, order: SortOrder) -> &mut Builder {
-        match order {
-            SortOrder::Descending => {
-                self.option_string += format!("&sort_order=desc").as_str()
-            },
-            _ => () // ASC is the default so do nothing
-        }
+        self.option_string += format!("&sort_order={}", order.as_param()).as_str();
         self
     }
 
@@ -38,12 +38,17 @@
 //! }
 //! ```
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::fmt::{self, Display, Formatter};
 
+use crate::common::{Paged, Pagination};
+use crate::error::{FredError, LIMIT_EXCEEDED_ERROR_TEXT};
 use crate::series::Series;
 
-#[derive(Deserialize, Clone, Debug, Default)]
+#[cfg(feature = "chrono")]
+use chrono::NaiveDate;
+
+#[derive(Deserialize, Serialize, Clone, Debug, Default)]
 /// Response data structure for the fred/series/updates endpoint
 /// 
 /// [https://research.stlouisfed.org/docs/api/fred/series_updates.html] (https://research.stlouisfed.org/docs/api/fred/series_updates.html)
@@ -67,7 +72,47 @@ pub struct Response {
     /// Maximum number of results to return
     pub limit: usize,
     /// Series returned by the search
+    #[serde(default, alias = "series")]
     pub seriess: Vec<Series>,
+    /// Fields in the response that this crate does not parse yet
+    /// 
+    /// Only available with the `extra_fields` feature.
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Response {
+    /// Returns the realtime period of the response as dates, see [series::Response::realtime_range](../struct.Response.html#method.realtime_range)
+    #[cfg(feature = "chrono")]
+    pub fn realtime_range(&self) -> Result<(NaiveDate, NaiveDate), String> {
+        crate::common::realtime_range(&self.realtime_start, &self.realtime_end)
+    }
+
+    /// Returns the request arguments FRED echoed back in the response as (name, value) pairs
+    pub fn echoed_params(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("realtime_start", self.realtime_start.clone()),
+            ("realtime_end", self.realtime_end.clone()),
+            ("filter_variable", self.filter_variable.clone()),
+            ("filter_value", self.filter_value.clone()),
+            ("order_by", self.order_by.clone()),
+            ("sort_order", self.sort_order.clone()),
+            ("limit", self.limit.to_string()),
+            ("offset", self.offset.to_string()),
+        ]
+    }
+}
+
+impl Paged for Response {
+    fn pagination(&self) -> Pagination {
+        Pagination {
+            count: self.count,
+            offset: self.offset,
+            limit: self.limit,
+            returned: self.seriess.len(),
+        }
+    }
 }
 
 impl Display for Response {
@@ -98,6 +143,7 @@ pub enum FilterValue {
     All,
 }
 
+#[derive(Clone)]
 pub struct Builder {
     option_string: String,
 }
@@ -123,13 +169,29 @@ impl Builder {
         }
     }
 
+    /// Removes all arguments from the builder so it can be reused
+    pub fn clear(&mut self) -> &mut Builder {
+        *self = Builder::new();
+        self
+    }
+
+    /// Returns the current arguments as a URL formatted string without consuming the builder
+    pub fn peek(&self) -> String {
+        self.option_string.clone()
+    }
+
     /// Returns the current arguments as a URL formatted string
-    /// 
-    /// Returns Err if there are no tag names specified using tag_name().
     pub(crate) fn build(self) -> String {
         self.option_string
     }
 
+    /// Consumes the builder and returns its arguments as a URL formatted string
+    /// 
+    /// This is the query string the client appends to the request URL, which can be logged or checked in tests without sending a request.
+    pub fn into_query(self) -> String {
+        self.build()
+    }
+
     /// Adds a realtime_start argument to the builder
     /// 
     /// # Arguments
@@ -137,7 +199,7 @@ impl Builder {
     /// 
     /// [https://research.stlouisfed.org/docs/api/fred/series_updates.html#realtime_start](https://research.stlouisfed.org/docs/api/fred/series_updates.html#realtime_start)
     pub fn realtime_start(&mut self, start_date: &str) -> &mut Builder {
-        self.option_string += format!("&realtime_start={}", start_date).as_str();
+        self.option_string += format!("&realtime_start={}", crate::common::date_arg(start_date)).as_str();
         self
     }
 
@@ -146,10 +208,12 @@ impl Builder {
     /// # Arguments
     /// * `end_date` - date formatted as YYYY-MM-DD
     pub fn realtime_end(&mut self, end_date: &str) -> &mut Builder {
-        self.option_string += format!("&realtime_end={}", end_date).as_str();
+        self.option_string += format!("&realtime_end={}", crate::common::date_arg(end_date)).as_str();
         self
     }
 
+    realtime_period!();
+
     /// Adds a limit argument to the builder
     /// 
     /// The limit argument specifies a maximum number of observations to return.
@@ -168,6 +232,19 @@ impl Builder {
         self
     }
 
+    /// Adds a limit argument to the builder, returning Err rather than clamping
+    /// 
+    /// limit() silently clamps values above the endpoint maximum of 1,000, which returns fewer results than requested.  This function returns Err in that case so the condition can be detected.
+    /// 
+    /// # Arguments
+    /// * `num_results` - Maximum number of results to return
+    pub fn try_limit(&mut self, num_results: usize) -> Result<&mut Builder, FredError> {
+        if num_results > 1000 {
+            return Err(FredError::InvalidArgument(String::from(LIMIT_EXCEEDED_ERROR_TEXT)));
+        }
+        Ok(self.limit(num_results))
+    }
+
     /// Adds an offset argument to the builder
     /// 
     /// Adding an offset shifts the starting result number.  For example, if limit is 5 and offset is 0 then results 1-5 will be returned, but if offset was 5 then results 6-10 would be returned.
@@ -259,5 +336,29 @@ mod tests {
                 item.popularity,
             );
         }
-    } 
-}
\ No newline at end of file
+    }
+
+    #[test]
+    fn series_updates_empty() {
+        // FRED leaves the list out of some responses with no results
+        let resp: Response = match serde_json::from_str(r#"{"realtime_start":"2020-01-01","realtime_end":"2020-01-01","filter_variable":"geography","filter_value":"all","order_by":"last_updated","sort_order":"desc","count":0,"offset":0,"limit":1000}"#) {
+            Ok(resp) => resp,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+        assert!(resp.seriess.is_empty());
+
+        let resp: Response = match serde_json::from_str(r#"{"realtime_start":"2020-01-01","realtime_end":"2020-01-01","filter_variable":"geography","filter_value":"all","order_by":"last_updated","sort_order":"desc","count":0,"offset":0,"limit":1000,"seriess":[]}"#) {
+            Ok(resp) => resp,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+        assert!(resp.seriess.is_empty());
+    }
+}
@@ -34,10 +34,17 @@
 //! }
 //! ```
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::fmt::{self, Display, Formatter};
 
-#[derive(Deserialize, Clone, Debug, Default)]
+use crate::common::{Paged, Pagination};
+pub use crate::common::SortOrder;
+use crate::error::{FredError, LIMIT_EXCEEDED_ERROR_TEXT};
+
+#[cfg(feature = "chrono")]
+use chrono::NaiveDate;
+
+#[derive(Deserialize, Serialize, Clone, Debug, Default)]
 /// Response data structure for the fred/series/vintagedates endpoint
 /// 
 /// [https://research.stlouisfed.org/docs/api/fred/series_vintagedates.html] (https://research.stlouisfed.org/docs/api/fred/series_vintagedates.html)
@@ -56,8 +63,57 @@ pub struct Response {
     pub offset: usize,
     /// Maximum number of results to return
     pub limit: usize,
-    /// Series returned by the search
+    /// The dates on which the series was revised or new data was released (YYYY-MM-DD)
+    #[serde(default, alias = "vintage_date")]
     pub vintage_dates: Vec<String>,
+    /// Fields in the response that this crate does not parse yet
+    /// 
+    /// Only available with the `extra_fields` feature.
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Response {
+    /// Returns the realtime period of the response as dates, see [series::Response::realtime_range](../struct.Response.html#method.realtime_range)
+    #[cfg(feature = "chrono")]
+    pub fn realtime_range(&self) -> Result<(NaiveDate, NaiveDate), String> {
+        crate::common::realtime_range(&self.realtime_start, &self.realtime_end)
+    }
+
+    /// Returns the vintage dates parsed into dates, in the order FRED returned them
+    /// 
+    /// Dates that cannot be parsed are skipped.
+    #[cfg(feature = "chrono")]
+    pub fn dates_parsed(&self) -> Vec<NaiveDate> {
+        self.vintage_dates
+            .iter()
+            .filter_map(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())
+            .collect()
+    }
+
+    /// Returns the request arguments FRED echoed back in the response as (name, value) pairs
+    pub fn echoed_params(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("realtime_start", self.realtime_start.clone()),
+            ("realtime_end", self.realtime_end.clone()),
+            ("order_by", self.order_by.clone()),
+            ("sort_order", self.sort_order.clone()),
+            ("limit", self.limit.to_string()),
+            ("offset", self.offset.to_string()),
+        ]
+    }
+}
+
+impl Paged for Response {
+    fn pagination(&self) -> Pagination {
+        Pagination {
+            count: self.count,
+            offset: self.offset,
+            limit: self.limit,
+            returned: self.vintage_dates.len(),
+        }
+    }
 }
 
 impl Display for Response {
@@ -76,16 +132,7 @@ impl Display for Response {
     }
 }
 
-/// Sort order options for the fred/series/vintagedates endpoint
-/// 
-/// [https://research.stlouisfed.org/docs/api/fred/series_vintagedates.html#sort_order](https://research.stlouisfed.org/docs/api/fred/series_vintagedates.html#sort_order)
-pub enum SortOrder {
-    /// Dates returned in ascending order (default)
-    Ascending,    
-    /// Dates returned in descending order
-    Descending,   
-}
-
+#[derive(Clone)]
 pub struct Builder {
     option_string: String,
 }
@@ -111,13 +158,29 @@ impl Builder {
         }
     }
 
+    /// Removes all arguments from the builder so it can be reused
+    pub fn clear(&mut self) -> &mut Builder {
+        *self = Builder::new();
+        self
+    }
+
+    /// Returns the current arguments as a URL formatted string without consuming the builder
+    pub fn peek(&self) -> String {
+        self.option_string.clone()
+    }
+
     /// Returns the current arguments as a URL formatted string
-    /// 
-    /// Returns Err if there are not tag names specified using tag_name().
     pub(crate) fn build(self) -> String {
         self.option_string
     }
 
+    /// Consumes the builder and returns its arguments as a URL formatted string
+    /// 
+    /// This is the query string the client appends to the request URL, which can be logged or checked in tests without sending a request.
+    pub fn into_query(self) -> String {
+        self.build()
+    }
+
     /// Adds a realtime_start argument to the builder
     /// 
     /// # Arguments
@@ -125,7 +188,7 @@ impl Builder {
     /// 
     /// [https://research.stlouisfed.org/docs/api/fred/series_vintagedates.html#realtime_start](https://research.stlouisfed.org/docs/api/fred/series_vintagedates.html#realtime_start)
     pub fn realtime_start(&mut self, start_date: &str) -> &mut Builder {
-        self.option_string += format!("&realtime_start={}", start_date).as_str();
+        self.option_string += format!("&realtime_start={}", crate::common::date_arg(start_date)).as_str();
         self
     }
 
@@ -136,10 +199,12 @@ impl Builder {
     /// 
     /// [https://research.stlouisfed.org/docs/api/fred/series_vintagedates.html#realtime_end](https://research.stlouisfed.org/docs/api/fred/series_vintagedates.html#realtime_end)
     pub fn realtime_end(&mut self, end_date: &str) -> &mut Builder {
-        self.option_string += format!("&realtime_end={}", end_date).as_str();
+        self.option_string += format!("&realtime_end={}", crate::common::date_arg(end_date)).as_str();
         self
     }
 
+    realtime_period!();
+
     /// Adds a limit argument to the builder
     /// 
     /// The limit argument specifies a maximum number of observations to return.
@@ -149,7 +214,7 @@ impl Builder {
     /// 
     /// [https://research.stlouisfed.org/docs/api/fred/series_vintagedates.html#limit](https://research.stlouisfed.org/docs/api/fred/series_vintagedates.html#limit)
     pub fn limit(&mut self, num_results: usize) -> &mut Builder {
-        let num_results = if num_results > 10000 { // max value is 1000
+        let num_results = if num_results > 10000 { // max value is 10000
             10000
         } else {
             num_results
@@ -158,6 +223,19 @@ impl Builder {
         self
     }
 
+    /// Adds a limit argument to the builder, returning Err rather than clamping
+    /// 
+    /// limit() silently clamps values above the endpoint maximum of 10,000, which returns fewer results than requested.  This function returns Err in that case so the condition can be detected.
+    /// 
+    /// # Arguments
+    /// * `num_results` - Maximum number of results to return
+    pub fn try_limit(&mut self, num_results: usize) -> Result<&mut Builder, FredError> {
+        if num_results > 10000 {
+            return Err(FredError::InvalidArgument(String::from(LIMIT_EXCEEDED_ERROR_TEXT)));
+        }
+        Ok(self.limit(num_results))
+    }
+
     /// Adds an offset argument to the builder
     /// 
     /// Adding an offset shifts the starting result number.  For example, if limit is 5 and offset is 0 then results 1-5 will be returned, but if offset was 5 then results 6-10 would be returned.
@@ -178,12 +256,7 @@ impl Builder {
     /// 
     /// [https://research.stlouisfed.org/docs/api/fred/series_vintagedates.html#sort_order](https://research.stlouisfed.org/docs/api/fred/series_vintagedates.html#sort_order)
     pub fn sort_order(&mut self, order: SortOrder) -> &mut Builder {
-        match order {
-            SortOrder::Descending => {
-                self.option_string += format!("&sort_order=desc").as_str()
-            },
-            _ => () // ASC is the default so do nothing
-        }
+        self.option_string += format!("&sort_order={}", order.as_param()).as_str();
         self
     }
 
@@ -222,5 +295,48 @@ mod tests {
         for item in resp.vintage_dates {
             println!("{}", item);
         }
-    } 
-}
\ No newline at end of file
+    }
+
+    #[test]
+    fn series_vintagedates_empty() {
+        // FRED leaves the list out of some responses with no results
+        let resp: Response = match serde_json::from_str(r#"{"realtime_start":"2020-01-01","realtime_end":"2020-01-01","order_by":"series_id","sort_order":"asc","count":0,"offset":0,"limit":1000}"#) {
+            Ok(resp) => resp,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+        assert!(resp.vintage_dates.is_empty());
+
+        let resp: Response = match serde_json::from_str(r#"{"realtime_start":"2020-01-01","realtime_end":"2020-01-01","order_by":"series_id","sort_order":"asc","count":0,"offset":0,"limit":1000,"vintage_dates":[]}"#) {
+            Ok(resp) => resp,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+        assert!(resp.vintage_dates.is_empty());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn series_vintagedates_dates_parsed() {
+        let mut resp = Response::default();
+        resp.vintage_dates = vec![
+            String::from("1958-12-21"),
+            String::from("1959-02-19"),
+            String::from("."),
+        ];
+
+        assert_eq!(
+            resp.dates_parsed(),
+            vec![
+                NaiveDate::from_ymd_opt(1958, 12, 21).unwrap(),
+                NaiveDate::from_ymd_opt(1959, 2, 19).unwrap(),
+            ]
+        );
+    }
+}
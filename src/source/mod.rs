@@ -36,10 +36,15 @@ pub mod releases;
 
 // -----------------------------------------------------------------------------
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::fmt::{self, Display, Formatter};
 
-#[derive(Deserialize, Clone, Debug, Default)]
+use crate::common::{Paged, Pagination};
+
+#[cfg(feature = "chrono")]
+use chrono::NaiveDate;
+
+#[derive(Deserialize, Serialize, Clone, Debug, Default)]
 /// Response data structure for the fred/source endpoint
 /// 
 /// [https://research.stlouisfed.org/docs/api/fred/source.html] (https://research.stlouisfed.org/docs/api/fred/source.html)
@@ -59,7 +64,54 @@ pub struct Response {
     /// Maximum number of results to return
     pub limit: Option<usize>,
     /// Series returned by the search
+    #[serde(default, alias = "source")]
     pub sources: Vec<Source>,
+    /// Fields in the response that this crate does not parse yet
+    /// 
+    /// Only available with the `extra_fields` feature.
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Response {
+    /// Returns the realtime period of the response as dates, see [series::Response::realtime_range](../series/struct.Response.html#method.realtime_range)
+    #[cfg(feature = "chrono")]
+    pub fn realtime_range(&self) -> Result<(NaiveDate, NaiveDate), String> {
+        crate::common::realtime_range(&self.realtime_start, &self.realtime_end)
+    }
+
+    /// Returns the request arguments FRED echoed back in the response as (name, value) pairs
+    pub fn echoed_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = vec![
+            ("realtime_start", self.realtime_start.clone()),
+            ("realtime_end", self.realtime_end.clone()),
+        ];
+        if let Some(v) = &self.order_by {
+            params.push(("order_by", v.clone()));
+        }
+        if let Some(v) = &self.sort_order {
+            params.push(("sort_order", v.clone()));
+        }
+        if let Some(v) = &self.limit {
+            params.push(("limit", v.to_string()));
+        }
+        if let Some(v) = &self.offset {
+            params.push(("offset", v.to_string()));
+        }
+        params
+    }
+}
+
+impl Paged for Response {
+    fn pagination(&self) -> Pagination {
+        Pagination {
+            count: self.count.unwrap_or(0),
+            offset: self.offset.unwrap_or(0),
+            limit: self.limit.unwrap_or(0),
+            returned: self.sources.len(),
+        }
+    }
 }
 
 impl Display for Response {
@@ -78,7 +130,7 @@ impl Display for Response {
     }
 }
 
-#[derive(Deserialize, Clone, Debug, Default)]
+#[derive(Deserialize, Serialize, Clone, Debug, Default)]
 /// Data structure containing infomation about a particular tag
 /// 
 /// [https://research.stlouisfed.org/docs/api/fred/source.html](https://research.stlouisfed.org/docs/api/fred/source.html)
@@ -103,6 +155,7 @@ impl Display for Source {
     }
 }
 
+#[derive(Clone)]
 pub struct Builder {
     option_string: String,
 }
@@ -128,11 +181,29 @@ impl Builder {
         }
     }
 
+    /// Removes all arguments from the builder so it can be reused
+    pub fn clear(&mut self) -> &mut Builder {
+        *self = Builder::new();
+        self
+    }
+
+    /// Returns the current arguments as a URL formatted string without consuming the builder
+    pub fn peek(&self) -> String {
+        self.option_string.clone()
+    }
+
     /// Returns the current arguments as a URL formatted string
     pub(crate) fn build(self) -> String {
         self.option_string
     }
 
+    /// Consumes the builder and returns its arguments as a URL formatted string
+    /// 
+    /// This is the query string the client appends to the request URL, which can be logged or checked in tests without sending a request.
+    pub fn into_query(self) -> String {
+        self.build()
+    }
+
     /// Adds a realtime_start argument to the builder
     /// 
     /// # Arguments
@@ -140,7 +211,7 @@ impl Builder {
     /// 
     /// [https://research.stlouisfed.org/docs/api/fred/source.html#realtime_start](https://research.stlouisfed.org/docs/api/fred/source.html#realtime_start)
     pub fn realtime_start(&mut self, start_date: &str) -> &mut Builder {
-        self.option_string += format!("&realtime_start={}", start_date).as_str();
+        self.option_string += format!("&realtime_start={}", crate::common::date_arg(start_date)).as_str();
         self
     }
 
@@ -151,10 +222,12 @@ impl Builder {
     /// 
     /// [https://research.stlouisfed.org/docs/api/fred/source.html#realtime_end](https://research.stlouisfed.org/docs/api/fred/source.html#realtime_end)
     pub fn realtime_end(&mut self, end_date: &str) -> &mut Builder {
-        self.option_string += format!("&realtime_end={}", end_date).as_str();
+        self.option_string += format!("&realtime_end={}", crate::common::date_arg(end_date)).as_str();
         self
     }
 
+    realtime_period!();
+
 }
 
 #[cfg(test)]
@@ -188,5 +261,29 @@ mod tests {
                 None => println!("{}", s.name)
             }
         }
-    } 
-}
\ No newline at end of file
+    }
+
+    #[test]
+    fn source_empty() {
+        // FRED leaves the list out of some responses with no results
+        let resp: Response = match serde_json::from_str(r#"{"realtime_start":"2020-01-01","realtime_end":"2020-01-01","order_by":"series_id","sort_order":"asc","count":0,"offset":0,"limit":1000}"#) {
+            Ok(resp) => resp,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+        assert!(resp.sources.is_empty());
+
+        let resp: Response = match serde_json::from_str(r#"{"realtime_start":"2020-01-01","realtime_end":"2020-01-01","order_by":"series_id","sort_order":"asc","count":0,"offset":0,"limit":1000,"sources":[]}"#) {
+            Ok(resp) => resp,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+        assert!(resp.sources.is_empty());
+    }
+}
@@ -38,6 +38,9 @@
 //! }
 //! ```
 
+pub use crate::common::SortOrder;
+use crate::error::{FredError, LIMIT_EXCEEDED_ERROR_TEXT};
+
 /// Determines the order of search results
 /// 
 /// [https://research.stlouisfed.org/docs/api/fred/sources.html#order_by](https://research.stlouisfed.org/docs/api/fred/sources.html#order_by)
@@ -49,16 +52,7 @@ pub enum OrderBy {
     RealtimeEnd,
 }
 
-/// Sort order options for the fred/sources endpoint
-/// 
-/// [https://research.stlouisfed.org/docs/api/fred/sources.html#sort_order](https://research.stlouisfed.org/docs/api/fred/sources.html#sort_order)
-pub enum SortOrder {
-    /// Dates returned in ascending order (default)
-    Ascending,    
-    /// Dates returned in descending order
-    Descending,   
-}
-
+#[derive(Clone)]
 pub struct Builder {
     option_string: String,
 }
@@ -84,11 +78,29 @@ impl Builder {
         }
     }
 
+    /// Removes all arguments from the builder so it can be reused
+    pub fn clear(&mut self) -> &mut Builder {
+        *self = Builder::new();
+        self
+    }
+
+    /// Returns the current arguments as a URL formatted string without consuming the builder
+    pub fn peek(&self) -> String {
+        self.option_string.clone()
+    }
+
     /// Returns the current arguments as a URL formatted string
     pub(crate) fn build(self) -> String {
         self.option_string
     }
 
+    /// Consumes the builder and returns its arguments as a URL formatted string
+    /// 
+    /// This is the query string the client appends to the request URL, which can be logged or checked in tests without sending a request.
+    pub fn into_query(self) -> String {
+        self.build()
+    }
+
     /// Adds a realtime_start argument to the builder
     /// 
     /// # Arguments
@@ -96,7 +108,7 @@ impl Builder {
     /// 
     /// [https://research.stlouisfed.org/docs/api/fred/sources.html#realtime_start](https://research.stlouisfed.org/docs/api/fred/sources.html#realtime_start)
     pub fn realtime_start(&mut self, start_date: &str) -> &mut Builder {
-        self.option_string += format!("&realtime_start={}", start_date).as_str();
+        self.option_string += format!("&realtime_start={}", crate::common::date_arg(start_date)).as_str();
         self
     }
 
@@ -107,10 +119,12 @@ impl Builder {
     /// 
     /// [https://research.stlouisfed.org/docs/api/fred/sources.html#realtime_end](https://research.stlouisfed.org/docs/api/fred/sources.html#realtime_end)
     pub fn realtime_end(&mut self, end_date: &str) -> &mut Builder {
-        self.option_string += format!("&realtime_end={}", end_date).as_str();
+        self.option_string += format!("&realtime_end={}", crate::common::date_arg(end_date)).as_str();
         self
     }
 
+    realtime_period!();
+
     /// Adds a limit argument to the builder
     /// 
     /// The limit argument specifies a maximum number of observations to return.
@@ -129,6 +143,19 @@ impl Builder {
         self
     }
 
+    /// Adds a limit argument to the builder, returning Err rather than clamping
+    /// 
+    /// limit() silently clamps values above the endpoint maximum of 1,000, which returns fewer results than requested.  This function returns Err in that case so the condition can be detected.
+    /// 
+    /// # Arguments
+    /// * `num_results` - Maximum number of results to return
+    pub fn try_limit(&mut self, num_results: usize) -> Result<&mut Builder, FredError> {
+        if num_results > 1000 {
+            return Err(FredError::InvalidArgument(String::from(LIMIT_EXCEEDED_ERROR_TEXT)));
+        }
+        Ok(self.limit(num_results))
+    }
+
     /// Adds an offset argument to the builder
     /// 
     /// Adding an offset shifts the starting result number.  For example, if limit is 5 and offset is 0 then results 1-5 will be returned, but if offset was 5 then results 6-10 would be returned.
@@ -175,12 +202,7 @@ impl Builder {
     /// 
     /// [https://research.stlouisfed.org/docs/api/fred/sources.html#sort_order](https://research.stlouisfed.org/docs/api/fred/sources.html#sort_order)
     pub fn sort_order(&mut self, order: SortOrder) -> &mut Builder {
-        match order {
-            SortOrder::Descending => {
-                self.option_string += format!("&sort_order=desc").as_str()
-            },
-            _ => () // ASC is the default so do nothing
-        }
+        self.option_string += format!("&sort_order={}", order.as_param()).as_str();
         self
     }
 
@@ -235,5 +257,22 @@ mod tests {
             }
             
         }
-    } 
+    }
+
+    #[test]
+    fn sources_try_limit() {
+        let mut builder = Builder::new();
+        match builder.try_limit(1001) {
+            Err(FredError::InvalidArgument(msg)) => assert_eq!(msg.as_str(), LIMIT_EXCEEDED_ERROR_TEXT),
+            _ => assert_eq!(2, 1),
+        }
+        match builder.try_limit(1000) {
+            Ok(_) => (),
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1)
+            },
+        }
+        assert_eq!(builder.build(), "&limit=1000");
+    }
 }
\ No newline at end of file
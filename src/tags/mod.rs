@@ -44,10 +44,19 @@ pub mod series;
 
 // -----------------------------------------------------------------------------
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fmt::{self, Display, Formatter};
 
-#[derive(Deserialize, Clone, Debug, Default)]
+use crate::common::{Paged, Pagination};
+pub use crate::common::SortOrder;
+use crate::encode::encode_query_value;
+use crate::error::{FredError, LIMIT_EXCEEDED_ERROR_TEXT};
+
+#[cfg(feature = "chrono")]
+use chrono::NaiveDate;
+
+#[derive(Deserialize, Serialize, Clone, Debug, Default)]
 /// Response data structure for the fred/tags endpoint
 /// 
 /// [https://research.stlouisfed.org/docs/api/fred/tags.html] (https://research.stlouisfed.org/docs/api/fred/tags.html)
@@ -67,7 +76,56 @@ pub struct Response {
     /// Maximum number of results to return
     pub limit: usize,
     /// Series returned by the search
+    #[serde(default, alias = "tag")]
     pub tags: Vec<Tag>,
+    /// Fields in the response that this crate does not parse yet
+    /// 
+    /// Only available with the `extra_fields` feature.
+    #[cfg(feature = "extra_fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Response {
+    /// Returns the realtime period of the response as dates, see [series::Response::realtime_range](../series/struct.Response.html#method.realtime_range)
+    #[cfg(feature = "chrono")]
+    pub fn realtime_range(&self) -> Result<(NaiveDate, NaiveDate), String> {
+        crate::common::realtime_range(&self.realtime_start, &self.realtime_end)
+    }
+
+    /// Returns the request arguments FRED echoed back in the response as (name, value) pairs
+    pub fn echoed_params(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("realtime_start", self.realtime_start.clone()),
+            ("realtime_end", self.realtime_end.clone()),
+            ("order_by", self.order_by.clone()),
+            ("sort_order", self.sort_order.clone()),
+            ("limit", self.limit.to_string()),
+            ("offset", self.offset.to_string()),
+        ]
+    }
+
+    /// Returns the tags grouped by their group_id (e.g. freq, geo, src)
+    /// 
+    /// Tags keep the order they were returned in within each group.
+    pub fn group_by(&self) -> BTreeMap<String, Vec<&Tag>> {
+        let mut groups: BTreeMap<String, Vec<&Tag>> = BTreeMap::new();
+        for tag in self.tags.iter() {
+            groups.entry(tag.group_id.clone()).or_default().push(tag);
+        }
+        groups
+    }
+}
+
+impl Paged for Response {
+    fn pagination(&self) -> Pagination {
+        Pagination {
+            count: self.count,
+            offset: self.offset,
+            limit: self.limit,
+            returned: self.tags.len(),
+        }
+    }
 }
 
 impl Display for Response {
@@ -86,7 +144,7 @@ impl Display for Response {
     }
 }
 
-#[derive(Deserialize, Clone, Debug, Default)]
+#[derive(Deserialize, Serialize, Clone, Debug, Default)]
 /// Data structure containing infomation about a particular tag
 /// 
 /// [https://research.stlouisfed.org/docs/api/fred/tags.html](https://research.stlouisfed.org/docs/api/fred/tags.html)
@@ -137,16 +195,7 @@ pub enum OrderBy {
     GroupId,
 }
 
-/// Sort order options for the fred/series/tags endpoint
-/// 
-/// [https://research.stlouisfed.org/docs/api/fred/tags.html#sort_order](https://research.stlouisfed.org/docs/api/fred/tags.html#sort_order)
-pub enum SortOrder {
-    /// Dates returned in ascending order (default)
-    Ascending,    
-    /// Dates returned in descending order
-    Descending,   
-}
-
+#[derive(Clone)]
 pub struct Builder {
     option_string: String,
     tag_names: String,
@@ -174,12 +223,31 @@ impl Builder {
         }
     }
 
-    /// Returns the current arguments as a URL formatted string
-    pub(crate) fn build(mut self) -> String {
+    /// Removes all arguments from the builder so it can be reused
+    pub fn clear(&mut self) -> &mut Builder {
+        *self = Builder::new();
+        self
+    }
+
+    /// Returns the current arguments as a URL formatted string without consuming the builder
+    pub fn peek(&self) -> String {
+        let mut options = self.option_string.clone();
         if self.tag_names.len() > 0 {
-            self.option_string += format!("&tag_names={}", self.tag_names).as_str()
+            options += format!("&tag_names={}", self.tag_names).as_str()
         }
-        self.option_string
+        options
+    }
+
+    /// Returns the current arguments as a URL formatted string
+    pub(crate) fn build(self) -> String {
+        self.peek()
+    }
+
+    /// Consumes the builder and returns its arguments as a URL formatted string
+    /// 
+    /// This is the query string the client appends to the request URL, which can be logged or checked in tests without sending a request.
+    pub fn into_query(self) -> String {
+        self.build()
     }
 
     /// Adds a realtime_start argument to the builder
@@ -189,7 +257,7 @@ impl Builder {
     /// 
     /// [https://research.stlouisfed.org/docs/api/fred/tags.html#realtime_start](https://research.stlouisfed.org/docs/api/fred/tags.html#realtime_start)
     pub fn realtime_start(&mut self, start_date: &str) -> &mut Builder {
-        self.option_string += format!("&realtime_start={}", start_date).as_str();
+        self.option_string += format!("&realtime_start={}", crate::common::date_arg(start_date)).as_str();
         self
     }
 
@@ -200,13 +268,15 @@ impl Builder {
     /// 
     /// [https://research.stlouisfed.org/docs/api/fred/tags.html#realtime_end](https://research.stlouisfed.org/docs/api/fred/tags.html#realtime_end)
     pub fn realtime_end(&mut self, end_date: &str) -> &mut Builder {
-        self.option_string += format!("&realtime_end={}", end_date).as_str();
+        self.option_string += format!("&realtime_end={}", crate::common::date_arg(end_date)).as_str();
         self
     }
 
+    realtime_period!();
+
     /// Adds a tag name to include in the search
     /// 
-    /// Results must match all included tag names.
+    /// Results must match all included tag names.  The fred/tags endpoint cannot exclude tag names, use [related_tags::Builder::exclude_tag](../related_tags/struct.Builder.html#method.exclude_tag) to leave tags out of the results.
     /// 
     /// # Arguments
     /// * `tag` - tag name to add
@@ -216,7 +286,7 @@ impl Builder {
         if self.tag_names.len() != 0 {
             self.tag_names.push(';');
         } 
-        self.tag_names += tag;
+        self.tag_names += encode_query_value(tag).as_str();
         self
     }
 
@@ -262,8 +332,10 @@ impl Builder {
     /// * `text` - text to search against
     /// 
     /// [https://research.stlouisfed.org/docs/api/fred/tags.html#search_text](https://research.stlouisfed.org/docs/api/fred/tags.html#search_text)
-    pub fn search_text(&mut self, text: &str) {
+    pub fn search_text(&mut self, text: &str) -> &mut Builder {
+        let text = encode_query_value(text);
         self.option_string += format!("&search_text={}", text).as_str();
+        self
     }
 
     /// Adds a limit argument to the builder
@@ -284,6 +356,19 @@ impl Builder {
         self
     }
 
+    /// Adds a limit argument to the builder, returning Err rather than clamping
+    /// 
+    /// limit() silently clamps values above the endpoint maximum of 1,000, which returns fewer results than requested.  This function returns Err in that case so the condition can be detected.
+    /// 
+    /// # Arguments
+    /// * `num_results` - Maximum number of results to return
+    pub fn try_limit(&mut self, num_results: usize) -> Result<&mut Builder, FredError> {
+        if num_results > 1000 {
+            return Err(FredError::InvalidArgument(String::from(LIMIT_EXCEEDED_ERROR_TEXT)));
+        }
+        Ok(self.limit(num_results))
+    }
+
     /// Adds an offset argument to the builder
     /// 
     /// Adding an offset shifts the starting result number.  For example, if limit is 5 and offset is 0 then results 1-5 will be returned, but if offset was 5 then results 6-10 would be returned.
@@ -331,12 +416,7 @@ impl Builder {
     /// 
     /// [https://research.stlouisfed.org/docs/api/fred/tags.html#sort_order](https://research.stlouisfed.org/docs/api/fred/tags.html#sort_order)
     pub fn sort_order(&mut self, order: SortOrder) -> &mut Builder {
-        match order {
-            SortOrder::Descending => {
-                self.option_string += format!("&sort_order=desc").as_str()
-            },
-            _ => () // ASC is the default so do nothing
-        }
+        self.option_string += format!("&sort_order={}", order.as_param()).as_str();
         self
     }
 
@@ -380,5 +460,97 @@ mod tests {
                 item.created,
             );
         }
-    } 
-}
\ No newline at end of file
+    }
+
+    #[test]
+    fn tags_search_text_chain() {
+        let mut builder = Builder::new();
+        builder
+            .search_text("consumer price")
+            .limit(5)
+            .order_by(OrderBy::Popularity);
+
+        assert_eq!(
+            builder.build(),
+            "&search_text=consumer%20price&limit=5&order_by=popularity"
+        );
+    }
+
+    #[test]
+    fn tags_peek_and_clear() {
+        let mut builder = Builder::new();
+        builder
+            .tag_name("usa")
+            .limit(5);
+
+        assert_eq!(builder.peek(), "&limit=5&tag_names=usa");
+        // peek does not consume the builder so it can keep accumulating
+        builder.tag_name("gdp");
+        assert_eq!(builder.peek(), "&limit=5&tag_names=usa;gdp");
+
+        builder.clear().tag_name("trade");
+        assert_eq!(builder.build(), "&tag_names=trade");
+    }
+
+    #[test]
+    fn tags_sort_order() {
+        let mut builder = Builder::new();
+        builder
+            .sort_order(SortOrder::Ascending)
+            .sort_order(crate::common::SortOrder::Descending);
+
+        assert_eq!(builder.build(), "&sort_order=asc&sort_order=desc");
+    }
+
+    #[test]
+    fn tags_group_by() {
+        let mut resp = Response::default();
+        for (name, group_id) in [("monthly", "freq"), ("usa", "geo"), ("annual", "freq")].iter() {
+            resp.tags.push(Tag {
+                name: String::from(*name),
+                group_id: String::from(*group_id),
+                ..Tag::default()
+            });
+        }
+
+        let groups = resp.group_by();
+        assert_eq!(groups.len(), 2);
+        let freq: Vec<&str> = groups["freq"].iter().map(|tag| tag.name.as_str()).collect();
+        assert_eq!(freq, vec!["monthly", "annual"]);
+        assert_eq!(groups["geo"][0].name, "usa");
+    }
+
+    #[test]
+    fn tags_empty() {
+        // FRED leaves the list out of some responses with no results
+        let resp: Response = match serde_json::from_str(r#"{"realtime_start":"2020-01-01","realtime_end":"2020-01-01","order_by":"series_id","sort_order":"asc","count":0,"offset":0,"limit":1000}"#) {
+            Ok(resp) => resp,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+        assert!(resp.tags.is_empty());
+
+        let resp: Response = match serde_json::from_str(r#"{"realtime_start":"2020-01-01","realtime_end":"2020-01-01","order_by":"series_id","sort_order":"asc","count":0,"offset":0,"limit":1000,"tags":[]}"#) {
+            Ok(resp) => resp,
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+                return
+            },
+        };
+        assert!(resp.tags.is_empty());
+    }
+
+    #[test]
+    fn tags_into_query() {
+        let mut builder = Builder::new();
+        builder
+            .limit(5)
+            .sort_order(SortOrder::Descending);
+
+        assert_eq!(builder.into_query(), "&limit=5&sort_order=desc");
+    }
+}
@@ -41,7 +41,9 @@
 //! }
 //! ```
 
-use crate::error::TAG_NAME_REQUIRED_ERROR_TEXT;
+pub use crate::common::SortOrder;
+use crate::encode::encode_query_value;
+use crate::error::{FredError, LIMIT_EXCEEDED_ERROR_TEXT, TAG_NAME_REQUIRED_ERROR_TEXT};
 
 /// Determines the type of search to perform
 /// 
@@ -73,16 +75,6 @@ pub enum OrderBy {
     GroupPopularity,
 }
 
-/// Sort order options for the fred/tags/series endpoint
-/// 
-/// [https://research.stlouisfed.org/docs/api/fred/tags_series.html#sort_order](https://research.stlouisfed.org/docs/api/fred/tags_series.html#sort_order)
-pub enum SortOrder {
-    /// Dates returned in ascending order (default)
-    Ascending,    
-    /// Dates returned in descending order
-    Descending,   
-}
-
 /// Apply result filter
 /// 
 /// This should be used in conjunction with the filter_value argument to filter results based on one (maybe more than one?) of the fields.
@@ -94,6 +86,7 @@ pub enum FilterVariable {
     SeasonalAdjustment,
 }
 
+#[derive(Clone)]
 pub struct Builder {
     option_string: String,
     tag_names: String,
@@ -123,17 +116,36 @@ impl Builder {
         }
     }
 
-    /// Returns the current arguments as a URL formatted string
-    pub(crate) fn build(mut self) -> Result<String, String> {
+    /// Removes all arguments from the builder so it can be reused
+    pub fn clear(&mut self) -> &mut Builder {
+        *self = Builder::new();
+        self
+    }
+
+    /// Returns the current arguments as a URL formatted string without consuming the builder
+    pub fn peek(&self) -> Result<String, String> {
+        let mut options = self.option_string.clone();
         if self.tag_names.len() > 0 {
-            self.option_string += format!("&tag_names={}", self.tag_names).as_str()
+            options += format!("&tag_names={}", self.tag_names).as_str()
         } else {
             return Err(String::from(TAG_NAME_REQUIRED_ERROR_TEXT));
         }
         if self.exclude_tags.len() > 0 {
-            self.option_string += format!("&exclude_tag_names={}", self.exclude_tags).as_str()
+            options += format!("&exclude_tag_names={}", self.exclude_tags).as_str()
         }
-        Ok(self.option_string)
+        Ok(options)
+    }
+
+    /// Returns the current arguments as a URL formatted string
+    pub(crate) fn build(self) -> Result<String, String> {
+        self.peek()
+    }
+
+    /// Consumes the builder and returns its arguments as a URL formatted string
+    /// 
+    /// This is the query string the client appends to the request URL, which can be logged or checked in tests without sending a request.  Returns Err if the arguments are invalid, as the client would.
+    pub fn into_query(self) -> Result<String, String> {
+        self.build()
     }
 
     /// Adds a tag name that all series must match
@@ -148,7 +160,7 @@ impl Builder {
         if self.tag_names.len() != 0 {
             self.tag_names.push(';');
         } 
-        self.tag_names += tag;
+        self.tag_names += encode_query_value(tag).as_str();
         self
     }
 
@@ -164,7 +176,7 @@ impl Builder {
         if self.exclude_tags.len() != 0 {
             self.exclude_tags.push(';');
         } 
-        self.exclude_tags += tag;
+        self.exclude_tags += encode_query_value(tag).as_str();
         self
     }
 
@@ -175,7 +187,7 @@ impl Builder {
     /// 
     /// [https://research.stlouisfed.org/docs/api/fred/tags_series.html#realtime_start](https://research.stlouisfed.org/docs/api/fred/tags_series.html#realtime_start)
     pub fn realtime_start(&mut self, start_date: &str) -> &mut Builder {
-        self.option_string += format!("&realtime_start={}", start_date).as_str();
+        self.option_string += format!("&realtime_start={}", crate::common::date_arg(start_date)).as_str();
         self
     }
 
@@ -186,10 +198,12 @@ impl Builder {
     /// 
     /// [https://research.stlouisfed.org/docs/api/fred/tags_series.html#realtime_end](https://research.stlouisfed.org/docs/api/fred/tags_series.html#realtime_end)
     pub fn realtime_end(&mut self, end_date: &str) -> &mut Builder {
-        self.option_string += format!("&realtime_end={}", end_date).as_str();
+        self.option_string += format!("&realtime_end={}", crate::common::date_arg(end_date)).as_str();
         self
     }
 
+    realtime_period!();
+
     /// Adds a limit argument to the builder
     /// 
     /// The limit argument specifies a maximum number of observations to return.
@@ -208,6 +222,19 @@ impl Builder {
         self
     }
 
+    /// Adds a limit argument to the builder, returning Err rather than clamping
+    /// 
+    /// limit() silently clamps values above the endpoint maximum of 1,000, which returns fewer results than requested.  This function returns Err in that case so the condition can be detected.
+    /// 
+    /// # Arguments
+    /// * `num_results` - Maximum number of results to return
+    pub fn try_limit(&mut self, num_results: usize) -> Result<&mut Builder, FredError> {
+        if num_results > 1000 {
+            return Err(FredError::InvalidArgument(String::from(LIMIT_EXCEEDED_ERROR_TEXT)));
+        }
+        Ok(self.limit(num_results))
+    }
+
     /// Adds an offset argument to the builder
     /// 
     /// Adding an offset shifts the starting result number.  For example, if limit is 5 and offset is 0 then results 1-5 will be returned, but if offset was 5 then results 6-10 would be returned.
@@ -276,12 +303,7 @@ impl Builder {
     /// 
     /// [https://research.stlouisfed.org/docs/api/fred/tags_series.html#sort_order](https://research.stlouisfed.org/docs/api/fred/tags_series.html#sort_order)
     pub fn sort_order(&mut self, order: SortOrder) -> &mut Builder {
-        match order {
-            SortOrder::Descending => {
-                self.option_string += format!("&sort_order=desc").as_str()
-            },
-            _ => () // ASC is the default so do nothing
-        }
+        self.option_string += format!("&sort_order={}", order.as_param()).as_str();
         self
     }
 
@@ -367,4 +389,24 @@ mod tests {
 
         assert_eq!(2, 1); // if the request succeeded then failure
     } 
+
+    #[test]
+    fn tags_series_reserved_characters() {
+        let mut builder = Builder::new();
+        builder
+            .tag_name("s&p 500")
+            .tag_name("gdp+growth;usa")
+            .exclude_tag("#discontinued");
+
+        match builder.peek() {
+            Ok(options) => assert_eq!(
+                options,
+                "&tag_names=s%26p%20500;gdp%2Bgrowth%3Busa&exclude_tag_names=%23discontinued"
+            ),
+            Err(msg) => {
+                println!("{}", msg);
+                assert_eq!(2, 1);
+            },
+        }
+    }
 }
\ No newline at end of file